@@ -5,6 +5,7 @@ import {
   SYSVAR_CLOCK_PUBKEY,
   SYSVAR_INSTRUCTIONS_PUBKEY,
   SYSVAR_RENT_PUBKEY,
+  SYSVAR_SLOT_HASHES_PUBKEY,
 } from "@solana/web3.js";
 import {
   HPL_HIVE_CONTROL_PROGRAM,
@@ -155,6 +156,7 @@ export async function createCollectRewardsOperation(
         instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
         tokenProgram: TOKEN_PROGRAM_ID,
         clock: SYSVAR_CLOCK_PUBKEY,
+        slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
       },
       programId
     ),
@@ -279,6 +281,7 @@ export async function creatRecallOperation(
         hplEvents: HPL_EVENTS_PROGRAM,
         clock: SYSVAR_CLOCK_PUBKEY,
         instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+        slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
       },
       programId
     )
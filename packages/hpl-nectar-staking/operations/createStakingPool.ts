@@ -131,8 +131,16 @@ export async function createCreateStakingPoolOperation(
             minStakeDuration: null,
             cooldownDuration: null,
             resetStakeDuration: null,
+            allowedMints: null,
             startTime: null,
             endTime: null,
+            compressedRewardsRatio: null,
+            paused: null,
+            settleOnUnstake: null,
+            emissionMode: null,
+            maxTotalRewards: null,
+            emissionEpochs: null,
+            criteriaAction: null,
           },
           project: args.project.address,
           stakingPool: stakingPool,
@@ -155,8 +163,16 @@ export async function createCreateStakingPoolOperation(
             minStakeDuration: null,
             cooldownDuration: null,
             resetStakeDuration: null,
+            allowedMints: null,
             startTime: null,
             endTime: null,
+            compressedRewardsRatio: null,
+            paused: null,
+            settleOnUnstake: null,
+            emissionMode: null,
+            maxTotalRewards: null,
+            emissionEpochs: null,
+            criteriaAction: null,
           },
           project: args.project.address,
           stakingPool: stakingPool,
@@ -179,8 +195,16 @@ export async function createCreateStakingPoolOperation(
             minStakeDuration: null,
             cooldownDuration: null,
             resetStakeDuration: null,
+            allowedMints: null,
             startTime: null,
             endTime: null,
+            compressedRewardsRatio: null,
+            paused: null,
+            settleOnUnstake: null,
+            emissionMode: null,
+            maxTotalRewards: null,
+            emissionEpochs: null,
+            criteriaAction: null,
           },
           project: args.project.address,
           stakingPool: stakingPool,
@@ -195,6 +219,8 @@ export async function createCreateStakingPoolOperation(
     await createInitMultiplierOperation(honeycomb, {
       args: {
         decimals: args.multipliersDecimals || 9,
+        stacking: null,
+        maxMultiplier: null,
       },
       project: args.project.address,
       stakingPool,
@@ -40,4 +40,70 @@ pub enum ErrorCode {
 
     #[msg("Invalid Profile data")]
     InvalidProfileData,
+
+    #[msg("Profile does not have enough XP for this mission")]
+    NotEnoughXp,
+
+    #[msg("Randomness can not be revealed before the committed slot")]
+    RandomnessNotAvailable,
+
+    #[msg("Invalid SlotHashes sysvar data")]
+    InvalidSlotHashes,
+
+    #[msg("The faction is not allowed in this mission")]
+    FactionNotAllowed,
+
+    #[msg("Party members are invalid or incomplete")]
+    InvalidPartyMembers,
+
+    #[msg("Reward shares are invalid for this party")]
+    InvalidShareRule,
+
+    #[msg("Wallet is not a member of this participation")]
+    NotAPartyMember,
+
+    #[msg("NFT reward is not available in the escrow")]
+    NftRewardNotAvailable,
+
+    #[msg("Escrow or NFT accounts not provided")]
+    NftAccountsNotProvided,
+
+    #[msg("Drop chance of a reward can not be more than 10000 basis points")]
+    InvalidDropChance,
+
+    #[msg("Reward drops must be empty or match the rewards")]
+    InvalidRewardDrops,
+
+    #[msg("Currency accounts for the recall penalty not provided")]
+    PenaltyAccountsNotProvided,
+
+    #[msg("Party members are recalled with recall_party_member")]
+    PartyRecallNotAllowed,
+
+    #[msg("StakingPool, Staker or Nectar Staking Program not provided")]
+    RecallAccountsNotProvided,
+
+    #[msg("Rewards are already revealed")]
+    RewardsAlreadyRevealed,
+
+    #[msg("The staking pool is not registered in the mission pool")]
+    StakingPoolNotAllowed,
+
+    #[msg("Party members must belong to the same faction")]
+    FactionMismatch,
+
+    #[msg("The mission does not belong to the mission pool")]
+    InvalidMission,
+
+    #[msg("The participation must be migrated with migrate_participation")]
+    ParticipationNotMigrated,
+
+    #[msg("The participation does not belong to the mission")]
+    InvalidParticipation,
+
+    #[msg("Rewards with NFTs must be revealed with reveal_rewards first")]
+    RewardsNotRevealed,
+
+    #[msg("NFT rewards must be collected with collect_rewards before recalling")]
+    NftRewardsPending,
 }
@@ -0,0 +1,384 @@
+use {
+    crate::{errors::ErrorCode, state::*},
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        associated_token::AssociatedToken,
+        token::{self, CloseAccount, Mint, Token, TokenAccount},
+    },
+    hpl_hive_control::{
+        program::HplHiveControl,
+        state::{DelegateAuthority, Project},
+    },
+    hpl_utils::traits::Default,
+};
+
+/// Accounts used in create nft_escrow instruction
+#[derive(Accounts)]
+#[instruction(args: CreateNftEscrowArgs)]
+pub struct CreateNftEscrow<'info> {
+    #[account()]
+    pub project: Box<Account<'info, Project>>,
+
+    /// MissionPool state account
+    #[account(has_one = project)]
+    pub mission_pool: Box<Account<'info, MissionPool>>,
+
+    /// NftEscrow state account
+    #[account(
+      init, payer = payer,
+      space = NftEscrow::LEN,
+      seeds = [
+        b"nft_escrow".as_ref(),
+        mission_pool.key().as_ref(),
+        args.name.as_bytes(),
+      ],
+      bump
+    )]
+    pub nft_escrow: Box<Account<'info, NftEscrow>>,
+
+    /// [Option] Project delegate authority
+    #[account(has_one = authority)]
+    pub delegate_authority: Option<Account<'info, DelegateAuthority>>,
+
+    /// The wallet that holds authority for this action
+    pub authority: Signer<'info>,
+
+    /// The wallet that pays for the rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub vault: AccountInfo<'info>,
+
+    /// SYSTEM PROGRAM
+    pub system_program: Program<'info, System>,
+
+    /// HPL Hive Control Program
+    pub hive_control: Program<'info, HplHiveControl>,
+
+    /// NATIVE INSTRUCTIONS SYSVAR
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CreateNftEscrowArgs {
+    pub name: String,
+}
+
+/// Create a new nft_escrow for the mission_pool
+pub fn create_nft_escrow(ctx: Context<CreateNftEscrow>, args: CreateNftEscrowArgs) -> Result<()> {
+    let nft_escrow = &mut ctx.accounts.nft_escrow;
+    nft_escrow.set_defaults();
+
+    nft_escrow.bump = ctx.bumps["nft_escrow"];
+    nft_escrow.mission_pool = ctx.accounts.mission_pool.key();
+    nft_escrow.name = args.name;
+
+    Ok(())
+}
+
+/// Accounts used in deposit escrow nft instruction
+#[derive(Accounts)]
+pub struct DepositEscrowNft<'info> {
+    #[account()]
+    pub project: Box<Account<'info, Project>>,
+
+    /// MissionPool state account
+    #[account(has_one = project)]
+    pub mission_pool: Box<Account<'info, MissionPool>>,
+
+    /// NftEscrow state account
+    #[account(mut, has_one = mission_pool)]
+    pub nft_escrow: Box<Account<'info, NftEscrow>>,
+
+    /// Mint address of the NFT
+    pub nft_mint: Box<Account<'info, Mint>>,
+
+    /// Token account of the NFT
+    #[account(mut, constraint = nft_account.mint == nft_mint.key() && nft_account.owner == authority.key())]
+    pub nft_account: Box<Account<'info, TokenAccount>>,
+
+    /// NFT token metadata
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub nft_metadata: AccountInfo<'info>,
+
+    /// NFT edition
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub nft_edition: AccountInfo<'info>,
+
+    /// NFT token record
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub nft_token_record: Option<AccountInfo<'info>>,
+
+    /// The account that will hold the nft in escrow
+    #[account(
+        init, payer = payer,
+        seeds = [
+            b"nft_escrow".as_ref(),
+            nft_escrow.key().as_ref(),
+            nft_mint.key().as_ref(),
+        ],
+        bump,
+        token::mint = nft_mint,
+        token::authority = mission_pool,
+    )]
+    pub escrow_account: Box<Account<'info, TokenAccount>>,
+
+    /// Escrow token record
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub escrow_token_record: Option<AccountInfo<'info>>,
+
+    /// [Option] Project delegate authority
+    #[account(has_one = authority)]
+    pub delegate_authority: Option<Account<'info, DelegateAuthority>>,
+
+    /// The wallet that holds authority for this action and owns the NFT
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The wallet that pays for the rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub vault: AccountInfo<'info>,
+
+    /// NATIVE SYSTEM PROGRAM
+    pub system_program: Program<'info, System>,
+
+    /// HPL Hive Control Program
+    pub hive_control: Program<'info, HplHiveControl>,
+
+    /// NATIVE TOKEN PROGRAM
+    pub token_program: Program<'info, Token>,
+
+    /// ASSOCIATED TOKEN PROGRAM
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// METAPLEX TOKEN METADATA PROGRAM
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: AccountInfo<'info>,
+
+    /// NATIVE RENT SYSVAR
+    pub rent_sysvar: Sysvar<'info, Rent>,
+
+    /// NATIVE INSTRUCTIONS SYSVAR
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub authorization_rules_program: Option<AccountInfo<'info>>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub authorization_rules: Option<AccountInfo<'info>>,
+}
+
+/// Deposit an NFT into the nft_escrow
+pub fn deposit_escrow_nft(ctx: Context<DepositEscrowNft>) -> Result<()> {
+    hpl_utils::transfer(
+        1,
+        ctx.accounts.nft_account.to_account_info(),
+        ctx.accounts.authority.to_account_info(),
+        ctx.accounts.escrow_account.to_account_info(),
+        ctx.accounts.mission_pool.to_account_info(),
+        ctx.accounts.nft_mint.to_account_info(),
+        ctx.accounts.nft_metadata.to_account_info(),
+        Some(ctx.accounts.nft_edition.to_account_info()),
+        ctx.accounts.nft_token_record.clone(),
+        ctx.accounts.escrow_token_record.clone(),
+        ctx.accounts.authority.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.associated_token_program.to_account_info(),
+        ctx.accounts.instructions_sysvar.to_account_info(),
+        ctx.accounts.authorization_rules_program.clone(),
+        ctx.accounts.authorization_rules.clone(),
+        None,
+    )?;
+
+    hpl_utils::reallocate(
+        32,
+        ctx.accounts.nft_escrow.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.rent_sysvar,
+        &ctx.accounts.system_program,
+    )?;
+    ctx.accounts
+        .nft_escrow
+        .mints
+        .push(ctx.accounts.nft_mint.key());
+
+    Ok(())
+}
+
+/// Accounts used in withdraw escrow nft instruction
+#[derive(Accounts)]
+pub struct WithdrawEscrowNft<'info> {
+    #[account()]
+    pub project: Box<Account<'info, Project>>,
+
+    /// MissionPool state account
+    #[account(has_one = project)]
+    pub mission_pool: Box<Account<'info, MissionPool>>,
+
+    /// NftEscrow state account
+    #[account(mut, has_one = mission_pool)]
+    pub nft_escrow: Box<Account<'info, NftEscrow>>,
+
+    /// Mint address of the NFT
+    pub nft_mint: Box<Account<'info, Mint>>,
+
+    /// Token account receiving the NFT
+    #[account(mut, constraint = nft_account.mint == nft_mint.key() && nft_account.owner == authority.key())]
+    pub nft_account: Box<Account<'info, TokenAccount>>,
+
+    /// NFT token metadata
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub nft_metadata: AccountInfo<'info>,
+
+    /// NFT edition
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub nft_edition: AccountInfo<'info>,
+
+    /// NFT token record
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub nft_token_record: Option<AccountInfo<'info>>,
+
+    /// The account holding the nft in escrow
+    #[account(
+        mut,
+        seeds = [
+            b"nft_escrow".as_ref(),
+            nft_escrow.key().as_ref(),
+            nft_mint.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub escrow_account: Box<Account<'info, TokenAccount>>,
+
+    /// Escrow token record
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub escrow_token_record: Option<AccountInfo<'info>>,
+
+    /// [Option] Project delegate authority
+    #[account(has_one = authority)]
+    pub delegate_authority: Option<Account<'info, DelegateAuthority>>,
+
+    /// The wallet that holds authority for this action and receives the NFT
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The wallet that pays for the rent and receives the rent of the escrow account
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub vault: AccountInfo<'info>,
+
+    /// NATIVE SYSTEM PROGRAM
+    pub system_program: Program<'info, System>,
+
+    /// HPL Hive Control Program
+    pub hive_control: Program<'info, HplHiveControl>,
+
+    /// NATIVE TOKEN PROGRAM
+    pub token_program: Program<'info, Token>,
+
+    /// ASSOCIATED TOKEN PROGRAM
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// METAPLEX TOKEN METADATA PROGRAM
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: AccountInfo<'info>,
+
+    /// NATIVE RENT SYSVAR
+    pub rent_sysvar: Sysvar<'info, Rent>,
+
+    /// NATIVE INSTRUCTIONS SYSVAR
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub authorization_rules_program: Option<AccountInfo<'info>>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub authorization_rules: Option<AccountInfo<'info>>,
+}
+
+/// Withdraw an NFT that is not yet rewarded from the nft_escrow
+/// NFTs reserved for random picks of ongoing participations can not be withdrawn
+pub fn withdraw_escrow_nft(ctx: Context<WithdrawEscrowNft>) -> Result<()> {
+    if !ctx.accounts.nft_escrow.can_withdraw()
+        || !ctx.accounts.nft_escrow.take(&ctx.accounts.nft_mint.key())
+    {
+        return Err(ErrorCode::NftRewardNotAvailable.into());
+    }
+
+    let mission_pool_seeds = &[
+        b"mission_pool".as_ref(),
+        ctx.accounts.mission_pool.project.as_ref(),
+        ctx.accounts.mission_pool.name.as_bytes(),
+        &[ctx.accounts.mission_pool.bump],
+    ];
+    let signer = &[&mission_pool_seeds[..]];
+
+    hpl_utils::transfer(
+        1,
+        ctx.accounts.escrow_account.to_account_info(),
+        ctx.accounts.mission_pool.to_account_info(),
+        ctx.accounts.nft_account.to_account_info(),
+        ctx.accounts.authority.to_account_info(),
+        ctx.accounts.nft_mint.to_account_info(),
+        ctx.accounts.nft_metadata.to_account_info(),
+        Some(ctx.accounts.nft_edition.to_account_info()),
+        ctx.accounts.escrow_token_record.clone(),
+        ctx.accounts.nft_token_record.clone(),
+        ctx.accounts.mission_pool.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+        ctx.accounts.associated_token_program.to_account_info(),
+        ctx.accounts.instructions_sysvar.to_account_info(),
+        ctx.accounts.authorization_rules_program.clone(),
+        ctx.accounts.authorization_rules.clone(),
+        Some(signer),
+    )?;
+
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.escrow_account.to_account_info(),
+            destination: ctx.accounts.payer.to_account_info(),
+            authority: ctx.accounts.mission_pool.to_account_info(),
+        },
+        signer,
+    ))?;
+
+    hpl_utils::reallocate(
+        -32,
+        ctx.accounts.nft_escrow.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.rent_sysvar,
+        &ctx.accounts.system_program,
+    )?;
+
+    Ok(())
+}
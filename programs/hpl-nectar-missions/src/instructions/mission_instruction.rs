@@ -1,5 +1,5 @@
 use {
-    crate::state::*,
+    crate::{errors::ErrorCode, state::*},
     anchor_lang::prelude::*,
     hpl_hive_control::{
         program::HplHiveControl,
@@ -70,6 +70,10 @@ pub struct CreateMissionArgs {
     /// The duration of the mission in seconds
     pub duration: i64,
     pub rewards: Vec<Reward>,
+    /// Drop table entries matching the rewards, empty if all of them always drop
+    pub reward_drops: Vec<RewardDrop>,
+    pub factions: Vec<String>,
+    pub recall_policy: RecallPolicy,
 }
 
 /// Create a new mission
@@ -83,9 +87,12 @@ pub fn create_mission(ctx: Context<CreateMission>, args: CreateMissionArgs) -> R
     mission.min_xp = args.min_xp;
     mission.cost = args.cost;
     mission.duration = args.duration;
+    mission.recall_policy = args.recall_policy;
+    let reward_drops = validate_rewards(&args.rewards, args.reward_drops)?;
 
     hpl_utils::reallocate(
-        (Reward::LEN * args.rewards.len()) as isize,
+        ((Reward::LEN + RewardDrop::LEN) * args.rewards.len()
+            + Mission::factions_len(&args.factions)) as isize,
         mission.to_account_info(),
         ctx.accounts.payer.to_account_info(),
         &ctx.accounts.rent_sysvar,
@@ -93,6 +100,8 @@ pub fn create_mission(ctx: Context<CreateMission>, args: CreateMissionArgs) -> R
     )?;
 
     mission.rewards = args.rewards;
+    mission.reward_drops = reward_drops;
+    mission.factions = args.factions;
 
     // msg!("JSON Mission: {:?}", mission);
     Ok(())
@@ -151,7 +160,11 @@ pub struct UpdateMissionArgs {
     pub duration: Option<i64>,
     pub remove_all_rewards: Option<bool>,
     pub add_rewards: Option<Vec<Reward>>,
+    /// Drop table entries matching add_rewards, empty if all of them always drop
+    pub add_reward_drops: Option<Vec<RewardDrop>>,
     pub remove_reward_indices: Option<Vec<u8>>,
+    pub factions: Option<Vec<String>>,
+    pub recall_policy: Option<RecallPolicy>,
 }
 
 /// Create a new mission
@@ -161,10 +174,18 @@ pub fn update_mission(ctx: Context<UpdateMission>, args: UpdateMissionArgs) -> R
     mission.min_xp = args.min_xp.unwrap_or(mission.min_xp);
     mission.cost = args.cost.unwrap_or(mission.cost.clone());
     mission.duration = args.duration.unwrap_or(mission.duration);
+    mission.recall_policy = args.recall_policy.unwrap_or(mission.recall_policy.clone());
+
+    // Missions created before drop tables have no entries, they are filled in
+    // with the entries of rewards that always drop to keep them aligned by index
+    let drops_len = mission.reward_drops.len();
+    let rewards_len = mission.rewards.len();
+    mission.reward_drops.resize(rewards_len, RewardDrop::ALWAYS);
 
     if args.remove_all_rewards.is_some() && args.remove_all_rewards.unwrap() {
         let curr_len = mission.rewards.len();
         mission.rewards = vec![];
+        mission.reward_drops = vec![];
         let diff = curr_len - mission.rewards.len();
         hpl_utils::reallocate(
             (Reward::LEN * diff) as isize * -1,
@@ -182,6 +203,13 @@ pub fn update_mission(ctx: Context<UpdateMission>, args: UpdateMissionArgs) -> R
             .filter(|(i, _)| indices.contains(&(*i as u8)))
             .map(|(_, val)| val.clone())
             .collect::<Vec<_>>();
+        mission.reward_drops = mission
+            .reward_drops
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| indices.contains(&(*i as u8)))
+            .map(|(_, val)| val.clone())
+            .collect::<Vec<_>>();
 
         let diff = curr_len - mission.rewards.len();
         hpl_utils::reallocate(
@@ -194,6 +222,8 @@ pub fn update_mission(ctx: Context<UpdateMission>, args: UpdateMissionArgs) -> R
     }
 
     if let Some(rewards) = args.add_rewards {
+        let mut reward_drops =
+            validate_rewards(&rewards, args.add_reward_drops.unwrap_or_default())?;
         hpl_utils::reallocate(
             (Reward::LEN * rewards.len()) as isize,
             mission.to_account_info(),
@@ -204,6 +234,124 @@ pub fn update_mission(ctx: Context<UpdateMission>, args: UpdateMissionArgs) -> R
 
         let mut temp = rewards.clone();
         mission.rewards.append(&mut temp);
+        mission.reward_drops.append(&mut reward_drops);
+    }
+
+    let drops_diff = mission.reward_drops.len() as isize - drops_len as isize;
+    if drops_diff != 0 {
+        hpl_utils::reallocate(
+            RewardDrop::LEN as isize * drops_diff,
+            mission.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.rent_sysvar,
+            &ctx.accounts.system_program,
+        )?;
+    }
+
+    if let Some(factions) = args.factions {
+        let diff = Mission::factions_len(&factions) as isize
+            - Mission::factions_len(&mission.factions) as isize;
+
+        if diff != 0 {
+            hpl_utils::reallocate(
+                diff,
+                mission.to_account_info(),
+                ctx.accounts.payer.to_account_info(),
+                &ctx.accounts.rent_sysvar,
+                &ctx.accounts.system_program,
+            )?;
+        }
+
+        mission.factions = factions;
+    }
+
+    Ok(())
+}
+
+/// Validate the drop table entries of rewards
+/// Returns the entries to store, one for every reward
+fn validate_rewards(rewards: &[Reward], reward_drops: Vec<RewardDrop>) -> Result<Vec<RewardDrop>> {
+    if reward_drops.is_empty() {
+        return Ok(vec![RewardDrop::ALWAYS; rewards.len()]);
+    }
+
+    if reward_drops.len() != rewards.len() {
+        return Err(ErrorCode::InvalidRewardDrops.into());
+    }
+
+    if reward_drops
+        .iter()
+        .any(|reward_drop| reward_drop.drop_chance > Reward::ALWAYS_DROPS)
+    {
+        return Err(ErrorCode::InvalidDropChance.into());
+    }
+
+    Ok(reward_drops)
+}
+
+/// Accounts used in migrate mission instruction
+#[derive(Accounts)]
+pub struct MigrateMission<'info> {
+    #[account()]
+    pub project: Box<Account<'info, Project>>,
+
+    /// Missions pool account
+    #[account(has_one = project)]
+    pub mission_pool: Box<Account<'info, MissionPool>>,
+
+    /// Mission state account in the layout it was created with
+    /// CHECK: This is not dangerous because the owner is checked and the account is deserialized once reallocated
+    #[account(mut, owner = crate::ID)]
+    pub mission: AccountInfo<'info>,
+
+    /// [Option] Project delegate authority
+    #[account(has_one = authority)]
+    pub delegate_authority: Option<Account<'info, DelegateAuthority>>,
+
+    /// The wallet that holds the authority over the assembler
+    pub authority: Signer<'info>,
+
+    /// The wallet that pays for the rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub vault: AccountInfo<'info>,
+
+    /// SYSTEM PROGRAM
+    pub system_program: Program<'info, System>,
+
+    /// HPL Hive Control Program
+    pub hive_control: Program<'info, HplHiveControl>,
+
+    /// RENT SYSVAR
+    pub rent_sysvar: Sysvar<'info, Rent>,
+
+    /// NATIVE INSTRUCTIONS SYSVAR
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+/// Migrate a mission created before factions, recall policies and drop tables
+/// The appended fields are zero filled, the mission is open to all factions,
+/// can not be recalled and all of its rewards always drop
+pub fn migrate_mission(ctx: Context<MigrateMission>) -> Result<()> {
+    let mission_info = &ctx.accounts.mission;
+    if Mission::try_deserialize(&mut &mission_info.try_borrow_data()?[..]).is_err() {
+        hpl_utils::reallocate(
+            isize::try_from(Mission::APPENDED_LEN).unwrap(),
+            mission_info.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.rent_sysvar,
+            &ctx.accounts.system_program,
+        )?;
+    }
+
+    let mission = Account::<Mission>::try_from(mission_info)?;
+    if mission.mission_pool != ctx.accounts.mission_pool.key() {
+        return Err(ErrorCode::InvalidMission.into());
     }
 
     Ok(())
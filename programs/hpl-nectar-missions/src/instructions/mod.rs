@@ -1,5 +1,10 @@
+pub mod escrow_instruction;
 pub mod mission_instruction;
 pub mod partipcation_instruction;
+pub mod party_instruction;
 pub mod pool_instruction;
 
-pub use {mission_instruction::*, partipcation_instruction::*, pool_instruction::*};
+pub use {
+    escrow_instruction::*, mission_instruction::*, partipcation_instruction::*,
+    party_instruction::*, pool_instruction::*,
+};
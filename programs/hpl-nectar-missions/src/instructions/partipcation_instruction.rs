@@ -1,7 +1,10 @@
 use {
-    crate::{errors::ErrorCode, state::*, utils::RANDOMIZER},
+    crate::{errors::ErrorCode, randomness, state::*},
     anchor_lang::prelude::*,
-    anchor_spl::token::{self, Mint, Token, TokenAccount},
+    anchor_spl::{
+        associated_token::AssociatedToken,
+        token::{self, CloseAccount, Mint, Token, TokenAccount},
+    },
     hpl_currency_manager::{
         cpi::{
             accounts::{BurnCurrency, MintCurrency},
@@ -15,7 +18,7 @@ use {
         cpi::{accounts::ManageProfileData, manage_profile_data},
         instructions::ManageProfileDataArgs,
         program::HplHiveControl,
-        state::{DelegateAuthority, Profile, ProfileData, ProfileIdentity, Project, Service},
+        state::{DelegateAuthority, Profile, ProfileData, ProfileIdentity, Project, Service, User},
     },
     hpl_nectar_staking::{
         cpi::{accounts::UseNft, use_nft},
@@ -27,13 +30,14 @@ use {
 };
 
 /// Accounts used in participate instruction
+/// The escrows of the mission NFT rewards are passed in remaining accounts
 #[derive(Accounts)]
 pub struct Participate<'info> {
     #[account(mut)]
     pub project: Box<Account<'info, Project>>,
 
     /// StakingPool state account
-    #[account(has_one = project)]
+    #[account(mut, has_one = project)]
     pub staking_pool: Box<Account<'info, StakingPool>>,
 
     /// MissionPool account
@@ -52,6 +56,14 @@ pub struct Participate<'info> {
     #[account(has_one = staking_pool, has_one = wallet)]
     pub staker: Box<Account<'info, Staker>>,
 
+    /// Hive control user of the wallet
+    #[account(constraint = user.primary_wallet == wallet.key() || user.secondary_wallets.contains(&wallet.key()))]
+    pub user: Option<Box<Account<'info, User>>>,
+
+    /// User profile account
+    #[account(has_one = project, constraint = profile.identity == ProfileIdentity::Main)]
+    pub profile: Option<Box<Account<'info, Profile>>>,
+
     #[account(has_one = mint, constraint = mission.cost.address == currency.key())]
     pub currency: Box<Account<'info, Currency>>,
     #[account(mut)]
@@ -96,6 +108,187 @@ pub struct Participate<'info> {
     pub instructions_sysvar: AccountInfo<'info>,
 }
 
+/// Missions XP stored in the app_context of the profile
+fn profile_xp(profile: &Profile) -> Result<u64> {
+    if let Some(ProfileData::SingleValue(value)) = profile.app_context.get("nectar_missions_xp") {
+        return value
+            .parse::<u64>()
+            .map_err(|_| ErrorCode::InvalidProfileData.into());
+    }
+
+    Ok(0)
+}
+
+/// Checks that the staking_pool is registered in the mission_pool
+pub(crate) fn assert_staking_pool(
+    project: &Project,
+    mission_pool: &MissionPool,
+    staking_pool: &Pubkey,
+) -> Result<()> {
+    if !mission_pool.staking_pools.iter().any(|pool_index| {
+        match project.services.get(*pool_index as usize) {
+            Some(Service::Staking { pool_id }) => pool_id == staking_pool,
+            _ => false,
+        }
+    }) {
+        return Err(ErrorCode::StakingPoolNotAllowed.into());
+    }
+
+    Ok(())
+}
+
+/// Loads the escrows of the NFT rewards passed in remaining accounts
+pub(crate) fn load_escrows<'info>(
+    accounts: &[AccountInfo<'info>],
+    mission_pool: &Pubkey,
+) -> Result<Vec<Account<'info, NftEscrow>>> {
+    let mut escrows: Vec<Account<'info, NftEscrow>> = Vec::with_capacity(accounts.len());
+    for info in accounts {
+        let escrow = Account::<NftEscrow>::try_from(info)?;
+        if escrow.mission_pool != *mission_pool
+            || escrows.iter().any(|loaded| loaded.key() == escrow.key())
+        {
+            return Err(ErrorCode::NftAccountsNotProvided.into());
+        }
+        escrows.push(escrow);
+    }
+
+    Ok(escrows)
+}
+
+/// Finds the escrow of an NFT reward among the loaded escrows
+fn find_escrow<'a, 'info>(
+    escrows: &'a mut [Account<'info, NftEscrow>],
+    escrow: &Pubkey,
+) -> Result<&'a mut Account<'info, NftEscrow>> {
+    match escrows.iter_mut().find(|loaded| loaded.key() == *escrow) {
+        Some(nft_escrow) => Ok(nft_escrow),
+        None => Err(ErrorCode::NftAccountsNotProvided.into()),
+    }
+}
+
+/// Reserves the NFT rewards of a participation in their escrows,
+/// so that every reward that drops can be delivered
+pub(crate) fn reserve_nft_rewards(
+    rewards: &[EarnedReward],
+    escrows: &mut [Account<NftEscrow>],
+) -> Result<()> {
+    for reward in rewards {
+        if let RewardType::Nft { escrow, mint } = &reward.reward_type {
+            if !find_escrow(escrows, escrow)?.reserve(mint) {
+                return Err(ErrorCode::NftRewardNotAvailable.into());
+            }
+        }
+    }
+
+    for nft_escrow in escrows.iter() {
+        nft_escrow.exit(&crate::ID)?;
+    }
+
+    Ok(())
+}
+
+/// Forfeits the NFT rewards that are not collected yet and releases them in their escrows
+fn forfeit_nft_rewards(
+    rewards: &mut [EarnedReward],
+    escrows: &mut [Account<NftEscrow>],
+) -> Result<()> {
+    for reward in rewards.iter_mut() {
+        if reward.collected {
+            continue;
+        }
+
+        if let RewardType::Nft { escrow, mint } = &reward.reward_type {
+            find_escrow(escrows, escrow)?.release(mint);
+            reward.collected = true;
+        }
+    }
+
+    for nft_escrow in escrows.iter() {
+        nft_escrow.exit(&crate::ID)?;
+    }
+
+    Ok(())
+}
+
+/// Checks that the profile of the wallet has the min_xp required by the mission
+pub(crate) fn assert_min_xp(
+    mission: &Mission,
+    user: &Option<Box<Account<User>>>,
+    profile: &Option<Box<Account<Profile>>>,
+) -> Result<()> {
+    if mission.min_xp == 0 {
+        return Ok(());
+    }
+
+    if user.is_none() || profile.is_none() {
+        return Err(ErrorCode::ProfileNotProvided.into());
+    }
+
+    let profile = profile.as_ref().unwrap();
+    if profile.user != user.as_ref().unwrap().key() {
+        return Err(ErrorCode::InvalidProfileData.into());
+    }
+
+    let xp = profile_xp(profile)?;
+    if xp < mission.min_xp {
+        msg!(
+            "Mission requires {} xp, profile has {} xp",
+            mission.min_xp,
+            xp
+        );
+        return Err(ErrorCode::NotEnoughXp.into());
+    }
+
+    Ok(())
+}
+
+/// Verifies the faction of the NFT mint and that the mission is open to it
+pub(crate) fn verify_faction(
+    mission_pool: &MissionPool,
+    mission: &Mission,
+    mint: &Pubkey,
+    faction: Option<String>,
+    merkle_proof: Option<Vec<[u8; 32]>>,
+) -> Result<Option<String>> {
+    let mut verified_faction = None;
+
+    if mission_pool.factions_merkle_root != [0; 32] {
+        if faction.is_none() {
+            return Err(ErrorCode::FactionNotProvided.into());
+        }
+
+        if merkle_proof.is_none() {
+            return Err(ErrorCode::MerkleProofNotProvided.into());
+        }
+
+        let faction = faction.unwrap();
+        let node =
+            hpl_utils::merkle_tree::create_node(&[&[0x00], faction.as_bytes(), mint.as_ref()]);
+        if !hpl_utils::merkle_tree::verify_merkle(
+            merkle_proof.unwrap(),
+            mission_pool.factions_merkle_root,
+            node.0,
+        ) {
+            return Err(ErrorCode::InvalidProof.into());
+        }
+
+        verified_faction = Some(faction);
+    }
+
+    if !mission.factions.is_empty() {
+        let allowed = verified_faction
+            .as_ref()
+            .map_or(false, |faction| mission.factions.contains(faction));
+
+        if !allowed {
+            return Err(ErrorCode::FactionNotAllowed.into());
+        }
+    }
+
+    Ok(verified_faction)
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct ParticipateArgs {
     pub faction: Option<String>,
@@ -103,77 +296,47 @@ pub struct ParticipateArgs {
 }
 
 /// participate in a mission
-pub fn participate(ctx: Context<Participate>, _args: ParticipateArgs) -> Result<()> {
+pub fn participate<'info>(
+    ctx: Context<'_, '_, '_, 'info, Participate<'info>>,
+    args: ParticipateArgs,
+) -> Result<()> {
     let participation = &mut ctx.accounts.participation;
     participation.set_defaults();
     participation.bump = ctx.bumps["participation"];
     participation.wallet = ctx.accounts.wallet.key();
     participation.mission = ctx.accounts.mission.key();
     participation.nft = ctx.accounts.nft.key();
-    participation.end_time = ctx.accounts.mission.duration + ctx.accounts.clock.unix_timestamp;
-
-    if !ctx
-        .accounts
-        .mission_pool
-        .staking_pools
-        .iter()
-        .any(|pool_index| {
-            if let Service::Staking { pool_id } =
-                ctx.accounts.project.services[*pool_index as usize]
-            {
-                pool_id == ctx.accounts.staking_pool.key()
-            } else {
-                false
-            }
-        })
-    {
-        panic!("Staking pool did not match");
-    }
-
-    // if ctx.accounts.mission_pool.factions_merkle_root[0] != 0 {
-    //     if args.faction.is_none() {
-    //         return Err(ErrorCode::FactionNotProvided.into());
-    //     }
-
-    //     if args.merkle_proof.is_none() {
-    //         return Err(ErrorCode::MerkleProofNotProvided.into());
-    //     }
-
-    //     let node = hpl_utils::merkle_tree::create_node(&[
-    //         &[0x00],
-    //         args.faction.unwrap().as_bytes(),
-    //         ctx.accounts.nft.mint.as_ref(),
-    //     ]);
-    //     if !hpl_utils::merkle_tree::verify_merkle(
-    //         args.merkle_proof.unwrap(),
-    //         ctx.accounts.mission_pool.factions_merkle_root,
-    //         node.0,
-    //     ) {
-    //         return Err(ErrorCode::InvalidProof.into());
-    //     }
-    // }
-
-    let rewards = ctx
-        .accounts
-        .mission
-        .rewards
-        .iter()
-        .map(|reward| EarnedReward {
-            amount: RANDOMIZER.get_random_between(
-                ctx.accounts.mission_pool.randomizer_round as usize,
-                ctx.accounts.clock.slot,
-                reward.min,
-                reward.max,
-            ),
-            reward_type: reward.reward_type.clone(),
-            collected: false,
-        })
-        .collect::<Vec<_>>();
+    participation.start_time = ctx.accounts.clock.unix_timestamp;
+    participation.end_time = ctx.accounts.mission.duration + participation.start_time;
+
+    assert_staking_pool(
+        &ctx.accounts.project,
+        &ctx.accounts.mission_pool,
+        &ctx.accounts.staking_pool.key(),
+    )?;
+    assert_min_xp(
+        &ctx.accounts.mission,
+        &ctx.accounts.user,
+        &ctx.accounts.profile,
+    )?;
+    participation.faction = verify_faction(
+        &ctx.accounts.mission_pool,
+        &ctx.accounts.mission,
+        &ctx.accounts.nft.mint,
+        args.faction,
+        args.merkle_proof,
+    )?;
 
-    ctx.accounts.mission_pool.increase_randomizer_round();
+    // Reward rolls are only committed here and revealed with the hash of a later slot
+    participation.reveal_slot = ctx.accounts.clock.slot + randomness::REVEAL_DELAY_SLOTS;
+    let (rewards, rolls) = ctx.accounts.mission.commit_rewards();
 
+    let faction_len = participation
+        .faction
+        .as_ref()
+        .map_or(0, |faction| faction.len());
     hpl_utils::reallocate(
-        (Reward::LEN * rewards.len()) as isize,
+        ((Reward::LEN + RewardRoll::LEN) * rewards.len() + faction_len) as isize,
         participation.to_account_info(),
         ctx.accounts.wallet.to_account_info(),
         &ctx.accounts.rent_sysvar,
@@ -181,6 +344,10 @@ pub fn participate(ctx: Context<Participate>, _args: ParticipateArgs) -> Result<
     )?;
 
     participation.rewards = rewards;
+    participation.rolls = rolls;
+
+    let mut escrows = load_escrows(ctx.remaining_accounts, &ctx.accounts.mission_pool.key())?;
+    reserve_nft_rewards(&participation.rewards, &mut escrows)?;
 
     if ctx.accounts.nft.last_staked_at < ctx.accounts.nft.last_unstaked_at {
         return Err(ErrorCode::NotStaked.into());
@@ -204,12 +371,9 @@ pub fn participate(ctx: Context<Participate>, _args: ParticipateArgs) -> Result<
                 token_program: ctx.accounts.token_program.to_account_info(),
             },
         ),
-        ctx.accounts.mission.cost.amount
-            * if ctx.accounts.nft.is_compressed {
-                1
-            } else {
-                10
-            },
+        ctx.accounts
+            .mission
+            .participation_cost(ctx.accounts.nft.is_compressed),
     )?;
 
     use_nft(
@@ -261,7 +425,11 @@ pub struct CollectRewards<'info> {
     pub mission: Box<Account<'info, Mission>>,
 
     /// Participation state account
-    #[account(mut, has_one = wallet, has_one = nft, has_one = mission)]
+    #[account(
+        mut,
+        has_one = mission,
+        constraint = participation.is_party() || (participation.wallet == wallet.key() && participation.nft == nft.key())
+    )]
     pub participation: Box<Account<'info, Participation>>,
 
     /// Staked NFT state account
@@ -284,6 +452,41 @@ pub struct CollectRewards<'info> {
     #[account(mut)]
     pub token_account: Option<Box<Account<'info, TokenAccount>>>,
 
+    /// NftEscrow holding the NFT rewards
+    #[account(mut, has_one = mission_pool)]
+    pub nft_escrow: Option<Box<Account<'info, NftEscrow>>>,
+
+    /// Mint address of the rewarded NFT
+    pub reward_mint: Option<Box<Account<'info, Mint>>>,
+
+    /// The account holding the rewarded NFT in escrow
+    #[account(mut)]
+    pub escrow_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Escrow token record
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub escrow_token_record: Option<AccountInfo<'info>>,
+
+    /// Token account of the wallet receiving the rewarded NFT
+    #[account(mut)]
+    pub reward_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Rewarded NFT token metadata
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub reward_metadata: Option<AccountInfo<'info>>,
+
+    /// Rewarded NFT edition
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub reward_edition: Option<AccountInfo<'info>>,
+
+    /// Rewarded NFT token record of the wallet
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub reward_token_record: Option<AccountInfo<'info>>,
+
     #[account(mut)]
     pub wallet: Signer<'info>,
     /// CHECK: This is just used to collect platform fee
@@ -308,6 +511,14 @@ pub struct CollectRewards<'info> {
     /// SPL Token Program
     pub token_program: Program<'info, Token>,
 
+    /// SPL Associated Token Program
+    pub associated_token_program: Option<Program<'info, AssociatedToken>>,
+
+    /// Metaplex Token Metadata Program
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: Option<AccountInfo<'info>>,
+
     /// Solana Rent Sysvar
     pub rent_sysvar: Sysvar<'info, Rent>,
 
@@ -318,6 +529,295 @@ pub struct CollectRewards<'info> {
 
     /// Solana Clock Sysvar
     pub clock: Sysvar<'info, Clock>,
+
+    /// Solana SlotHashes Sysvar
+    /// CHECK: This is not dangerous because we only read the committed slot hash
+    #[account(address = anchor_lang::solana_program::sysvar::slot_hashes::ID)]
+    pub slot_hashes: AccountInfo<'info>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub authorization_rules_program: Option<AccountInfo<'info>>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub authorization_rules: Option<AccountInfo<'info>>,
+}
+
+/// Reveal the reward rolls committed in participate
+/// Once the committed slot aged out of the SlotHashes sysvar the rolls resolve deterministically
+/// to their min amounts and only the rewards that always drop, so delaying the reveal never pays off
+fn reveal_rewards(
+    participation: &mut Account<'_, Participation>,
+    slot_hashes: &AccountInfo,
+    escrows: &mut [Account<NftEscrow>],
+) -> Result<()> {
+    if !participation.is_migrated() {
+        return Err(ErrorCode::ParticipationNotMigrated.into());
+    }
+
+    let reveal_hash =
+        randomness::reveal_hash(&slot_hashes.try_borrow_data()?, participation.reveal_slot)?;
+    let slot_hash = match reveal_hash {
+        randomness::RevealHash::Revealed(slot_hash) => Some(slot_hash),
+        randomness::RevealHash::Pending => return Err(ErrorCode::RandomnessNotAvailable.into()),
+        randomness::RevealHash::Expired => {
+            msg!("The committed slot expired, rewards resolve to their minimum");
+            None
+        }
+    };
+
+    let participation_key = participation.key();
+    let rolls = participation.rolls.clone();
+    for (index, reward) in participation.rewards.iter_mut().enumerate() {
+        let roll = &rolls[index];
+        reward.amount = match (slot_hash, &reward.reward_type) {
+            // The roll of an NFT reward is used to pick from the escrow
+            (Some(slot_hash), RewardType::Nft { .. }) => {
+                let seed = randomness::roll_seed(&slot_hash, &participation_key, index as u8);
+                randomness::random_between(&seed, 0, u64::MAX)
+            }
+            (Some(slot_hash), _) => {
+                let seed = randomness::roll_seed(&slot_hash, &participation_key, index as u8);
+                randomness::random_between(&seed, roll.min, roll.max)
+            }
+            (None, RewardType::Nft { .. }) => 0,
+            (None, _) => roll.min,
+        };
+    }
+
+    let dropped = match slot_hash {
+        Some(slot_hash) => randomness::roll_drops(&rolls, &slot_hash, &participation_key),
+        None => randomness::expired_drops(&rolls),
+    };
+    for (index, is_dropped) in dropped.into_iter().enumerate() {
+        if is_dropped {
+            continue;
+        }
+
+        participation.rewards[index].amount = 0;
+        participation.rewards[index].collected = true;
+        for member in participation.members.iter_mut() {
+            member.rewards[index].collected = true;
+        }
+    }
+
+    // NFT rewards were reserved when participating, a random pick is taken out
+    // of the escrow by its roll and the rewards that did not drop are released
+    for index in 0..participation.rewards.len() {
+        let reward = &participation.rewards[index];
+        let (escrow, mint) = match reward.reward_type {
+            RewardType::Nft { escrow, mint } => (escrow, mint),
+            _ => continue,
+        };
+
+        let nft_escrow = find_escrow(escrows, &escrow)?;
+        if reward.collected {
+            nft_escrow.release(&mint);
+            continue;
+        }
+
+        if mint.is_none() {
+            let picked = nft_escrow.pick(reward.amount);
+            if picked.is_none() {
+                return Err(ErrorCode::NftRewardNotAvailable.into());
+            }
+
+            let reward_type = RewardType::Nft {
+                escrow,
+                mint: picked,
+            };
+            for member in participation.members.iter_mut() {
+                member.rewards[index].reward_type = reward_type.clone();
+            }
+            participation.rewards[index].reward_type = reward_type;
+        }
+    }
+
+    for nft_escrow in escrows.iter() {
+        nft_escrow.exit(&crate::ID)?;
+    }
+
+    // Party rewards are rolled once and then split among the members
+    let rewards = participation.rewards.clone();
+    for (index, reward) in rewards.iter().enumerate() {
+        if reward.collected {
+            continue;
+        }
+
+        if let RewardType::Nft { .. } = reward.reward_type {
+            if !participation.is_party() {
+                continue;
+            }
+
+            // An NFT can not be split, it goes to a single member picked by the shares,
+            // the first member with a share once expired
+            let mut roll = match slot_hash {
+                Some(slot_hash) => {
+                    let seed = randomness::roll_seed(
+                        &slot_hash,
+                        &participation_key,
+                        (rewards.len() + index) as u8,
+                    );
+                    randomness::random_between(&seed, 0, ShareRule::TOTAL_SHARES as u64 - 1)
+                }
+                None => 0,
+            };
+            let mut winner = 0;
+            for (member_index, member) in participation.members.iter().enumerate() {
+                if roll < member.share as u64 {
+                    winner = member_index;
+                    break;
+                }
+                roll -= member.share as u64;
+            }
+
+            for (member_index, member) in participation.members.iter_mut().enumerate() {
+                member.rewards[index].amount = reward.amount;
+                member.rewards[index].collected = member_index != winner;
+            }
+            continue;
+        }
+
+        let mut remaining = reward.amount;
+        for member in participation.members.iter_mut().rev() {
+            let amount = (reward.amount as u128 * member.share as u128
+                / ShareRule::TOTAL_SHARES as u128) as u64;
+            member.rewards[index].amount = amount;
+            remaining -= amount;
+        }
+
+        if let Some(chief) = participation.members.first_mut() {
+            chief.rewards[index].amount += remaining;
+        }
+    }
+    participation.is_revealed = true;
+
+    Ok(())
+}
+
+/// Accounts used in reveal rewards instruction
+/// The escrows of the NFT rewards are passed in remaining accounts
+#[derive(Accounts)]
+pub struct RevealRewards<'info> {
+    #[account(mut)]
+    pub project: Box<Account<'info, Project>>,
+
+    #[account(has_one = project)]
+    pub mission_pool: Box<Account<'info, MissionPool>>,
+
+    /// Mission state account
+    #[account(has_one = mission_pool)]
+    pub mission: Box<Account<'info, Mission>>,
+
+    /// Participation state account
+    #[account(mut, has_one = mission)]
+    pub participation: Box<Account<'info, Participation>>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: This is just used to collect platform fee
+    #[account(mut)]
+    pub vault: AccountInfo<'info>,
+
+    /// Solana System Program
+    pub system_program: Program<'info, System>,
+
+    /// HPL Hive Control Program
+    pub hive_control: Program<'info, HplHiveControl>,
+
+    /// Solana Instructions Sysvar
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    /// Solana SlotHashes Sysvar
+    /// CHECK: This is not dangerous because we only read the committed slot hash
+    #[account(address = anchor_lang::solana_program::sysvar::slot_hashes::ID)]
+    pub slot_hashes: AccountInfo<'info>,
+}
+
+/// Reveal the reward rolls of a participation
+/// Anyone can reveal them once the committed slot is produced, the outcome is fixed by then
+pub fn reveal_participation_rewards<'info>(
+    ctx: Context<'_, '_, '_, 'info, RevealRewards<'info>>,
+) -> Result<()> {
+    if ctx.accounts.participation.is_revealed {
+        return Err(ErrorCode::RewardsAlreadyRevealed.into());
+    }
+
+    let mut escrows = load_escrows(ctx.remaining_accounts, &ctx.accounts.mission_pool.key())?;
+    reveal_rewards(
+        &mut ctx.accounts.participation,
+        &ctx.accounts.slot_hashes,
+        &mut escrows,
+    )
+}
+
+/// Accounts used in migrate participation instruction
+#[derive(Accounts)]
+pub struct MigrateParticipation<'info> {
+    #[account(mut)]
+    pub project: Box<Account<'info, Project>>,
+
+    #[account(has_one = project)]
+    pub mission_pool: Box<Account<'info, MissionPool>>,
+
+    /// Mission state account
+    #[account(has_one = mission_pool)]
+    pub mission: Box<Account<'info, Mission>>,
+
+    /// Participation state account in the layout it was started with
+    /// CHECK: This is not dangerous because the owner is checked and the account is deserialized once reallocated
+    #[account(mut, owner = crate::ID)]
+    pub participation: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: This is just used to collect platform fee
+    #[account(mut)]
+    pub vault: AccountInfo<'info>,
+
+    /// Solana System Program
+    pub system_program: Program<'info, System>,
+
+    /// HPL Hive Control Program
+    pub hive_control: Program<'info, HplHiveControl>,
+
+    /// Solana Rent Sysvar
+    pub rent_sysvar: Sysvar<'info, Rent>,
+
+    /// Solana Instructions Sysvar
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+/// Migrate a participation started before the reward rolls were committed
+/// Its rewards were already rolled, so it is marked revealed and its start_time
+/// is derived from the end_time and the current mission duration
+pub fn migrate_participation(ctx: Context<MigrateParticipation>) -> Result<()> {
+    let participation_info = &ctx.accounts.participation;
+    if Participation::try_deserialize(&mut &participation_info.try_borrow_data()?[..]).is_err() {
+        hpl_utils::reallocate(
+            isize::try_from(Participation::APPENDED_LEN).unwrap(),
+            participation_info.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.rent_sysvar,
+            &ctx.accounts.system_program,
+        )?;
+    }
+
+    let mut participation = Account::<Participation>::try_from(participation_info)?;
+    if participation.mission != ctx.accounts.mission.key() {
+        return Err(ErrorCode::InvalidParticipation.into());
+    }
+
+    if !participation.is_migrated() {
+        participation.start_time = participation.end_time - ctx.accounts.mission.duration;
+        participation.is_revealed = true;
+        participation.exit(&crate::ID)?;
+    }
+
+    Ok(())
 }
 
 /// Collect rewards
@@ -330,24 +830,60 @@ pub fn collect_rewards(ctx: Context<CollectRewards>) -> Result<()> {
         return Err(ErrorCode::NotEnded.into());
     }
 
+    // The escrows of NFT rewards are only passed to reveal_rewards
+    if !ctx.accounts.participation.is_revealed {
+        if ctx.accounts.participation.has_nft_rewards() {
+            return Err(ErrorCode::RewardsNotRevealed.into());
+        }
+
+        reveal_rewards(
+            &mut ctx.accounts.participation,
+            &ctx.accounts.slot_hashes,
+            &mut [],
+        )?;
+    }
+
+    let participation = &mut ctx.accounts.participation;
+    let rewards = if participation.is_party() {
+        let wallet = ctx.accounts.wallet.key();
+        let nft = ctx.accounts.nft.key();
+        let member = participation
+            .members
+            .iter_mut()
+            .find(|member| member.wallet == wallet && member.nft == nft);
+
+        if member.is_none() {
+            return Err(ErrorCode::NotAPartyMember.into());
+        }
+
+        &mut member.unwrap().rewards
+    } else {
+        &mut participation.rewards
+    };
+
     let mut reward_serial_no: u8 = 0;
-    let reward = ctx
-        .accounts
-        .participation
-        .rewards
-        .iter_mut()
-        .find(|reward| {
-            reward_serial_no += 1;
-            if ctx.accounts.currency.is_some() {
-                reward.reward_type
-                    == RewardType::Currency {
-                        address: ctx.accounts.currency.as_ref().unwrap().key(),
-                    }
-                    && !reward.collected
-            } else {
-                reward.reward_type == RewardType::Xp && !reward.collected
+    let reward = rewards.iter_mut().find(|reward| {
+        reward_serial_no += 1;
+        if let Some(nft_escrow) = &ctx.accounts.nft_escrow {
+            match reward.reward_type {
+                RewardType::Nft { escrow, mint } => {
+                    escrow == nft_escrow.key()
+                        && mint.is_some()
+                        && mint == ctx.accounts.reward_mint.as_ref().map(|x| x.key())
+                        && !reward.collected
+                }
+                _ => false,
             }
-        });
+        } else if ctx.accounts.currency.is_some() {
+            reward.reward_type
+                == RewardType::Currency {
+                    address: ctx.accounts.currency.as_ref().unwrap().key(),
+                }
+                && !reward.collected
+        } else {
+            reward.reward_type == RewardType::Xp && !reward.collected
+        }
+    });
 
     if reward.is_none() {
         return Err(ErrorCode::RewardNotAvailable.into());
@@ -413,20 +949,91 @@ pub fn collect_rewards(ctx: Context<CollectRewards>) -> Result<()> {
                 reward.amount,
             )
         }
+        RewardType::Nft { escrow: _, mint } => {
+            if ctx.accounts.reward_mint.is_none()
+                || ctx.accounts.escrow_account.is_none()
+                || ctx.accounts.reward_account.is_none()
+                || ctx.accounts.reward_metadata.is_none()
+                || ctx.accounts.reward_edition.is_none()
+                || ctx.accounts.associated_token_program.is_none()
+                || ctx.accounts.token_metadata_program.is_none()
+            {
+                return Err(ErrorCode::NftAccountsNotProvided.into());
+            }
+
+            // The mint was taken out of the escrow when participating or revealed,
+            // its space is given back once delivered
+            let mint = mint.unwrap();
+            let reward_mint = ctx.accounts.reward_mint.as_ref().unwrap();
+            hpl_utils::reallocate(
+                -32,
+                ctx.accounts.nft_escrow.as_ref().unwrap().to_account_info(),
+                ctx.accounts.wallet.to_account_info(),
+                &ctx.accounts.rent_sysvar,
+                &ctx.accounts.system_program,
+            )?;
+
+            let escrow_account = ctx.accounts.escrow_account.as_ref().unwrap();
+            let reward_account = ctx.accounts.reward_account.as_ref().unwrap();
+            if escrow_account.mint != mint
+                || escrow_account.owner != ctx.accounts.mission_pool.key()
+                || reward_account.mint != mint
+                || reward_account.owner != ctx.accounts.wallet.key()
+            {
+                return Err(ErrorCode::NftRewardNotAvailable.into());
+            }
+
+            let mission_pool_seeds = &[
+                b"mission_pool".as_ref(),
+                ctx.accounts.mission_pool.project.as_ref(),
+                ctx.accounts.mission_pool.name.as_bytes(),
+                &[ctx.accounts.mission_pool.bump],
+            ];
+            let signer = &[&mission_pool_seeds[..]];
+
+            hpl_utils::transfer(
+                1,
+                escrow_account.to_account_info(),
+                ctx.accounts.mission_pool.to_account_info(),
+                reward_account.to_account_info(),
+                ctx.accounts.wallet.to_account_info(),
+                reward_mint.to_account_info(),
+                ctx.accounts.reward_metadata.clone().unwrap(),
+                ctx.accounts.reward_edition.clone(),
+                ctx.accounts.escrow_token_record.clone(),
+                ctx.accounts.reward_token_record.clone(),
+                ctx.accounts.mission_pool.to_account_info(),
+                ctx.accounts.wallet.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts
+                    .associated_token_program
+                    .clone()
+                    .unwrap()
+                    .to_account_info(),
+                ctx.accounts.instructions_sysvar.to_account_info(),
+                ctx.accounts.authorization_rules_program.clone(),
+                ctx.accounts.authorization_rules.clone(),
+                Some(signer),
+            )?;
+
+            token::close_account(CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                CloseAccount {
+                    account: escrow_account.to_account_info(),
+                    destination: ctx.accounts.wallet.to_account_info(),
+                    authority: ctx.accounts.mission_pool.to_account_info(),
+                },
+                signer,
+            ))
+        }
         RewardType::Xp => {
             if ctx.accounts.profile.is_none() {
                 return Err(ErrorCode::ProfileNotProvided.into());
             }
 
             let profile = ctx.accounts.profile.clone().unwrap();
-            let mut xp = reward.amount;
-
-            if let Some(profile_data) = profile.app_context.get("nectar_missions_xp") {
-                match profile_data {
-                    ProfileData::SingleValue(value) => xp += value.parse::<u64>().unwrap(),
-                    _ => {}
-                }
-            }
+            let xp = profile_xp(&profile)? + reward.amount;
 
             manage_profile_data(
                 CpiContext::new(
@@ -457,14 +1064,360 @@ pub fn collect_rewards(ctx: Context<CollectRewards>) -> Result<()> {
     res
 }
 
+/// Accounts used in collect all rewards instruction
+/// Every currency is passed in remaining accounts as [currency, mint, holder_account, token_account]
+#[derive(Accounts)]
+pub struct CollectAllRewards<'info> {
+    #[account(mut)]
+    pub project: Box<Account<'info, Project>>,
+
+    #[account(has_one = project)]
+    pub mission_pool: Box<Account<'info, MissionPool>>,
+
+    /// MissionsPool delegate account for this project
+    /// It is required to mint rewards
+    #[account(has_one = project, constraint = mission_pool_delegate.authority.eq(&mission_pool.key()))]
+    pub mission_pool_delegate: Option<Box<Account<'info, DelegateAuthority>>>,
+
+    /// Mission state account
+    #[account(has_one = mission_pool)]
+    pub mission: Box<Account<'info, Mission>>,
+
+    /// Participation state account
+    #[account(
+        mut,
+        has_one = mission,
+        constraint = participation.is_party() || (participation.wallet == wallet.key() && participation.nft == nft.key())
+    )]
+    pub participation: Box<Account<'info, Participation>>,
+
+    /// Staked NFT state account
+    #[account(mut)]
+    pub nft: Box<Account<'info, NFTv1>>,
+
+    /// User profile account
+    #[account(mut, has_one = project, constraint = profile.identity == ProfileIdentity::Main )]
+    pub profile: Option<Box<Account<'info, Profile>>>,
+
+    /// StakingPool state account, required to recall
+    #[account(mut, has_one = project)]
+    pub staking_pool: Option<Box<Account<'info, StakingPool>>>,
+
+    /// Staker state account, required to recall
+    #[account(has_one = wallet)]
+    pub staker: Option<Box<Account<'info, Staker>>>,
+
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+    /// CHECK: This is just used to collect platform fee
+    #[account(mut)]
+    pub vault: AccountInfo<'info>,
+
+    /// Solana System Program
+    pub system_program: Program<'info, System>,
+
+    /// HPL Hive Control Program
+    pub hive_control: Program<'info, HplHiveControl>,
+
+    /// HPL Currency Manager Program
+    pub currency_manager_program: Program<'info, HplCurrencyManager>,
+
+    /// HPL Nectar Staking Program, required to recall
+    pub nectar_staking_program: Option<Program<'info, HplNectarStaking>>,
+
+    /// HPL Events Program
+    pub hpl_events: Program<'info, HplEvents>,
+
+    /// SPL Token Program
+    pub token_program: Program<'info, Token>,
+
+    /// Solana Rent Sysvar
+    pub rent_sysvar: Sysvar<'info, Rent>,
+
+    /// Solana Instructions Sysvar
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    /// Solana Clock Sysvar
+    pub clock: Sysvar<'info, Clock>,
+
+    /// Solana SlotHashes Sysvar
+    /// CHECK: This is not dangerous because we only read the committed slot hash
+    #[account(address = anchor_lang::solana_program::sysvar::slot_hashes::ID)]
+    pub slot_hashes: AccountInfo<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CollectAllRewardsArgs {
+    /// Recall the NFT once all rewards are collected
+    /// NFT rewards must be collected with collect_rewards before, parties can not be recalled here
+    pub recall: bool,
+}
+
+/// Collect all rewards of a participation, NFT rewards are collected with collect_rewards
+/// Recalling is only possible once the NFT rewards are collected and not for party members,
+/// who are recalled with recall_party_member
+pub fn collect_all_rewards<'info>(
+    ctx: Context<'_, '_, '_, 'info, CollectAllRewards<'info>>,
+    args: CollectAllRewardsArgs,
+) -> Result<()> {
+    if ctx.accounts.nft.last_staked_at < ctx.accounts.nft.last_unstaked_at {
+        return Err(ErrorCode::NotStaked.into());
+    }
+
+    if ctx.accounts.participation.end_time > ctx.accounts.clock.unix_timestamp {
+        return Err(ErrorCode::NotEnded.into());
+    }
+
+    // The escrows of NFT rewards are only passed to reveal_rewards
+    if !ctx.accounts.participation.is_revealed {
+        if ctx.accounts.participation.has_nft_rewards() {
+            return Err(ErrorCode::RewardsNotRevealed.into());
+        }
+
+        reveal_rewards(
+            &mut ctx.accounts.participation,
+            &ctx.accounts.slot_hashes,
+            &mut [],
+        )?;
+    }
+
+    // Recalling closes the participation, so nothing may be left to collect after this call
+    if args.recall {
+        if ctx.accounts.participation.is_party() {
+            return Err(ErrorCode::PartyRecallNotAllowed.into());
+        }
+
+        if ctx
+            .accounts
+            .participation
+            .rewards
+            .iter()
+            .any(|reward| !reward.collected && matches!(reward.reward_type, RewardType::Nft { .. }))
+        {
+            return Err(ErrorCode::NftRewardsPending.into());
+        }
+
+        if ctx.accounts.staking_pool.is_none()
+            || ctx.accounts.staker.is_none()
+            || ctx.accounts.nectar_staking_program.is_none()
+        {
+            return Err(ErrorCode::RecallAccountsNotProvided.into());
+        }
+    }
+
+    let participation = &mut ctx.accounts.participation;
+    let is_party = participation.is_party();
+    let rewards = if is_party {
+        let wallet = ctx.accounts.wallet.key();
+        let nft = ctx.accounts.nft.key();
+        let member = participation
+            .members
+            .iter_mut()
+            .find(|member| member.wallet == wallet && member.nft == nft);
+
+        if member.is_none() {
+            return Err(ErrorCode::NotAPartyMember.into());
+        }
+
+        &mut member.unwrap().rewards
+    } else {
+        &mut participation.rewards
+    };
+
+    let mission_pool_seeds = &[
+        b"mission_pool".as_ref(),
+        ctx.accounts.mission_pool.project.as_ref(),
+        ctx.accounts.mission_pool.name.as_bytes(),
+        &[ctx.accounts.mission_pool.bump],
+    ];
+    let signer = &[&mission_pool_seeds[..]];
+
+    for accounts in ctx.remaining_accounts.chunks(4) {
+        if accounts.len() < 4 {
+            return Err(ErrorCode::HolderAccountsNotProvided.into());
+        }
+
+        let currency = Account::<Currency>::try_from(&accounts[0])?;
+        let holder_account = Account::<HolderAccount>::try_from(&accounts[2])?;
+        if currency.mint != accounts[1].key()
+            || holder_account.currency != currency.key()
+            || holder_account.token_account != accounts[3].key()
+            || holder_account.owner != ctx.accounts.wallet.key()
+        {
+            return Err(ErrorCode::HolderAccountsNotProvided.into());
+        }
+
+        let mut amount: u64 = 0;
+        for reward in rewards.iter_mut() {
+            if reward.collected
+                || reward.reward_type
+                    != (RewardType::Currency {
+                        address: currency.key(),
+                    })
+            {
+                continue;
+            }
+
+            reward.collected = true;
+            amount = amount
+                .checked_add(reward.amount)
+                .ok_or(ErrorCode::Overflow)?;
+        }
+
+        if amount == 0 {
+            continue;
+        }
+
+        if ctx.accounts.mission_pool_delegate.is_none() {
+            return Err(ErrorCode::HolderAccountsNotProvided.into());
+        }
+
+        mint_currency(
+            CpiContext::new_with_signer(
+                ctx.accounts.currency_manager_program.to_account_info(),
+                MintCurrency {
+                    project: ctx.accounts.project.to_account_info(),
+                    currency: currency.to_account_info(),
+                    mint: accounts[1].to_account_info(),
+                    holder_account: holder_account.to_account_info(),
+                    token_account: accounts[3].to_account_info(),
+                    delegate_authority: Some(
+                        ctx.accounts
+                            .mission_pool_delegate
+                            .clone()
+                            .unwrap()
+                            .to_account_info(),
+                    ),
+                    authority: ctx.accounts.mission_pool.to_account_info(),
+                    payer: ctx.accounts.wallet.to_account_info(),
+                    instructions_sysvar: ctx.accounts.instructions_sysvar.to_account_info(),
+                    vault: ctx.accounts.vault.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    hive_control: ctx.accounts.hive_control.to_account_info(),
+                    token_program: ctx.accounts.token_program.to_account_info(),
+                },
+                signer,
+            ),
+            amount,
+        )?;
+    }
+
+    let mut xp: u64 = 0;
+    let mut has_xp = false;
+    for reward in rewards.iter_mut() {
+        if reward.collected || reward.reward_type != RewardType::Xp {
+            continue;
+        }
+
+        reward.collected = true;
+        has_xp = true;
+        xp = xp.checked_add(reward.amount).ok_or(ErrorCode::Overflow)?;
+    }
+
+    if has_xp {
+        if ctx.accounts.profile.is_none() {
+            return Err(ErrorCode::ProfileNotProvided.into());
+        }
+
+        let profile = ctx.accounts.profile.clone().unwrap();
+        let xp = profile_xp(&profile)? + xp;
+
+        manage_profile_data(
+            CpiContext::new(
+                ctx.accounts.hive_control.to_account_info(),
+                ManageProfileData {
+                    project: ctx.accounts.project.to_account_info(),
+                    profile: profile.to_account_info(),
+                    delegate_authority: None,
+                    authority: ctx.accounts.wallet.to_account_info(),
+                    payer: ctx.accounts.wallet.to_account_info(),
+                    rent_sysvar: ctx.accounts.rent_sysvar.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    hpl_events: ctx.accounts.hpl_events.to_account_info(),
+                    clock: ctx.accounts.clock.to_account_info(),
+                    vault: ctx.accounts.vault.to_account_info(),
+                    instructions_sysvar: ctx.accounts.instructions_sysvar.to_account_info(),
+                },
+            ),
+            ManageProfileDataArgs {
+                label: String::from("nectar_missions_xp"),
+                value: Some(ProfileData::SingleValue(xp.to_string())),
+                is_app_context: true,
+            },
+        )?;
+    }
+
+    if rewards.iter().any(|reward| !reward.collected) {
+        if args.recall {
+            return Err(ErrorCode::RewardsNotCollected.into());
+        }
+
+        msg!("NFT rewards are left to be collected with collect_rewards");
+    }
+
+    if !args.recall {
+        return Ok(());
+    }
+
+    let staking_pool = ctx.accounts.staking_pool.clone().unwrap();
+    let staker = ctx.accounts.staker.clone().unwrap();
+    if ctx.accounts.nft.staking_pool != staking_pool.key()
+        || ctx.accounts.nft.staker != Some(staker.key())
+        || staker.staking_pool != staking_pool.key()
+    {
+        return Err(ErrorCode::RecallAccountsNotProvided.into());
+    }
+
+    // The NFT is already released if it was recalled early
+    if !participation.is_recalled {
+        participation.is_recalled = true;
+
+        use_nft(
+            CpiContext::new(
+                ctx.accounts
+                    .nectar_staking_program
+                    .clone()
+                    .unwrap()
+                    .to_account_info(),
+                UseNft {
+                    project: ctx.accounts.project.to_account_info(),
+                    staking_pool: staking_pool.to_account_info(),
+                    staker: staker.to_account_info(),
+                    nft: ctx.accounts.nft.to_account_info(),
+                    wallet: ctx.accounts.wallet.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    hive_control: ctx.accounts.hive_control.to_account_info(),
+                    hpl_events: ctx.accounts.hpl_events.to_account_info(),
+                    clock: ctx.accounts.clock.to_account_info(),
+                    instructions_sysvar: ctx.accounts.instructions_sysvar.to_account_info(),
+                    vault: ctx.accounts.vault.to_account_info(),
+                },
+            ),
+            NFTUsedBy::None,
+        )?;
+    }
+
+    events::Event::recall_participation(
+        participation.key(),
+        participation.try_to_vec().unwrap(),
+        &ctx.accounts.clock,
+    )
+    .emit(ctx.accounts.hpl_events.to_account_info())?;
+
+    participation.close(ctx.accounts.wallet.to_account_info())
+}
+
 /// Accounts used in recall instruction
+/// The escrows of NFT rewards that are forfeited are passed in remaining accounts
 #[derive(Accounts)]
 pub struct Recall<'info> {
     #[account(mut)]
     pub project: Box<Account<'info, Project>>,
 
     /// StakingPool state account
-    #[account(has_one = project)]
+    #[account(mut, has_one = project)]
     pub staking_pool: Box<Account<'info, StakingPool>>,
 
     /// NFT state account
@@ -484,14 +1437,33 @@ pub struct Recall<'info> {
     pub mission: Box<Account<'info, Mission>>,
 
     /// Participation state account
-    #[account(mut, has_one = wallet, has_one = mission, close = wallet)]
+    #[account(mut, has_one = wallet, has_one = mission, constraint = !participation.is_party())]
     pub participation: Box<Account<'info, Participation>>,
 
+    /// Currency of the recall penalty
+    #[account(has_one = mint, constraint = mission.cost.address == currency.key())]
+    pub currency: Option<Box<Account<'info, Currency>>>,
+
+    #[account(mut)]
+    pub mint: Option<Box<Account<'info, Mint>>>,
+
+    #[account(has_one = currency, has_one = token_account, constraint = holder_account.owner == wallet.key())]
+    pub holder_account: Option<Box<Account<'info, HolderAccount>>>,
+
+    #[account(mut)]
+    pub token_account: Option<Box<Account<'info, TokenAccount>>>,
+
     #[account(mut)]
     pub wallet: Signer<'info>,
 
     pub system_program: Program<'info, System>,
 
+    /// HPL Currency Manager Program
+    pub currency_manager_program: Option<Program<'info, HplCurrencyManager>>,
+
+    /// SPL Token Program
+    pub token_program: Option<Program<'info, Token>>,
+
     /// HPL Hive Control Program
     pub hive_control: Program<'info, HplHiveControl>,
 
@@ -502,45 +1474,137 @@ pub struct Recall<'info> {
     /// CHECK: This is not dangerous because we don't read or write from this account
     #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
     pub instructions_sysvar: AccountInfo<'info>,
+    /// Solana SlotHashes Sysvar
+    /// CHECK: This is not dangerous because we only read the committed slot hash
+    #[account(address = anchor_lang::solana_program::sysvar::slot_hashes::ID)]
+    pub slot_hashes: AccountInfo<'info>,
     /// CHECK: This is not dangerous because we don't read or write from this account
     #[account(mut)]
     pub vault: AccountInfo<'info>,
 }
 
 /// recall from a mission
-pub fn recall(ctx: Context<Recall>) -> Result<()> {
+/// Before the mission ends the recall policy of the mission is applied
+pub fn recall<'info>(ctx: Context<'_, '_, '_, 'info, Recall<'info>>) -> Result<()> {
+    let mut escrows = load_escrows(ctx.remaining_accounts, &ctx.accounts.mission_pool.key())?;
     let participation = &mut ctx.accounts.participation;
+    let now = ctx.accounts.clock.unix_timestamp;
+    let is_released = participation.is_recalled;
+    let is_early = participation.end_time > now;
+
+    if is_early {
+        match ctx.accounts.mission.recall_policy {
+            RecallPolicy::Disabled => return Err(ErrorCode::NotEnded.into()),
+            RecallPolicy::ForfeitAll => {
+                forfeit_nft_rewards(&mut participation.rewards, &mut escrows)?;
+                participation
+                    .rewards
+                    .iter_mut()
+                    .for_each(|reward| reward.collected = true);
+            }
+            RecallPolicy::ProRated => {
+                // The rolled amounts are scaled, so the rolls are revealed first
+                if !participation.is_revealed {
+                    reveal_rewards(participation, &ctx.accounts.slot_hashes, &mut escrows)?;
+                }
+
+                // An NFT can not be scaled, it is forfeited
+                forfeit_nft_rewards(&mut participation.rewards, &mut escrows)?;
+
+                // The mission may have been updated since, the duration participated for is used
+                let duration = (participation.end_time - participation.start_time).max(1) as u128;
+                let elapsed = (now - participation.start_time).max(0) as u128;
+
+                for reward in participation.rewards.iter_mut() {
+                    reward.amount = (reward.amount as u128 * elapsed / duration) as u64;
+                }
+            }
+            RecallPolicy::Penalty { amount } => {
+                if ctx.accounts.currency.is_none()
+                    || ctx.accounts.mint.is_none()
+                    || ctx.accounts.holder_account.is_none()
+                    || ctx.accounts.token_account.is_none()
+                    || ctx.accounts.currency_manager_program.is_none()
+                    || ctx.accounts.token_program.is_none()
+                {
+                    return Err(ErrorCode::PenaltyAccountsNotProvided.into());
+                }
+
+                burn_currency(
+                    CpiContext::new(
+                        ctx.accounts
+                            .currency_manager_program
+                            .clone()
+                            .unwrap()
+                            .to_account_info(),
+                        BurnCurrency {
+                            project: ctx.accounts.project.to_account_info(),
+                            currency: ctx.accounts.currency.clone().unwrap().to_account_info(),
+                            mint: ctx.accounts.mint.clone().unwrap().to_account_info(),
+                            holder_account: ctx
+                                .accounts
+                                .holder_account
+                                .clone()
+                                .unwrap()
+                                .to_account_info(),
+                            token_account: ctx
+                                .accounts
+                                .token_account
+                                .clone()
+                                .unwrap()
+                                .to_account_info(),
+                            authority: ctx.accounts.wallet.to_account_info(),
+                            payer: ctx.accounts.wallet.to_account_info(),
+                            instructions_sysvar: ctx.accounts.instructions_sysvar.to_account_info(),
+                            vault: ctx.accounts.vault.to_account_info(),
+                            system_program: ctx.accounts.system_program.to_account_info(),
+                            hive_control: ctx.accounts.hive_control.to_account_info(),
+                            token_program: ctx
+                                .accounts
+                                .token_program
+                                .clone()
+                                .unwrap()
+                                .to_account_info(),
+                        },
+                    ),
+                    amount,
+                )?;
+            }
+        }
 
-    let reward = participation
-        .rewards
-        .iter()
-        .find(|reward| !reward.collected);
+        // Rewards that are kept become collectable right away
+        participation.end_time = now;
+    }
 
-    if reward.is_some() {
+    let is_settled = participation.rewards.iter().all(|reward| reward.collected);
+    if !is_settled && !is_early {
         return Err(ErrorCode::RewardsNotCollected.into());
     }
 
     participation.is_recalled = true;
 
-    use_nft(
-        CpiContext::new(
-            ctx.accounts.nectar_staking_program.to_account_info(),
-            UseNft {
-                project: ctx.accounts.project.to_account_info(),
-                staking_pool: ctx.accounts.staking_pool.to_account_info(),
-                staker: ctx.accounts.staker.to_account_info(),
-                nft: ctx.accounts.nft.to_account_info(),
-                wallet: ctx.accounts.wallet.to_account_info(),
-                system_program: ctx.accounts.system_program.to_account_info(),
-                hive_control: ctx.accounts.hive_control.to_account_info(),
-                hpl_events: ctx.accounts.hpl_events.to_account_info(),
-                clock: ctx.accounts.clock.to_account_info(),
-                instructions_sysvar: ctx.accounts.instructions_sysvar.to_account_info(),
-                vault: ctx.accounts.vault.to_account_info(),
-            },
-        ),
-        NFTUsedBy::None,
-    )?;
+    // The NFT is released once, either on an early recall or when settled
+    if !is_released {
+        use_nft(
+            CpiContext::new(
+                ctx.accounts.nectar_staking_program.to_account_info(),
+                UseNft {
+                    project: ctx.accounts.project.to_account_info(),
+                    staking_pool: ctx.accounts.staking_pool.to_account_info(),
+                    staker: ctx.accounts.staker.to_account_info(),
+                    nft: ctx.accounts.nft.to_account_info(),
+                    wallet: ctx.accounts.wallet.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    hive_control: ctx.accounts.hive_control.to_account_info(),
+                    hpl_events: ctx.accounts.hpl_events.to_account_info(),
+                    clock: ctx.accounts.clock.to_account_info(),
+                    instructions_sysvar: ctx.accounts.instructions_sysvar.to_account_info(),
+                    vault: ctx.accounts.vault.to_account_info(),
+                },
+            ),
+            NFTUsedBy::None,
+        )?;
+    }
 
     events::Event::recall_participation(
         participation.key(),
@@ -548,5 +1612,11 @@ pub fn recall(ctx: Context<Recall>) -> Result<()> {
         &ctx.accounts.clock,
     )
     .emit(ctx.accounts.hpl_events.to_account_info())?;
+
+    // Kept rewards of an early recall are collected before the participation is closed
+    if is_settled {
+        participation.close(ctx.accounts.wallet.to_account_info())?;
+    }
+
     Ok(())
 }
@@ -0,0 +1,434 @@
+use {
+    super::partipcation_instruction::{
+        assert_min_xp, assert_staking_pool, load_escrows, reserve_nft_rewards, verify_faction,
+    },
+    crate::{errors::ErrorCode, state::*},
+    anchor_lang::prelude::*,
+    anchor_spl::token::{self, Mint, Token, TokenAccount},
+    hpl_currency_manager::{
+        cpi::{accounts::BurnCurrency, burn_currency},
+        program::HplCurrencyManager,
+        state::{Currency, HolderAccount},
+    },
+    hpl_events::HplEvents,
+    hpl_hive_control::{
+        program::HplHiveControl,
+        state::{Profile, ProfileIdentity, Project, User},
+    },
+    hpl_nectar_staking::{
+        cpi::{accounts::UseNft, use_nft},
+        program::HplNectarStaking,
+        state::{GuildRole, NFTUsedBy, NFTv1, Staker, StakingPool},
+    },
+    hpl_utils::traits::Default,
+};
+
+/// Accounts used in participate party instruction
+/// Every other member is passed as remaining accounts in groups of [nft, staker, wallet]
+/// followed by the escrows of the mission NFT rewards
+#[derive(Accounts)]
+pub struct ParticipateParty<'info> {
+    #[account(mut)]
+    pub project: Box<Account<'info, Project>>,
+
+    /// StakingPool state account
+    #[account(mut, has_one = project)]
+    pub staking_pool: Box<Account<'info, StakingPool>>,
+
+    /// MissionPool account
+    #[account(has_one = project)]
+    pub mission_pool: Box<Account<'info, MissionPool>>,
+
+    /// Mission state account
+    #[account(has_one = mission_pool)]
+    pub mission: Box<Account<'info, Mission>>,
+
+    /// NFT state account of the party chief
+    #[account(mut, has_one = staking_pool, constraint = nft.staker.is_some() && nft.staker.unwrap().eq(&staker.key()))]
+    pub nft: Box<Account<'info, NFTv1>>,
+
+    /// Staker state account of the party chief
+    #[account(has_one = staking_pool, has_one = wallet)]
+    pub staker: Box<Account<'info, Staker>>,
+
+    /// Hive control user of the party chief
+    #[account(constraint = user.primary_wallet == wallet.key() || user.secondary_wallets.contains(&wallet.key()))]
+    pub user: Option<Box<Account<'info, User>>>,
+
+    /// User profile account of the party chief
+    #[account(has_one = project, constraint = profile.identity == ProfileIdentity::Main)]
+    pub profile: Option<Box<Account<'info, Profile>>>,
+
+    #[account(has_one = mint, constraint = mission.cost.address == currency.key())]
+    pub currency: Box<Account<'info, Currency>>,
+    #[account(mut)]
+    pub mint: Box<Account<'info, Mint>>,
+    #[account(has_one = currency, has_one = token_account, constraint = holder_account.owner == wallet.key())]
+    pub holder_account: Box<Account<'info, HolderAccount>>,
+    #[account(mut)]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Participation state account
+    #[account(
+      init, payer = wallet,
+      space = Participation::LEN,
+      seeds = [
+        b"participation".as_ref(),
+        nft.key().as_ref()
+      ],
+      bump
+    )]
+    pub participation: Box<Account<'info, Participation>>,
+
+    /// The party chief, pays the mission cost for every member
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub vault: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+
+    /// HPL Hive Control Program
+    pub hive_control: Program<'info, HplHiveControl>,
+
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+    pub currency_manager_program: Program<'info, HplCurrencyManager>,
+    pub nectar_staking_program: Program<'info, HplNectarStaking>,
+    pub hpl_events: Program<'info, HplEvents>,
+    pub clock: Sysvar<'info, Clock>,
+    pub rent_sysvar: Sysvar<'info, Rent>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ParticipatePartyArgs {
+    pub share_rule: ShareRule,
+    pub faction: Option<String>,
+    /// Faction merkle proofs of every member NFT, the chief first
+    pub merkle_proofs: Option<Vec<Vec<[u8; 32]>>>,
+}
+
+/// participate in a mission with a party of NFTs
+pub fn participate_party<'info>(
+    ctx: Context<'_, '_, '_, 'info, ParticipateParty<'info>>,
+    args: ParticipatePartyArgs,
+) -> Result<()> {
+    let escrows_len = ctx.accounts.mission.nft_escrows().len();
+    if ctx.remaining_accounts.len() < escrows_len {
+        return Err(ErrorCode::NftAccountsNotProvided.into());
+    }
+    let (member_accounts, escrow_accounts) = ctx
+        .remaining_accounts
+        .split_at(ctx.remaining_accounts.len() - escrows_len);
+
+    if member_accounts.is_empty() || member_accounts.len() % 3 != 0 {
+        return Err(ErrorCode::InvalidPartyMembers.into());
+    }
+
+    assert_staking_pool(
+        &ctx.accounts.project,
+        &ctx.accounts.mission_pool,
+        &ctx.accounts.staking_pool.key(),
+    )?;
+    assert_min_xp(
+        &ctx.accounts.mission,
+        &ctx.accounts.user,
+        &ctx.accounts.profile,
+    )?;
+
+    if ctx.accounts.nft.last_staked_at < ctx.accounts.nft.last_unstaked_at {
+        return Err(ErrorCode::NotStaked.into());
+    }
+
+    let staking_pool_key = ctx.accounts.staking_pool.key();
+    let mut members = vec![(
+        ctx.accounts.nft.key(),
+        ctx.accounts.nft.mint,
+        ctx.accounts.nft.is_compressed,
+        ctx.accounts.wallet.key(),
+    )];
+    for accounts in member_accounts.chunks(3) {
+        let nft = Account::<NFTv1>::try_from(&accounts[0])?;
+        let staker = Account::<Staker>::try_from(&accounts[1])?;
+        let wallet = &accounts[2];
+
+        if !wallet.is_signer
+            || nft.staking_pool != staking_pool_key
+            || staker.staking_pool != staking_pool_key
+            || staker.wallet != wallet.key()
+            || nft.staker != Some(staker.key())
+            || nft.last_staked_at < nft.last_unstaked_at
+            || members.iter().any(|member| member.0 == nft.key())
+        {
+            msg!("Invalid party member {:?}", nft.key());
+            return Err(ErrorCode::InvalidPartyMembers.into());
+        }
+
+        members.push((nft.key(), nft.mint, nft.is_compressed, wallet.key()));
+    }
+
+    let shares = args.share_rule.shares(members.len());
+    if shares.is_none() {
+        return Err(ErrorCode::InvalidShareRule.into());
+    }
+    let shares = shares.unwrap();
+
+    let participation = &mut ctx.accounts.participation;
+    participation.set_defaults();
+    participation.bump = ctx.bumps["participation"];
+    participation.wallet = ctx.accounts.wallet.key();
+    participation.mission = ctx.accounts.mission.key();
+    participation.nft = ctx.accounts.nft.key();
+    participation.start_time = ctx.accounts.clock.unix_timestamp;
+    participation.end_time = ctx.accounts.mission.duration + participation.start_time;
+
+    // Every member of the party must belong to the same faction
+    for (index, (_, mint, _, _)) in members.iter().enumerate() {
+        let faction = verify_faction(
+            &ctx.accounts.mission_pool,
+            &ctx.accounts.mission,
+            mint,
+            args.faction.clone(),
+            args.merkle_proofs
+                .as_ref()
+                .and_then(|proofs| proofs.get(index).cloned()),
+        )?;
+
+        if index > 0 && faction != participation.faction {
+            return Err(ErrorCode::FactionMismatch.into());
+        }
+        participation.faction = faction;
+    }
+
+    // Reward rolls are only committed here and revealed with the hash of a later slot
+    participation.reveal_slot = ctx.accounts.clock.slot + randomness::REVEAL_DELAY_SLOTS;
+    let (rewards, rolls) = ctx.accounts.mission.commit_rewards();
+
+    let faction_len = participation
+        .faction
+        .as_ref()
+        .map_or(0, |faction| faction.len());
+    hpl_utils::reallocate(
+        ((Reward::LEN + RewardRoll::LEN) * rewards.len()
+            + faction_len
+            + (PartyMember::LEN + Reward::LEN * rewards.len()) * members.len()) as isize,
+        participation.to_account_info(),
+        ctx.accounts.wallet.to_account_info(),
+        &ctx.accounts.rent_sysvar,
+        &ctx.accounts.system_program,
+    )?;
+
+    participation.members = members
+        .iter()
+        .zip(shares)
+        .map(|((nft, _, _, wallet), share)| PartyMember {
+            wallet: *wallet,
+            nft: *nft,
+            share,
+            is_recalled: false,
+            rewards: rewards.clone(),
+        })
+        .collect::<Vec<_>>();
+    participation.rewards = rewards;
+    participation.rolls = rolls;
+
+    let mut escrows = load_escrows(escrow_accounts, &ctx.accounts.mission_pool.key())?;
+    reserve_nft_rewards(&participation.rewards, &mut escrows)?;
+
+    let cost = members
+        .iter()
+        .map(|(_, _, is_compressed, _)| ctx.accounts.mission.participation_cost(*is_compressed))
+        .sum::<u64>();
+
+    burn_currency(
+        CpiContext::new(
+            ctx.accounts.currency_manager_program.to_account_info(),
+            BurnCurrency {
+                project: ctx.accounts.project.to_account_info(),
+                currency: ctx.accounts.currency.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                holder_account: ctx.accounts.holder_account.to_account_info(),
+                token_account: ctx.accounts.token_account.to_account_info(),
+                authority: ctx.accounts.wallet.to_account_info(),
+                payer: ctx.accounts.wallet.to_account_info(),
+                instructions_sysvar: ctx.accounts.instructions_sysvar.to_account_info(),
+                vault: ctx.accounts.vault.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                hive_control: ctx.accounts.hive_control.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            },
+        ),
+        cost,
+    )?;
+
+    let participation_key = participation.key();
+    let mut nft_accounts = vec![(
+        ctx.accounts.staker.to_account_info(),
+        ctx.accounts.nft.to_account_info(),
+        ctx.accounts.wallet.to_account_info(),
+        GuildRole::Chief,
+    )];
+    for accounts in member_accounts.chunks(3) {
+        nft_accounts.push((
+            accounts[1].to_account_info(),
+            accounts[0].to_account_info(),
+            accounts[2].to_account_info(),
+            GuildRole::Member,
+        ));
+    }
+
+    for (staker, nft, wallet, role) in nft_accounts {
+        use_nft(
+            CpiContext::new(
+                ctx.accounts.nectar_staking_program.to_account_info(),
+                UseNft {
+                    project: ctx.accounts.project.to_account_info(),
+                    staking_pool: ctx.accounts.staking_pool.to_account_info(),
+                    staker,
+                    nft,
+                    wallet,
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                    hive_control: ctx.accounts.hive_control.to_account_info(),
+                    hpl_events: ctx.accounts.hpl_events.to_account_info(),
+                    clock: ctx.accounts.clock.to_account_info(),
+                    instructions_sysvar: ctx.accounts.instructions_sysvar.to_account_info(),
+                    vault: ctx.accounts.vault.to_account_info(),
+                },
+            ),
+            NFTUsedBy::Guild {
+                id: participation_key,
+                role,
+            },
+        )?;
+    }
+
+    events::Event::new_participation(
+        participation_key,
+        ctx.accounts.participation.try_to_vec().unwrap(),
+        &ctx.accounts.clock,
+    )
+    .emit(ctx.accounts.hpl_events.to_account_info())?;
+
+    Ok(())
+}
+
+/// Accounts used in recall party member instruction
+#[derive(Accounts)]
+pub struct RecallPartyMember<'info> {
+    #[account(mut)]
+    pub project: Box<Account<'info, Project>>,
+
+    /// StakingPool state account
+    #[account(mut, has_one = project)]
+    pub staking_pool: Box<Account<'info, StakingPool>>,
+
+    /// NFT state account of the member
+    #[account(mut, has_one = staking_pool, constraint = nft.staker.is_some() && nft.staker.unwrap().eq(&staker.key()))]
+    pub nft: Box<Account<'info, NFTv1>>,
+
+    /// Staker state account of the member
+    #[account(has_one = staking_pool, has_one = wallet)]
+    pub staker: Box<Account<'info, Staker>>,
+
+    /// MissionPool account
+    #[account(has_one = project)]
+    pub mission_pool: Box<Account<'info, MissionPool>>,
+
+    /// Mission account
+    #[account(has_one = mission_pool)]
+    pub mission: Box<Account<'info, Mission>>,
+
+    /// Participation state account
+    #[account(mut, has_one = mission, constraint = participation.is_party())]
+    pub participation: Box<Account<'info, Participation>>,
+
+    /// The party chief, receives the rent once every member is recalled
+    /// CHECK: This is not dangerous because it is checked against the participation
+    #[account(mut, address = participation.wallet)]
+    pub chief: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+
+    /// HPL Hive Control Program
+    pub hive_control: Program<'info, HplHiveControl>,
+
+    pub nectar_staking_program: Program<'info, HplNectarStaking>,
+    pub hpl_events: Program<'info, HplEvents>,
+    pub clock: Sysvar<'info, Clock>,
+    /// NATIVE INSTRUCTIONS SYSVAR
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub vault: AccountInfo<'info>,
+}
+
+/// recall a member NFT from a party mission
+pub fn recall_party_member(ctx: Context<RecallPartyMember>) -> Result<()> {
+    let participation = &mut ctx.accounts.participation;
+    let wallet = ctx.accounts.wallet.key();
+    let nft = ctx.accounts.nft.key();
+
+    let member = participation
+        .members
+        .iter_mut()
+        .find(|member| member.wallet == wallet && member.nft == nft && !member.is_recalled);
+
+    if member.is_none() {
+        return Err(ErrorCode::NotAPartyMember.into());
+    }
+
+    let member = member.unwrap();
+    if member.rewards.iter().any(|reward| !reward.collected) {
+        return Err(ErrorCode::RewardsNotCollected.into());
+    }
+
+    member.is_recalled = true;
+
+    use_nft(
+        CpiContext::new(
+            ctx.accounts.nectar_staking_program.to_account_info(),
+            UseNft {
+                project: ctx.accounts.project.to_account_info(),
+                staking_pool: ctx.accounts.staking_pool.to_account_info(),
+                staker: ctx.accounts.staker.to_account_info(),
+                nft: ctx.accounts.nft.to_account_info(),
+                wallet: ctx.accounts.wallet.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                hive_control: ctx.accounts.hive_control.to_account_info(),
+                hpl_events: ctx.accounts.hpl_events.to_account_info(),
+                clock: ctx.accounts.clock.to_account_info(),
+                instructions_sysvar: ctx.accounts.instructions_sysvar.to_account_info(),
+                vault: ctx.accounts.vault.to_account_info(),
+            },
+        ),
+        NFTUsedBy::None,
+    )?;
+
+    if participation
+        .members
+        .iter()
+        .all(|member| member.is_recalled)
+    {
+        participation.is_recalled = true;
+
+        events::Event::recall_participation(
+            participation.key(),
+            participation.try_to_vec().unwrap(),
+            &ctx.accounts.clock,
+        )
+        .emit(ctx.accounts.hpl_events.to_account_info())?;
+
+        participation.close(ctx.accounts.chief.to_account_info())?;
+    }
+
+    Ok(())
+}
@@ -2,8 +2,8 @@ use anchor_lang::prelude::*;
 
 pub mod errors;
 pub mod instructions;
+pub mod randomness;
 pub mod state;
-pub mod utils;
 
 declare_id!("HuntaX1CmUt5EByyFPE8pMf13SpvezybmMTtjmpmGmfj");
 
@@ -76,7 +76,77 @@ pub mod hpl_nectar_missions {
         instructions::update_mission(ctx, args)
     }
 
-    pub fn participate(ctx: Context<Participate>, args: ParticipateArgs) -> Result<()> {
+    pub fn migrate_mission(ctx: Context<MigrateMission>) -> Result<()> {
+        platform_gate_cpi(
+            hpl_hive_control::state::SerializableActions::ManageMissionPool,
+            None,
+            ctx.accounts.project.to_account_info(),
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            &ctx.accounts.delegate_authority,
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.hive_control.to_account_info(),
+            ctx.accounts.instructions_sysvar.to_account_info(),
+        )?;
+        instructions::migrate_mission(ctx)
+    }
+
+    pub fn create_nft_escrow(
+        ctx: Context<CreateNftEscrow>,
+        args: CreateNftEscrowArgs,
+    ) -> Result<()> {
+        platform_gate_cpi(
+            hpl_hive_control::state::SerializableActions::ManageMissionPool,
+            None,
+            ctx.accounts.project.to_account_info(),
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            &ctx.accounts.delegate_authority,
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.hive_control.to_account_info(),
+            ctx.accounts.instructions_sysvar.to_account_info(),
+        )?;
+        instructions::create_nft_escrow(ctx, args)
+    }
+
+    pub fn deposit_escrow_nft(ctx: Context<DepositEscrowNft>) -> Result<()> {
+        platform_gate_cpi(
+            hpl_hive_control::state::SerializableActions::ManageMissionPool,
+            None,
+            ctx.accounts.project.to_account_info(),
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            &ctx.accounts.delegate_authority,
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.hive_control.to_account_info(),
+            ctx.accounts.instructions_sysvar.to_account_info(),
+        )?;
+        instructions::deposit_escrow_nft(ctx)
+    }
+
+    pub fn withdraw_escrow_nft(ctx: Context<WithdrawEscrowNft>) -> Result<()> {
+        platform_gate_cpi(
+            hpl_hive_control::state::SerializableActions::ManageMissionPool,
+            None,
+            ctx.accounts.project.to_account_info(),
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            &ctx.accounts.delegate_authority,
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.hive_control.to_account_info(),
+            ctx.accounts.instructions_sysvar.to_account_info(),
+        )?;
+        instructions::withdraw_escrow_nft(ctx)
+    }
+
+    pub fn participate<'info>(
+        ctx: Context<'_, '_, '_, 'info, Participate<'info>>,
+        args: ParticipateArgs,
+    ) -> Result<()> {
         platform_gate_cpi(
             hpl_hive_control::state::SerializableActions::PublicHigh,
             None,
@@ -93,6 +163,26 @@ pub mod hpl_nectar_missions {
         instructions::participate(ctx, args)
     }
 
+    pub fn participate_party<'info>(
+        ctx: Context<'_, '_, '_, 'info, ParticipateParty<'info>>,
+        args: ParticipatePartyArgs,
+    ) -> Result<()> {
+        platform_gate_cpi(
+            hpl_hive_control::state::SerializableActions::PublicHigh,
+            None,
+            ctx.accounts.project.to_account_info(),
+            ctx.accounts.wallet.to_account_info(),
+            ctx.accounts.wallet.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            &None,
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.hive_control.to_account_info(),
+            ctx.accounts.instructions_sysvar.to_account_info(),
+        )?;
+
+        instructions::participate_party(ctx, args)
+    }
+
     pub fn collect_rewards(ctx: Context<CollectRewards>) -> Result<()> {
         platform_gate_cpi(
             hpl_hive_control::state::SerializableActions::FeeExempt,
@@ -110,7 +200,63 @@ pub mod hpl_nectar_missions {
         instructions::collect_rewards(ctx)
     }
 
-    pub fn recall(ctx: Context<Recall>) -> Result<()> {
+    pub fn collect_all_rewards<'info>(
+        ctx: Context<'_, '_, '_, 'info, CollectAllRewards<'info>>,
+        args: CollectAllRewardsArgs,
+    ) -> Result<()> {
+        platform_gate_cpi(
+            hpl_hive_control::state::SerializableActions::FeeExempt,
+            None,
+            ctx.accounts.project.to_account_info(),
+            ctx.accounts.wallet.to_account_info(),
+            ctx.accounts.wallet.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            &None,
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.hive_control.to_account_info(),
+            ctx.accounts.instructions_sysvar.to_account_info(),
+        )?;
+
+        instructions::collect_all_rewards(ctx, args)
+    }
+
+    pub fn reveal_rewards<'info>(
+        ctx: Context<'_, '_, '_, 'info, RevealRewards<'info>>,
+    ) -> Result<()> {
+        platform_gate_cpi(
+            hpl_hive_control::state::SerializableActions::FeeExempt,
+            None,
+            ctx.accounts.project.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            &None,
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.hive_control.to_account_info(),
+            ctx.accounts.instructions_sysvar.to_account_info(),
+        )?;
+
+        instructions::reveal_participation_rewards(ctx)
+    }
+
+    pub fn migrate_participation(ctx: Context<MigrateParticipation>) -> Result<()> {
+        platform_gate_cpi(
+            hpl_hive_control::state::SerializableActions::FeeExempt,
+            None,
+            ctx.accounts.project.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            &None,
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.hive_control.to_account_info(),
+            ctx.accounts.instructions_sysvar.to_account_info(),
+        )?;
+
+        instructions::migrate_participation(ctx)
+    }
+
+    pub fn recall<'info>(ctx: Context<'_, '_, '_, 'info, Recall<'info>>) -> Result<()> {
         platform_gate_cpi(
             hpl_hive_control::state::SerializableActions::FeeExempt,
             None,
@@ -126,4 +272,21 @@ pub mod hpl_nectar_missions {
 
         instructions::recall(ctx)
     }
+
+    pub fn recall_party_member(ctx: Context<RecallPartyMember>) -> Result<()> {
+        platform_gate_cpi(
+            hpl_hive_control::state::SerializableActions::FeeExempt,
+            None,
+            ctx.accounts.project.to_account_info(),
+            ctx.accounts.wallet.to_account_info(),
+            ctx.accounts.wallet.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            &None,
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.hive_control.to_account_info(),
+            ctx.accounts.instructions_sysvar.to_account_info(),
+        )?;
+
+        instructions::recall_party_member(ctx)
+    }
 }
@@ -0,0 +1,293 @@
+use {
+    crate::{
+        errors::ErrorCode,
+        state::{Reward, RewardRoll},
+    },
+    anchor_lang::{prelude::*, solana_program::hash::hashv},
+};
+
+/// Size of the length prefix of the SlotHashes sysvar data
+const SLOT_HASHES_LEN_PREFIX: usize = 8;
+
+/// Size of a single (slot, hash) entry of the SlotHashes sysvar data
+const SLOT_HASH_ENTRY_LEN: usize = 8 + 32;
+
+/// Slots between the commit and the slot whose hash reveals the rolls
+/// The hash of a slot is not known before the slot is produced
+pub const REVEAL_DELAY_SLOTS: u64 = 2;
+
+/// The hash revealing the rolls committed to a slot
+#[derive(Debug, PartialEq, Eq)]
+pub enum RevealHash {
+    /// The committed slot is not produced yet
+    Pending,
+
+    /// The hash of the first slot at or after the committed slot
+    Revealed([u8; 32]),
+
+    /// The committed slot aged out of the SlotHashes sysvar
+    Expired,
+}
+
+/// Looks up the hash revealing the rolls committed to `slot` in the raw SlotHashes sysvar data
+/// The sysvar is too large to be deserialized on chain so it is read raw,
+/// its entries are ordered from the most recent slot to the oldest
+pub fn reveal_hash(data: &[u8], slot: u64) -> Result<RevealHash> {
+    if data.len() < SLOT_HASHES_LEN_PREFIX + SLOT_HASH_ENTRY_LEN {
+        return Err(ErrorCode::InvalidSlotHashes.into());
+    }
+
+    let count = (u64::from_le_bytes(data[..SLOT_HASHES_LEN_PREFIX].try_into().unwrap()) as usize)
+        .min((data.len() - SLOT_HASHES_LEN_PREFIX) / SLOT_HASH_ENTRY_LEN);
+    if count == 0 {
+        return Err(ErrorCode::InvalidSlotHashes.into());
+    }
+
+    let entry = |index: usize| {
+        let start = SLOT_HASHES_LEN_PREFIX + index * SLOT_HASH_ENTRY_LEN;
+        let entry = &data[start..start + SLOT_HASH_ENTRY_LEN];
+        let entry_slot = u64::from_le_bytes(entry[..8].try_into().unwrap());
+        let hash: [u8; 32] = entry[8..].try_into().unwrap();
+        (entry_slot, hash)
+    };
+
+    if entry(0).0 < slot {
+        return Ok(RevealHash::Pending);
+    }
+
+    // Skipped slots have no entry, so an older entry than the committed slot must still be kept
+    // to know that no earlier slot at or after it aged out
+    if entry(count - 1).0 > slot {
+        return Ok(RevealHash::Expired);
+    }
+
+    let (mut low, mut high) = (0, count - 1);
+    while low < high {
+        let mid = (low + high + 1) / 2;
+        if entry(mid).0 >= slot {
+            low = mid;
+        } else {
+            high = mid - 1;
+        }
+    }
+
+    Ok(RevealHash::Revealed(entry(low).1))
+}
+
+/// Derives the seed of a single roll from the revealed slot hash
+pub fn roll_seed(slot_hash: &[u8; 32], participation: &Pubkey, index: u8) -> [u8; 32] {
+    hashv(&[slot_hash, participation.as_ref(), &[index]]).to_bytes()
+}
+
+/// Derives the seed of the drop roll of a single reward
+pub fn drop_seed(slot_hash: &[u8; 32], participation: &Pubkey, index: u8) -> [u8; 32] {
+    hashv(&[b"drop", slot_hash, participation.as_ref(), &[index]]).to_bytes()
+}
+
+/// Derives the seed of the pick of a reward group
+pub fn group_seed(slot_hash: &[u8; 32], participation: &Pubkey, group: u8) -> [u8; 32] {
+    hashv(&[b"group", slot_hash, participation.as_ref(), &[group]]).to_bytes()
+}
+
+/// Maps a seed uniformly onto the full min..=max range
+pub fn random_between(seed: &[u8; 32], min: u64, max: u64) -> u64 {
+    if max <= min {
+        return min;
+    }
+
+    let random = u64::from_le_bytes(seed[..8].try_into().unwrap()) as u128;
+    let range = (max - min) as u128 + 1;
+    min + ((random * range) >> 64) as u64
+}
+
+/// Roll which of the rewards drop
+/// Rewards without a group drop by their own chance and exactly one reward
+/// of every group drops, picked with the chances as weights
+pub fn roll_drops(rolls: &[RewardRoll], slot_hash: &[u8; 32], participation: &Pubkey) -> Vec<bool> {
+    let mut dropped = vec![true; rolls.len()];
+
+    for (index, reward) in rolls.iter().enumerate() {
+        if reward.group.is_some() || reward.drop_chance >= Reward::ALWAYS_DROPS {
+            continue;
+        }
+
+        let seed = drop_seed(slot_hash, participation, index as u8);
+        let roll = random_between(&seed, 0, Reward::ALWAYS_DROPS as u64 - 1);
+        dropped[index] = roll < reward.drop_chance as u64;
+    }
+
+    let mut groups = rolls
+        .iter()
+        .filter_map(|reward| reward.group)
+        .collect::<Vec<_>>();
+    groups.sort();
+    groups.dedup();
+
+    for group in groups {
+        let entries = rolls
+            .iter()
+            .enumerate()
+            .filter(|(_, reward)| reward.group == Some(group))
+            .map(|(index, _)| index)
+            .collect::<Vec<_>>();
+
+        let total_weight: u64 = entries
+            .iter()
+            .map(|index| rolls[*index].drop_chance as u64)
+            .sum();
+
+        let mut winner = entries[0];
+        if total_weight > 0 {
+            let seed = group_seed(slot_hash, participation, group);
+            let mut roll = random_between(&seed, 0, total_weight - 1);
+            for index in entries.iter() {
+                let weight = rolls[*index].drop_chance as u64;
+                if roll < weight {
+                    winner = *index;
+                    break;
+                }
+                roll -= weight;
+            }
+        }
+
+        for index in entries {
+            dropped[index] = index == winner;
+        }
+    }
+
+    dropped
+}
+
+/// Resolve which of the rewards drop without randomness, only the rewards that always drop do
+pub fn expired_drops(rolls: &[RewardRoll]) -> Vec<bool> {
+    rolls
+        .iter()
+        .map(|roll| roll.group.is_none() && roll.drop_chance >= Reward::ALWAYS_DROPS)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn slot_hashes(slots: &[u64]) -> Vec<u8> {
+        let mut data = (slots.len() as u64).to_le_bytes().to_vec();
+        for slot in slots {
+            data.extend_from_slice(&slot.to_le_bytes());
+            data.extend_from_slice(&[*slot as u8; 32]);
+        }
+        data
+    }
+
+    #[test]
+    fn reveal_hash_reads_the_committed_slot() {
+        let data = slot_hashes(&[10, 9, 7, 5]);
+        assert_eq!(
+            reveal_hash(&data, 9).unwrap(),
+            RevealHash::Revealed([9; 32])
+        );
+        assert_eq!(
+            reveal_hash(&data, 5).unwrap(),
+            RevealHash::Revealed([5; 32])
+        );
+        assert_eq!(
+            reveal_hash(&data, 10).unwrap(),
+            RevealHash::Revealed([10; 32])
+        );
+    }
+
+    #[test]
+    fn reveal_hash_uses_the_next_slot_when_skipped() {
+        let data = slot_hashes(&[10, 9, 7, 5]);
+        assert_eq!(
+            reveal_hash(&data, 8).unwrap(),
+            RevealHash::Revealed([9; 32])
+        );
+        assert_eq!(
+            reveal_hash(&data, 6).unwrap(),
+            RevealHash::Revealed([7; 32])
+        );
+    }
+
+    #[test]
+    fn reveal_hash_is_pending_before_the_committed_slot() {
+        let data = slot_hashes(&[10, 9, 7, 5]);
+        assert_eq!(reveal_hash(&data, 11).unwrap(), RevealHash::Pending);
+    }
+
+    #[test]
+    fn reveal_hash_expires_once_aged_out() {
+        let data = slot_hashes(&[10, 9, 7, 5]);
+        assert_eq!(reveal_hash(&data, 4).unwrap(), RevealHash::Expired);
+    }
+
+    #[test]
+    fn reveal_hash_rejects_empty_data() {
+        assert!(reveal_hash(&slot_hashes(&[]), 1).is_err());
+        assert!(reveal_hash(&[], 1).is_err());
+    }
+
+    #[test]
+    fn random_between_covers_the_range() {
+        assert_eq!(random_between(&[0; 32], 3, 7), 3);
+        assert_eq!(random_between(&[0xff; 32], 3, 7), 7);
+        assert_eq!(random_between(&[0xff; 32], 0, u64::MAX), u64::MAX);
+        assert_eq!(random_between(&[0xff; 32], 5, 5), 5);
+        assert_eq!(random_between(&[0xff; 32], 5, 1), 5);
+    }
+
+    fn roll(drop_chance: u16, group: Option<u8>) -> RewardRoll {
+        RewardRoll {
+            min: 1,
+            max: 10,
+            drop_chance,
+            group,
+        }
+    }
+
+    #[test]
+    fn roll_drops_picks_exactly_one_of_every_group() {
+        let participation = Pubkey::new_unique();
+        let rolls = [
+            roll(Reward::ALWAYS_DROPS, None),
+            roll(0, None),
+            roll(3_000, Some(1)),
+            roll(7_000, Some(1)),
+            roll(0, Some(2)),
+            roll(0, Some(2)),
+        ];
+
+        for slot_hash in 0..32u8 {
+            let dropped = roll_drops(&rolls, &[slot_hash; 32], &participation);
+            assert!(dropped[0]);
+            assert!(!dropped[1]);
+            assert!(dropped[2] ^ dropped[3]);
+            // A group without weights falls back to its first entry
+            assert!(dropped[4] && !dropped[5]);
+        }
+    }
+
+    #[test]
+    fn expired_drops_keep_only_the_rewards_that_always_drop() {
+        let rolls = [
+            roll(Reward::ALWAYS_DROPS, None),
+            roll(9_999, None),
+            roll(Reward::ALWAYS_DROPS, Some(1)),
+            roll(0, Some(1)),
+        ];
+        assert_eq!(expired_drops(&rolls), vec![true, false, false, false]);
+    }
+
+    #[test]
+    fn seeds_differ_by_index() {
+        let participation = Pubkey::new_unique();
+        assert_ne!(
+            roll_seed(&[1; 32], &participation, 0),
+            roll_seed(&[1; 32], &participation, 1)
+        );
+        assert_ne!(
+            drop_seed(&[1; 32], &participation, 0),
+            group_seed(&[1; 32], &participation, 0)
+        );
+    }
+}
@@ -0,0 +1,137 @@
+use {anchor_lang::prelude::*, hpl_utils::Default};
+
+/// NftEscrow state account holding the NFT rewards of a mission pool
+/// The NFTs are held in token accounts owned by the mission pool PDA
+/// PDA: ['nft_escrow', mission_pool, name]
+/// Category: escrow_state
+#[account]
+pub struct NftEscrow {
+    pub bump: u8,
+    pub mission_pool: Pubkey,
+    pub name: String,
+    /// Random picks reserved by participations until they are revealed
+    pub reserved: u64,
+    /// Mints of the NFTs available in the escrow
+    /// Specific NFT rewards are taken out when participating, random picks when revealed
+    pub mints: Vec<Pubkey>,
+}
+impl Default for NftEscrow {
+    const LEN: usize = 8 + 80;
+
+    fn set_defaults(&mut self) {
+        self.bump = 0;
+        self.mission_pool = Pubkey::default();
+        self.name = String::default();
+        self.reserved = 0;
+        self.mints = vec![];
+    }
+}
+impl NftEscrow {
+    /// Removes a mint from the available mints, returns false if it is not available
+    pub fn take(&mut self, mint: &Pubkey) -> bool {
+        match self.mints.iter().position(|x| x == mint) {
+            Some(index) => {
+                self.mints.swap_remove(index);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Reserves an NFT reward, a specific mint is taken out right away
+    /// and a random pick is counted against the mints not reserved yet
+    pub fn reserve(&mut self, mint: &Option<Pubkey>) -> bool {
+        match mint {
+            Some(mint) => self.take(mint),
+            None => {
+                if self.mints.len() as u64 <= self.reserved {
+                    return false;
+                }
+
+                self.reserved += 1;
+                true
+            }
+        }
+    }
+
+    /// Releases an NFT reward that is not delivered, a taken out mint is put back
+    pub fn release(&mut self, mint: &Option<Pubkey>) {
+        match mint {
+            Some(mint) => self.mints.push(*mint),
+            None => self.reserved = self.reserved.saturating_sub(1),
+        }
+    }
+
+    /// Picks the mint of a reserved random NFT reward by its roll and takes it out
+    pub fn pick(&mut self, roll: u64) -> Option<Pubkey> {
+        if self.reserved == 0 || self.mints.is_empty() {
+            return None;
+        }
+
+        self.reserved -= 1;
+        let index = (roll % self.mints.len() as u64) as usize;
+        Some(self.mints.swap_remove(index))
+    }
+
+    /// Whether an NFT can be withdrawn without breaking the reserved random picks
+    pub fn can_withdraw(&self) -> bool {
+        self.mints.len() as u64 > self.reserved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn escrow(count: usize) -> NftEscrow {
+        NftEscrow {
+            bump: 255,
+            mission_pool: Pubkey::new_unique(),
+            name: "Loot".to_string(),
+            reserved: 0,
+            mints: (0..count).map(|_| Pubkey::new_unique()).collect(),
+        }
+    }
+
+    #[test]
+    fn random_picks_are_bounded_by_the_stock() {
+        let mut escrow = escrow(2);
+        assert!(escrow.reserve(&None));
+        assert!(escrow.reserve(&None));
+        assert!(!escrow.reserve(&None));
+        assert!(!escrow.can_withdraw());
+
+        escrow.release(&None);
+        assert_eq!(escrow.reserved, 1);
+        assert!(escrow.can_withdraw());
+    }
+
+    #[test]
+    fn specific_mints_are_taken_out_when_reserved() {
+        let mut escrow = escrow(2);
+        let mint = escrow.mints[0];
+        assert!(escrow.reserve(&Some(mint)));
+        assert!(!escrow.reserve(&Some(mint)));
+        assert_eq!(escrow.mints.len(), 1);
+
+        // A random pick can not count on the reserved mint
+        assert!(escrow.reserve(&None));
+        assert!(!escrow.reserve(&None));
+
+        escrow.release(&Some(mint));
+        assert!(escrow.mints.contains(&mint));
+    }
+
+    #[test]
+    fn pick_takes_out_the_rolled_mint() {
+        let mut escrow = escrow(3);
+        assert_eq!(escrow.pick(7), None);
+
+        assert!(escrow.reserve(&None));
+        let expected = escrow.mints[7 % 3];
+        assert_eq!(escrow.pick(7), Some(expected));
+        assert_eq!(escrow.reserved, 0);
+        assert_eq!(escrow.mints.len(), 2);
+        assert!(!escrow.mints.contains(&expected));
+    }
+}
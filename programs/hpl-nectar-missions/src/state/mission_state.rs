@@ -1,10 +1,14 @@
-use {anchor_lang::prelude::*, hpl_utils::Default};
+use {
+    super::{EarnedReward, RewardRoll},
+    anchor_lang::prelude::*,
+    hpl_utils::Default,
+};
 
 /// Mission account holding the details about mission
 /// PDA: ['mission', project, name]
 /// Category: mission_state
 #[account]
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Mission {
     pub bump: u8,
     pub mission_pool: Pubkey,
@@ -14,9 +18,15 @@ pub struct Mission {
     /// The duration of the mission in seconds
     pub duration: i64,
     pub rewards: Vec<Reward>,
+    /// The factions allowed to participate, empty if open to all
+    pub factions: Vec<String>,
+    /// What happens to the rewards when recalled before the mission ends
+    pub recall_policy: RecallPolicy,
+    /// Drop table entries of the rewards by index, rewards without an entry always drop
+    pub reward_drops: Vec<RewardDrop>,
 }
 impl Default for Mission {
-    const LEN: usize = 8 + 112;
+    const LEN: usize = 8 + 129;
 
     fn set_defaults(&mut self) {
         self.bump = 0;
@@ -29,6 +39,69 @@ impl Default for Mission {
         };
         self.duration = 0;
         self.rewards = vec![];
+        self.factions = vec![];
+        self.recall_policy = RecallPolicy::Disabled;
+        self.reward_drops = vec![];
+    }
+}
+impl Mission {
+    /// Bytes of the fields appended after the layout missions were created with
+    /// factions, recall_policy and reward_drops, zero filled by migrate_mission
+    pub const APPENDED_LEN: usize = 17;
+
+    /// The drop table entry of a reward
+    pub fn reward_drop(&self, index: usize) -> RewardDrop {
+        self.reward_drops
+            .get(index)
+            .cloned()
+            .unwrap_or(RewardDrop::ALWAYS)
+    }
+
+    /// The cost of participating with a single NFT, cNFTs pay a tenth of NFTs
+    pub fn participation_cost(&self, is_compressed: bool) -> u64 {
+        self.cost.amount * if is_compressed { 1 } else { 10 }
+    }
+
+    /// The distinct escrows of the NFT rewards
+    pub fn nft_escrows(&self) -> Vec<Pubkey> {
+        let mut escrows = vec![];
+        for reward in self.rewards.iter() {
+            if let RewardType::Nft { escrow, .. } = reward.reward_type {
+                if !escrows.contains(&escrow) {
+                    escrows.push(escrow);
+                }
+            }
+        }
+        escrows
+    }
+
+    /// The rewards earned by participating and their rolls to commit until revealed
+    pub fn commit_rewards(&self) -> (Vec<EarnedReward>, Vec<RewardRoll>) {
+        self.rewards
+            .iter()
+            .enumerate()
+            .map(|(index, reward)| {
+                let reward_drop = self.reward_drop(index);
+                (
+                    EarnedReward {
+                        amount: 0,
+                        reward_type: reward.reward_type.clone(),
+                        collected: false,
+                    },
+                    RewardRoll {
+                        min: reward.min,
+                        max: reward.max,
+                        drop_chance: reward_drop.drop_chance,
+                        group: reward_drop.group,
+                    },
+                )
+            })
+            .unzip()
+    }
+
+    /// Bytes taken by a list of factions in the account data
+    pub fn factions_len(factions: &[String]) -> usize {
+        factions.iter().map(|faction| 4 + faction.len()).sum()
     }
 }
 
@@ -45,11 +118,108 @@ pub struct Reward {
     pub reward_type: RewardType,
 }
 impl Reward {
-    pub const LEN: usize = 8 + 56;
+    pub const LEN: usize = 8 + 84;
+
+    /// Drop chance of a reward that always drops
+    pub const ALWAYS_DROPS: u16 = 10_000;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Eq, PartialEq)]
+pub struct RewardDrop {
+    /// The chance of the reward to drop in basis points
+    pub drop_chance: u16,
+    /// Rewards of the same group are mutually exclusive, exactly one of them drops
+    /// The drop chances are then used as the weights of the pick
+    pub group: Option<u8>,
+}
+impl RewardDrop {
+    pub const LEN: usize = 4;
+
+    /// The entry of a reward that always drops
+    pub const ALWAYS: Self = Self {
+        drop_chance: Reward::ALWAYS_DROPS,
+        group: None,
+    };
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Eq, PartialEq)]
 pub enum RewardType {
     Xp,
-    Currency { address: Pubkey },
+    Currency {
+        address: Pubkey,
+    },
+    /// An NFT from the escrow, a specific one if `mint` is set otherwise a random pick
+    /// The escrow stock is reserved when participating and the picked mint
+    /// is recorded on the earned reward when the rewards are revealed
+    Nft {
+        escrow: Pubkey,
+        mint: Option<Pubkey>,
+    },
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Eq, PartialEq)]
+pub enum RecallPolicy {
+    /// The NFT can not be recalled before the mission ends
+    Disabled,
+
+    /// All rewards are forfeited
+    ForfeitAll,
+
+    /// The rolled rewards are scaled by the time spent on the mission, NFT rewards are forfeited
+    ProRated,
+
+    /// The participant pays `amount` of the mission cost currency and keeps the rewards
+    Penalty { amount: u64 },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legacy_layout_decodes_with_zero_filled_appended_fields() {
+        let mission = Mission {
+            bump: 255,
+            mission_pool: Pubkey::new_unique(),
+            name: "Expedition".to_string(),
+            min_xp: 100,
+            cost: Currency {
+                amount: 10,
+                address: Pubkey::new_unique(),
+            },
+            duration: 3_600,
+            rewards: vec![
+                Reward {
+                    min: 10,
+                    max: 20,
+                    reward_type: RewardType::Xp,
+                },
+                Reward {
+                    min: 1,
+                    max: 5,
+                    reward_type: RewardType::Currency {
+                        address: Pubkey::new_unique(),
+                    },
+                },
+            ],
+            factions: vec![],
+            recall_policy: RecallPolicy::Disabled,
+            reward_drops: vec![],
+        };
+
+        let mut data = vec![];
+        mission.try_serialize(&mut data).unwrap();
+
+        // The fields appended after rewards serialize to 9 zero bytes here
+        let (legacy, appended) = data.split_at(data.len() - 9);
+        assert!(appended.iter().all(|byte| *byte == 0));
+        let mut legacy = legacy.to_vec();
+
+        assert!(Mission::try_deserialize(&mut &legacy[..]).is_err());
+
+        legacy.resize(legacy.len() + Mission::APPENDED_LEN, 0);
+        let migrated = Mission::try_deserialize(&mut &legacy[..]).unwrap();
+        assert_eq!(migrated, mission);
+        assert_eq!(migrated.reward_drop(1), RewardDrop::ALWAYS);
+    }
 }
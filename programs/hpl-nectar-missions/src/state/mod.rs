@@ -1,6 +1,7 @@
+pub mod escrow_state;
 pub mod events;
 pub mod mission_state;
 pub mod participation_state;
 pub mod pool_state;
 
-pub use {events::*, mission_state::*, participation_state::*, pool_state::*};
+pub use {escrow_state::*, events::*, mission_state::*, participation_state::*, pool_state::*};
@@ -15,9 +15,23 @@ pub struct Participation {
     pub end_time: i64,
     pub is_recalled: bool,
     pub rewards: Vec<EarnedReward>,
+    /// The start time of the mission in unix timestamp
+    pub start_time: i64,
+    /// The slot whose hash reveals the reward rolls, committed in participate
+    /// Rolls revealed after it aged out of the SlotHashes sysvar resolve to their minimum
+    pub reveal_slot: u64,
+    /// Whether the reward rolls are revealed
+    pub is_revealed: bool,
+    /// The committed rolls of the rewards by index
+    pub rolls: Vec<RewardRoll>,
+    /// The faction of the NFT verified against the mission pool merkle root
+    pub faction: Option<String>,
+    /// Members of a party participation, empty for a single NFT participation
+    /// The rolled rewards are split among the members by their share
+    pub members: Vec<PartyMember>,
 }
 impl Default for Participation {
-    const LEN: usize = 8 + 136;
+    const LEN: usize = 8 + 162;
 
     fn set_defaults(&mut self) {
         self.bump = 0;
@@ -27,12 +41,224 @@ impl Default for Participation {
         self.end_time = 0;
         self.is_recalled = false;
         self.rewards = vec![];
+        self.start_time = 0;
+        self.reveal_slot = 0;
+        self.is_revealed = false;
+        self.rolls = vec![];
+        self.faction = None;
+        self.members = vec![];
+    }
+}
+impl Participation {
+    /// Bytes of the fields appended after the layout participations were started with
+    /// start_time through members, zero filled by migrate_participation
+    pub const APPENDED_LEN: usize = 26;
+
+    pub fn is_party(&self) -> bool {
+        !self.members.is_empty()
+    }
+
+    pub fn has_nft_rewards(&self) -> bool {
+        self.rewards
+            .iter()
+            .any(|reward| matches!(reward.reward_type, RewardType::Nft { .. }))
+    }
+
+    /// Participations started before the rolls were committed already rolled their rewards,
+    /// they have no rolls and are marked revealed by migrate_participation
+    pub fn is_migrated(&self) -> bool {
+        self.is_revealed || self.rolls.len() == self.rewards.len()
     }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Eq, Clone, Debug)]
 pub struct EarnedReward {
+    /// The rolled amount, zero until the rolls are revealed
     pub amount: u64,
     pub reward_type: RewardType,
+    /// Rewards that did not drop are marked collected when revealed
     pub collected: bool,
 }
+
+/// The roll of a reward committed in participate, copied from the mission reward
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Eq, Clone, Debug)]
+pub struct RewardRoll {
+    pub min: u64,
+    pub max: u64,
+    pub drop_chance: u16,
+    pub group: Option<u8>,
+}
+impl RewardRoll {
+    pub const LEN: usize = 20;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, PartialEq, Eq, Clone, Debug)]
+pub struct PartyMember {
+    pub wallet: Pubkey,
+    pub nft: Pubkey,
+    /// Share of the rewards in basis points
+    pub share: u16,
+    pub is_recalled: bool,
+    pub rewards: Vec<EarnedReward>,
+}
+impl PartyMember {
+    pub const LEN: usize = 80;
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub enum ShareRule {
+    /// Rewards are split equally among the members
+    Equal,
+
+    /// The chief gets `chief_share` basis points, the rest is split equally among the members
+    ChiefBonus { chief_share: u16 },
+
+    /// Shares in basis points for every member in order, must add up to 10_000
+    Custom { shares: Vec<u16> },
+}
+impl ShareRule {
+    pub const TOTAL_SHARES: u16 = 10_000;
+
+    /// Shares in basis points for `count` members, the first member being the chief
+    /// Any remainder of the division goes to the chief
+    pub fn shares(&self, count: usize) -> Option<Vec<u16>> {
+        if count == 0 {
+            return None;
+        }
+
+        let mut shares = match self {
+            Self::Equal => vec![Self::TOTAL_SHARES / count as u16; count],
+            Self::ChiefBonus { chief_share } => {
+                if *chief_share > Self::TOTAL_SHARES {
+                    return None;
+                }
+
+                let mut shares = vec![*chief_share];
+                if count > 1 {
+                    let member_share = (Self::TOTAL_SHARES - chief_share) / (count - 1) as u16;
+                    shares.extend(vec![member_share; count - 1]);
+                }
+                shares
+            }
+            Self::Custom { shares } => {
+                if shares.len() != count
+                    || shares.iter().map(|x| *x as u32).sum::<u32>() != Self::TOTAL_SHARES as u32
+                {
+                    return None;
+                }
+                shares.clone()
+            }
+        };
+
+        let total: u16 = shares.iter().sum();
+        shares[0] += Self::TOTAL_SHARES - total;
+        Some(shares)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legacy_layout_decodes_with_zero_filled_appended_fields() {
+        let participation = Participation {
+            bump: 255,
+            wallet: Pubkey::new_unique(),
+            mission: Pubkey::new_unique(),
+            nft: Pubkey::new_unique(),
+            end_time: 3_600,
+            is_recalled: false,
+            rewards: vec![EarnedReward {
+                amount: 15,
+                reward_type: RewardType::Xp,
+                collected: false,
+            }],
+            start_time: 0,
+            reveal_slot: 0,
+            is_revealed: false,
+            rolls: vec![],
+            faction: None,
+            members: vec![],
+        };
+
+        let mut data = vec![];
+        participation.try_serialize(&mut data).unwrap();
+
+        // The fields appended after rewards serialize to APPENDED_LEN zero bytes
+        let (legacy, appended) = data.split_at(data.len() - Participation::APPENDED_LEN);
+        assert!(appended.iter().all(|byte| *byte == 0));
+        let mut legacy = legacy.to_vec();
+
+        assert!(Participation::try_deserialize(&mut &legacy[..]).is_err());
+
+        legacy.resize(legacy.len() + Participation::APPENDED_LEN, 0);
+        let mut migrated = Participation::try_deserialize(&mut &legacy[..]).unwrap();
+        assert_eq!(migrated, participation);
+
+        // The rewards were rolled when participating, there is nothing left to reveal
+        assert!(!migrated.is_migrated());
+        migrated.is_revealed = true;
+        assert!(migrated.is_migrated());
+    }
+
+    #[test]
+    fn equal_shares_give_the_remainder_to_the_chief() {
+        assert_eq!(ShareRule::Equal.shares(3), Some(vec![3_334, 3_333, 3_333]));
+        assert_eq!(ShareRule::Equal.shares(4), Some(vec![2_500; 4]));
+        assert_eq!(ShareRule::Equal.shares(1), Some(vec![10_000]));
+    }
+
+    #[test]
+    fn chief_bonus_splits_the_rest_equally() {
+        let rule = ShareRule::ChiefBonus { chief_share: 4_000 };
+        assert_eq!(rule.shares(3), Some(vec![4_000, 3_000, 3_000]));
+
+        let rule = ShareRule::ChiefBonus { chief_share: 5_000 };
+        assert_eq!(rule.shares(4), Some(vec![5_002, 1_666, 1_666, 1_666]));
+        assert_eq!(rule.shares(1), Some(vec![10_000]));
+    }
+
+    #[test]
+    fn custom_shares_must_match_the_party() {
+        let rule = ShareRule::Custom {
+            shares: vec![6_000, 4_000],
+        };
+        assert_eq!(rule.shares(2), Some(vec![6_000, 4_000]));
+        assert_eq!(rule.shares(3), None);
+
+        let rule = ShareRule::Custom {
+            shares: vec![6_000, 3_000],
+        };
+        assert_eq!(rule.shares(2), None);
+    }
+
+    #[test]
+    fn invalid_rules_have_no_shares() {
+        assert_eq!(ShareRule::Equal.shares(0), None);
+        assert_eq!(
+            ShareRule::ChiefBonus {
+                chief_share: 10_001
+            }
+            .shares(2),
+            None
+        );
+    }
+
+    #[test]
+    fn shares_always_add_up() {
+        for count in 1..=12 {
+            for rule in [
+                ShareRule::Equal,
+                ShareRule::ChiefBonus { chief_share: 2_500 },
+            ] {
+                let shares = rule.shares(count).unwrap();
+                assert_eq!(shares.len(), count);
+                assert_eq!(
+                    shares.iter().map(|x| *x as u32).sum::<u32>(),
+                    ShareRule::TOTAL_SHARES as u32
+                );
+            }
+        }
+    }
+}
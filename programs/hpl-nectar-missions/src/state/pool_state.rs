@@ -9,7 +9,7 @@ pub struct MissionPool {
     pub project: Pubkey,
     pub name: String,
     pub factions_merkle_root: [u8; 32],
-    pub randomizer_round: u8,
+    pub temp_place_holder_1: u8,
     pub staking_pools: Vec<u8>,
 }
 impl Default for MissionPool {
@@ -20,12 +20,7 @@ impl Default for MissionPool {
         self.project = Pubkey::default();
         self.name = String::default();
         self.factions_merkle_root = [0; 32];
-        self.randomizer_round = 0;
+        self.temp_place_holder_1 = 0;
         self.staking_pools = vec![];
     }
 }
-impl MissionPool {
-    pub fn increase_randomizer_round(&mut self) {
-        self.randomizer_round = (self.randomizer_round + 1) % 100;
-    }
-}
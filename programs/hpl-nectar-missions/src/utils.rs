@@ -1,723 +0,0 @@
-pub struct Randomizer {
-    pub randoms: [[u8; 101]; 100],
-}
-impl Randomizer {
-    pub const ROUNDS: usize = 10;
-
-    pub fn get_random(&self, round: usize, random_slot: u64) -> u8 {
-        let vec = self.randoms[round % self.randoms.len()];
-        let index = (random_slot % 101 as u64) as usize;
-        vec[index]
-    }
-
-    pub fn get_random_between(&self, round: usize, random_slot: u64, min: u64, max: u64) -> u64 {
-        let range = max - min;
-        let random = self.get_random(round, random_slot) as u64;
-        min + ((random * range) / 100)
-    }
-}
-
-pub const RANDOMIZER: Randomizer = Randomizer {
-    randoms: [
-        [
-            33, 83, 32, 76, 17, 55, 94, 73, 53, 31, 97, 27, 36, 63, 15, 93, 47, 19, 51, 22, 50, 75,
-            54, 26, 43, 84, 40, 57, 67, 71, 77, 89, 10, 34, 35, 20, 80, 28, 5, 62, 86, 56, 23, 44,
-            29, 68, 6, 30, 16, 9, 65, 98, 60, 39, 82, 87, 61, 46, 92, 70, 91, 7, 58, 2, 66, 14, 95,
-            21, 72, 42, 18, 59, 38, 3, 48, 1, 100, 90, 64, 13, 37, 78, 99, 85, 45, 74, 11, 69, 4,
-            81, 49, 41, 24, 52, 8, 0, 25, 88, 12, 96, 79,
-        ],
-        [
-            47, 32, 42, 3, 85, 91, 96, 26, 43, 100, 80, 86, 90, 46, 30, 65, 55, 97, 23, 52, 53, 41,
-            39, 98, 81, 57, 76, 93, 38, 14, 29, 63, 92, 20, 88, 28, 94, 22, 25, 54, 7, 10, 68, 8,
-            12, 21, 27, 35, 0, 17, 33, 82, 2, 66, 48, 5, 37, 58, 56, 99, 75, 83, 31, 36, 74, 62,
-            79, 67, 9, 24, 95, 15, 4, 77, 87, 40, 59, 60, 89, 44, 45, 64, 34, 78, 51, 70, 18, 50,
-            13, 19, 6, 61, 16, 49, 1, 71, 84, 73, 69, 11, 72,
-        ],
-        [
-            9, 53, 65, 18, 10, 26, 1, 32, 41, 57, 40, 93, 20, 11, 33, 63, 77, 60, 0, 2, 72, 56, 49,
-            54, 66, 23, 5, 31, 39, 71, 17, 91, 81, 78, 29, 95, 3, 68, 83, 55, 90, 14, 89, 38, 99,
-            37, 74, 48, 96, 44, 100, 73, 22, 6, 52, 19, 7, 36, 79, 28, 86, 21, 35, 62, 47, 43, 64,
-            45, 42, 80, 8, 16, 27, 84, 50, 59, 88, 24, 4, 25, 58, 82, 70, 97, 98, 51, 92, 13, 67,
-            85, 76, 87, 34, 75, 46, 61, 12, 15, 94, 69, 30,
-        ],
-        [
-            100, 98, 53, 9, 55, 11, 79, 65, 94, 52, 35, 15, 61, 93, 44, 41, 97, 57, 89, 66, 38, 36,
-            19, 59, 40, 23, 81, 14, 80, 22, 26, 4, 67, 3, 12, 77, 42, 92, 63, 87, 8, 17, 64, 49,
-            82, 27, 60, 78, 75, 69, 86, 68, 43, 85, 91, 13, 58, 45, 71, 6, 70, 39, 84, 99, 31, 76,
-            30, 1, 0, 32, 95, 25, 83, 46, 72, 90, 88, 16, 34, 50, 21, 2, 10, 5, 33, 7, 24, 47, 56,
-            48, 20, 18, 96, 28, 74, 62, 73, 29, 51, 54, 37,
-        ],
-        [
-            68, 59, 97, 56, 10, 16, 43, 35, 21, 76, 61, 98, 63, 90, 93, 28, 65, 36, 31, 37, 45, 85,
-            2, 86, 18, 64, 7, 44, 19, 95, 73, 84, 94, 8, 30, 70, 0, 41, 87, 49, 48, 91, 32, 58, 47,
-            51, 29, 20, 83, 6, 4, 54, 25, 24, 17, 89, 66, 100, 39, 96, 81, 26, 79, 50, 9, 57, 62,
-            82, 99, 71, 69, 77, 72, 12, 42, 38, 46, 67, 40, 53, 3, 78, 75, 80, 92, 55, 88, 23, 5,
-            11, 33, 52, 13, 27, 74, 34, 60, 14, 1, 15, 22,
-        ],
-        [
-            93, 90, 2, 63, 50, 70, 31, 56, 39, 55, 75, 23, 46, 62, 13, 43, 80, 54, 33, 36, 83, 26,
-            16, 71, 97, 35, 37, 67, 68, 24, 84, 89, 85, 74, 19, 64, 88, 77, 28, 0, 57, 6, 99, 48,
-            52, 42, 53, 10, 98, 38, 92, 49, 87, 3, 5, 82, 96, 72, 86, 79, 51, 27, 40, 8, 22, 73,
-            30, 94, 95, 29, 60, 7, 61, 76, 34, 32, 45, 44, 25, 9, 58, 4, 41, 1, 69, 65, 47, 20, 66,
-            78, 91, 12, 18, 81, 59, 14, 11, 17, 21, 100, 15,
-        ],
-        [
-            42, 69, 27, 75, 61, 71, 43, 59, 68, 34, 81, 48, 99, 52, 79, 94, 97, 17, 7, 70, 91, 62,
-            41, 39, 24, 47, 12, 77, 74, 4, 16, 9, 49, 100, 11, 45, 8, 60, 56, 84, 38, 2, 29, 18,
-            14, 31, 1, 86, 63, 92, 50, 53, 37, 78, 5, 88, 65, 44, 82, 15, 35, 51, 33, 26, 89, 98,
-            67, 10, 40, 19, 64, 58, 95, 85, 93, 23, 55, 21, 73, 0, 57, 22, 46, 25, 13, 90, 66, 54,
-            28, 30, 83, 80, 96, 32, 3, 6, 36, 76, 87, 72, 20,
-        ],
-        [
-            82, 53, 74, 78, 4, 14, 94, 25, 48, 91, 13, 49, 70, 27, 47, 57, 41, 22, 97, 67, 31, 37,
-            10, 28, 33, 6, 17, 64, 23, 15, 34, 59, 12, 100, 54, 36, 35, 90, 5, 45, 38, 71, 93, 44,
-            9, 81, 2, 79, 29, 85, 26, 62, 99, 68, 50, 18, 88, 66, 86, 96, 73, 21, 84, 8, 11, 76,
-            83, 30, 72, 46, 20, 98, 24, 95, 58, 75, 56, 69, 61, 77, 80, 0, 40, 1, 52, 43, 16, 39,
-            3, 19, 65, 32, 7, 60, 87, 63, 51, 55, 92, 89, 42,
-        ],
-        [
-            90, 88, 17, 85, 18, 22, 56, 5, 86, 12, 60, 10, 37, 32, 25, 66, 20, 50, 46, 2, 11, 4,
-            24, 7, 99, 23, 68, 42, 92, 53, 35, 16, 36, 34, 39, 27, 41, 87, 15, 81, 48, 72, 67, 13,
-            47, 51, 74, 100, 3, 62, 6, 57, 8, 59, 91, 84, 21, 14, 96, 28, 77, 80, 69, 98, 94, 49,
-            9, 30, 82, 44, 78, 63, 0, 1, 61, 52, 19, 38, 55, 65, 33, 26, 75, 31, 76, 54, 45, 83,
-            79, 71, 58, 73, 93, 29, 89, 70, 40, 97, 95, 64, 43,
-        ],
-        [
-            22, 73, 24, 19, 98, 37, 74, 97, 9, 11, 41, 53, 100, 1, 70, 81, 13, 75, 56, 8, 50, 20,
-            47, 92, 40, 18, 79, 10, 54, 84, 89, 61, 48, 62, 35, 55, 23, 31, 39, 34, 2, 14, 51, 95,
-            64, 30, 65, 85, 17, 87, 93, 26, 76, 68, 29, 4, 36, 69, 52, 15, 3, 25, 86, 71, 16, 99,
-            78, 66, 0, 44, 96, 58, 32, 43, 60, 5, 45, 88, 6, 38, 72, 80, 33, 7, 49, 46, 12, 28, 27,
-            21, 90, 94, 91, 63, 67, 59, 82, 57, 42, 83, 77,
-        ],
-        [
-            12, 4, 17, 50, 86, 75, 9, 5, 89, 14, 68, 97, 63, 42, 71, 10, 25, 21, 83, 40, 88, 82,
-            36, 8, 66, 29, 100, 28, 84, 99, 47, 64, 23, 48, 1, 18, 16, 72, 94, 77, 65, 6, 56, 51,
-            11, 91, 19, 70, 26, 54, 85, 58, 59, 2, 74, 93, 53, 69, 49, 78, 81, 62, 27, 43, 95, 20,
-            87, 35, 80, 55, 73, 30, 34, 61, 67, 22, 45, 13, 38, 76, 41, 0, 15, 79, 44, 39, 37, 60,
-            31, 7, 3, 46, 33, 32, 90, 24, 57, 96, 52, 92, 98,
-        ],
-        [
-            31, 16, 84, 59, 11, 85, 27, 17, 52, 14, 36, 6, 46, 56, 43, 7, 90, 22, 26, 23, 54, 93,
-            0, 63, 70, 89, 92, 61, 77, 25, 30, 2, 48, 81, 9, 73, 95, 86, 13, 64, 94, 60, 38, 98, 5,
-            20, 44, 53, 49, 35, 87, 1, 41, 18, 76, 62, 45, 28, 83, 33, 99, 71, 10, 88, 55, 100, 29,
-            3, 19, 15, 68, 8, 82, 91, 50, 96, 51, 57, 97, 69, 39, 80, 12, 72, 75, 47, 66, 42, 21,
-            40, 67, 4, 74, 65, 32, 78, 34, 37, 58, 79, 24,
-        ],
-        [
-            30, 12, 80, 51, 63, 2, 99, 76, 4, 81, 98, 46, 0, 29, 65, 53, 16, 41, 48, 59, 39, 95,
-            84, 9, 68, 73, 71, 93, 43, 57, 40, 87, 44, 11, 24, 82, 6, 19, 72, 49, 77, 27, 52, 1,
-            60, 45, 83, 89, 35, 14, 61, 66, 78, 36, 64, 67, 38, 23, 62, 56, 17, 26, 85, 31, 54, 37,
-            97, 32, 58, 25, 94, 75, 28, 18, 47, 100, 74, 13, 50, 96, 92, 34, 79, 8, 70, 88, 33, 10,
-            5, 90, 22, 55, 15, 21, 91, 42, 3, 20, 86, 69, 7,
-        ],
-        [
-            26, 29, 49, 27, 37, 2, 58, 59, 32, 6, 77, 53, 96, 64, 91, 31, 97, 25, 52, 81, 34, 86,
-            41, 16, 13, 69, 43, 0, 24, 80, 83, 85, 79, 75, 20, 3, 63, 55, 98, 18, 14, 21, 100, 22,
-            15, 78, 23, 84, 7, 1, 94, 36, 93, 33, 71, 99, 12, 42, 73, 76, 40, 4, 60, 54, 51, 45,
-            90, 72, 74, 28, 89, 44, 67, 30, 48, 11, 8, 46, 88, 66, 19, 87, 92, 70, 39, 82, 35, 17,
-            56, 62, 38, 61, 50, 5, 95, 57, 10, 47, 65, 68, 9,
-        ],
-        [
-            34, 55, 1, 14, 71, 31, 6, 97, 78, 48, 94, 91, 8, 2, 76, 28, 59, 26, 52, 80, 30, 49, 98,
-            68, 88, 74, 95, 60, 61, 24, 72, 69, 56, 77, 33, 75, 96, 79, 11, 44, 93, 66, 32, 46, 17,
-            47, 36, 65, 73, 15, 92, 9, 3, 16, 99, 5, 86, 85, 13, 38, 42, 58, 64, 10, 81, 84, 35,
-            19, 50, 27, 25, 89, 45, 82, 39, 21, 100, 20, 54, 22, 29, 41, 90, 53, 83, 70, 37, 57,
-            63, 23, 40, 12, 4, 7, 18, 62, 51, 87, 43, 67, 0,
-        ],
-        [
-            64, 38, 75, 89, 99, 26, 15, 6, 25, 13, 68, 8, 5, 17, 61, 44, 91, 2, 81, 45, 29, 100,
-            82, 96, 47, 24, 53, 48, 72, 70, 42, 21, 88, 31, 19, 52, 43, 4, 46, 0, 62, 98, 28, 92,
-            90, 49, 11, 67, 73, 83, 27, 35, 34, 76, 12, 54, 58, 86, 71, 79, 84, 55, 50, 7, 40, 3,
-            41, 78, 87, 10, 93, 23, 85, 16, 97, 30, 57, 69, 22, 37, 59, 20, 65, 95, 14, 33, 39, 63,
-            51, 56, 77, 66, 74, 1, 32, 60, 9, 18, 94, 36, 80,
-        ],
-        [
-            30, 73, 94, 14, 21, 71, 93, 65, 38, 61, 60, 52, 28, 46, 80, 54, 92, 47, 49, 5, 8, 50,
-            29, 97, 26, 56, 40, 68, 70, 91, 42, 84, 85, 63, 48, 74, 98, 76, 72, 64, 90, 69, 89, 77,
-            55, 83, 2, 27, 12, 59, 66, 34, 25, 13, 95, 16, 41, 1, 32, 39, 33, 44, 67, 10, 100, 35,
-            81, 11, 36, 82, 0, 51, 57, 53, 58, 20, 88, 86, 9, 43, 23, 75, 45, 87, 3, 99, 96, 17,
-            78, 15, 24, 6, 37, 22, 4, 62, 19, 7, 18, 31, 79,
-        ],
-        [
-            10, 47, 53, 33, 25, 0, 89, 96, 73, 31, 59, 95, 74, 81, 54, 78, 94, 1, 69, 4, 49, 21,
-            87, 55, 57, 42, 8, 40, 52, 45, 46, 14, 19, 15, 7, 32, 34, 50, 39, 18, 75, 93, 82, 56,
-            23, 37, 6, 100, 20, 67, 29, 63, 44, 97, 27, 86, 2, 70, 12, 16, 17, 36, 85, 92, 76, 13,
-            35, 11, 60, 66, 24, 83, 72, 98, 90, 43, 48, 65, 79, 28, 41, 22, 58, 71, 3, 88, 9, 51,
-            77, 64, 26, 5, 30, 38, 80, 99, 68, 61, 62, 91, 84,
-        ],
-        [
-            44, 83, 30, 69, 20, 10, 9, 28, 38, 23, 64, 15, 14, 1, 61, 59, 87, 85, 13, 77, 22, 52,
-            51, 54, 41, 17, 78, 25, 12, 89, 24, 70, 5, 48, 50, 42, 99, 73, 84, 74, 75, 36, 93, 81,
-            16, 71, 98, 63, 60, 32, 62, 82, 45, 0, 57, 4, 40, 92, 7, 34, 8, 90, 43, 76, 3, 94, 11,
-            86, 100, 29, 39, 6, 68, 2, 91, 27, 79, 35, 49, 37, 19, 58, 46, 18, 66, 47, 56, 31, 53,
-            65, 95, 97, 55, 26, 67, 21, 33, 88, 72, 80, 96,
-        ],
-        [
-            43, 79, 21, 30, 7, 28, 25, 93, 57, 62, 76, 50, 38, 58, 98, 60, 42, 55, 90, 61, 53, 51,
-            3, 31, 45, 83, 40, 81, 26, 95, 80, 19, 33, 35, 5, 86, 32, 71, 49, 59, 66, 15, 89, 67,
-            47, 4, 85, 17, 44, 9, 34, 6, 39, 52, 94, 24, 92, 72, 64, 96, 75, 8, 20, 63, 10, 37, 36,
-            18, 56, 2, 99, 46, 78, 91, 70, 88, 29, 84, 87, 54, 14, 65, 82, 100, 13, 69, 16, 11, 1,
-            0, 74, 12, 48, 97, 23, 27, 73, 68, 22, 77, 41,
-        ],
-        [
-            16, 52, 64, 87, 33, 25, 32, 19, 81, 17, 47, 38, 53, 62, 63, 95, 67, 74, 99, 57, 1, 4,
-            2, 8, 68, 80, 75, 36, 11, 82, 96, 3, 73, 98, 29, 30, 55, 23, 26, 88, 66, 61, 50, 84,
-            40, 13, 85, 21, 51, 46, 97, 89, 59, 54, 24, 45, 70, 86, 44, 31, 41, 60, 49, 100, 5, 22,
-            72, 94, 56, 76, 65, 71, 34, 42, 18, 28, 15, 10, 12, 79, 48, 93, 78, 91, 77, 92, 6, 39,
-            43, 27, 20, 58, 37, 0, 69, 9, 90, 83, 7, 14, 35,
-        ],
-        [
-            59, 83, 92, 28, 34, 10, 20, 74, 11, 100, 5, 94, 0, 60, 7, 15, 82, 65, 30, 43, 85, 57,
-            27, 98, 4, 88, 55, 91, 25, 53, 2, 87, 42, 89, 81, 95, 38, 80, 33, 26, 6, 67, 32, 1, 96,
-            61, 62, 63, 90, 49, 44, 58, 14, 76, 41, 84, 13, 75, 77, 56, 36, 19, 39, 37, 46, 17, 47,
-            68, 66, 70, 73, 93, 72, 78, 23, 99, 18, 50, 86, 35, 69, 21, 71, 45, 12, 29, 52, 40, 16,
-            48, 22, 8, 31, 3, 24, 64, 51, 97, 79, 54, 9,
-        ],
-        [
-            65, 6, 8, 44, 22, 84, 49, 18, 12, 47, 15, 11, 0, 38, 42, 26, 51, 58, 86, 61, 46, 3, 56,
-            54, 28, 66, 24, 4, 80, 48, 10, 96, 37, 73, 90, 55, 39, 85, 50, 81, 7, 78, 19, 41, 63,
-            5, 34, 13, 77, 43, 67, 60, 16, 40, 75, 89, 45, 91, 1, 97, 53, 33, 94, 36, 83, 52, 92,
-            62, 31, 57, 14, 59, 71, 95, 88, 32, 17, 69, 74, 72, 87, 29, 68, 82, 27, 64, 20, 70, 76,
-            98, 2, 79, 100, 30, 25, 21, 23, 93, 99, 35, 9,
-        ],
-        [
-            85, 66, 36, 90, 68, 22, 45, 87, 14, 52, 34, 51, 49, 81, 77, 4, 65, 16, 80, 59, 11, 100,
-            44, 70, 37, 63, 0, 48, 82, 7, 54, 10, 30, 67, 89, 9, 41, 2, 95, 74, 97, 20, 26, 98, 46,
-            53, 62, 21, 69, 99, 29, 86, 56, 13, 78, 27, 91, 71, 73, 23, 84, 3, 19, 61, 47, 18, 32,
-            24, 50, 72, 35, 83, 60, 39, 43, 76, 12, 40, 55, 75, 25, 58, 42, 1, 15, 33, 92, 38, 94,
-            5, 96, 17, 93, 8, 88, 79, 28, 31, 6, 64, 57,
-        ],
-        [
-            47, 59, 12, 6, 82, 21, 7, 67, 100, 19, 84, 2, 64, 55, 56, 37, 49, 63, 70, 74, 80, 4,
-            36, 60, 40, 93, 51, 16, 43, 8, 86, 79, 75, 50, 41, 66, 11, 94, 35, 81, 34, 5, 25, 45,
-            78, 85, 10, 96, 90, 87, 13, 97, 91, 23, 89, 9, 68, 76, 15, 73, 29, 27, 71, 30, 48, 20,
-            88, 99, 65, 57, 62, 92, 42, 0, 31, 33, 52, 3, 98, 1, 77, 14, 54, 24, 22, 46, 44, 28,
-            17, 83, 26, 72, 39, 18, 58, 32, 61, 38, 69, 95, 53,
-        ],
-        [
-            72, 33, 78, 31, 52, 70, 4, 39, 74, 19, 58, 62, 35, 5, 94, 16, 17, 54, 47, 76, 67, 42,
-            3, 91, 21, 50, 49, 66, 71, 20, 59, 13, 0, 32, 27, 81, 88, 90, 2, 9, 15, 11, 73, 6, 63,
-            43, 7, 87, 100, 25, 45, 30, 97, 92, 48, 46, 77, 99, 85, 22, 61, 95, 65, 28, 14, 56, 68,
-            79, 57, 64, 75, 60, 8, 24, 12, 1, 83, 84, 29, 40, 55, 34, 23, 36, 86, 82, 53, 10, 98,
-            69, 44, 96, 80, 51, 93, 37, 26, 18, 89, 41, 38,
-        ],
-        [
-            29, 93, 91, 25, 84, 81, 31, 94, 32, 38, 71, 42, 23, 75, 45, 30, 64, 39, 40, 82, 21, 74,
-            28, 36, 8, 33, 16, 62, 86, 4, 95, 88, 44, 6, 67, 9, 34, 66, 14, 22, 3, 43, 26, 15, 98,
-            10, 47, 41, 7, 27, 78, 76, 58, 65, 61, 0, 85, 17, 83, 56, 50, 97, 2, 49, 79, 100, 92,
-            12, 77, 24, 51, 1, 19, 72, 89, 13, 90, 46, 55, 11, 69, 54, 52, 99, 87, 20, 18, 96, 48,
-            63, 37, 60, 57, 59, 68, 35, 53, 70, 80, 5, 73,
-        ],
-        [
-            91, 32, 7, 81, 15, 68, 31, 39, 4, 0, 41, 78, 66, 71, 40, 1, 88, 94, 76, 64, 26, 74, 38,
-            21, 50, 48, 49, 8, 54, 43, 82, 19, 77, 10, 13, 9, 28, 63, 90, 69, 6, 65, 53, 5, 52, 59,
-            87, 70, 44, 25, 3, 58, 34, 67, 84, 92, 22, 35, 36, 61, 42, 33, 56, 45, 99, 51, 11, 62,
-            57, 89, 80, 96, 20, 29, 75, 24, 79, 72, 97, 73, 55, 85, 86, 27, 47, 14, 30, 18, 100,
-            95, 12, 46, 16, 23, 93, 2, 83, 98, 60, 17, 37,
-        ],
-        [
-            56, 39, 29, 16, 32, 50, 80, 59, 10, 8, 89, 35, 95, 70, 33, 82, 74, 60, 85, 88, 6, 61,
-            0, 75, 17, 77, 14, 24, 55, 69, 12, 76, 41, 4, 20, 37, 23, 66, 34, 13, 18, 43, 91, 44,
-            53, 22, 63, 30, 58, 99, 47, 96, 52, 2, 40, 46, 51, 19, 7, 79, 15, 71, 100, 9, 31, 90,
-            84, 93, 49, 64, 73, 1, 45, 67, 27, 26, 54, 65, 62, 36, 25, 81, 97, 28, 38, 5, 57, 78,
-            42, 68, 92, 72, 21, 83, 98, 94, 87, 3, 86, 11, 48,
-        ],
-        [
-            95, 87, 91, 76, 27, 3, 51, 41, 48, 34, 4, 85, 56, 69, 47, 20, 12, 22, 18, 90, 98, 40,
-            31, 52, 43, 89, 57, 6, 9, 29, 88, 45, 5, 61, 77, 46, 25, 68, 16, 37, 75, 59, 100, 53,
-            63, 65, 96, 32, 35, 82, 23, 42, 14, 0, 19, 28, 39, 78, 66, 79, 50, 26, 36, 80, 49, 10,
-            74, 67, 1, 93, 30, 81, 64, 58, 97, 54, 92, 15, 7, 8, 72, 94, 99, 44, 71, 11, 21, 86,
-            73, 62, 70, 55, 2, 17, 60, 13, 33, 83, 24, 38, 84,
-        ],
-        [
-            33, 54, 97, 65, 76, 19, 35, 23, 62, 95, 80, 44, 48, 67, 5, 47, 71, 40, 93, 51, 24, 22,
-            4, 60, 98, 69, 43, 0, 56, 2, 10, 73, 50, 27, 14, 96, 38, 63, 9, 28, 53, 72, 74, 87, 17,
-            89, 20, 45, 32, 68, 34, 8, 3, 12, 13, 59, 41, 58, 78, 49, 1, 46, 25, 36, 16, 30, 83,
-            37, 75, 66, 86, 52, 26, 64, 99, 82, 18, 29, 11, 57, 100, 85, 61, 70, 94, 42, 15, 77,
-            39, 55, 81, 90, 31, 92, 7, 84, 21, 79, 88, 6, 91,
-        ],
-        [
-            93, 49, 8, 99, 40, 61, 82, 100, 63, 67, 70, 31, 76, 18, 68, 17, 54, 86, 97, 5, 60, 32,
-            36, 1, 88, 9, 0, 72, 43, 57, 34, 45, 96, 52, 19, 73, 69, 12, 53, 79, 25, 38, 58, 71,
-            80, 28, 39, 85, 22, 41, 46, 21, 89, 10, 87, 42, 95, 26, 90, 27, 47, 91, 29, 7, 55, 56,
-            64, 51, 84, 81, 50, 15, 14, 98, 33, 30, 2, 94, 13, 62, 35, 6, 59, 65, 16, 11, 92, 44,
-            75, 66, 77, 3, 37, 23, 4, 20, 48, 24, 83, 78, 74,
-        ],
-        [
-            73, 87, 38, 3, 4, 84, 47, 7, 41, 86, 56, 10, 96, 17, 37, 57, 9, 67, 30, 25, 91, 88, 28,
-            24, 26, 27, 80, 40, 29, 61, 100, 45, 69, 77, 64, 36, 12, 49, 78, 68, 66, 70, 16, 85,
-            83, 33, 59, 32, 6, 89, 34, 11, 62, 65, 5, 92, 52, 63, 44, 48, 71, 13, 95, 21, 97, 42,
-            35, 0, 19, 51, 23, 75, 20, 82, 53, 46, 99, 14, 98, 90, 1, 39, 74, 54, 58, 22, 79, 76,
-            2, 50, 94, 15, 93, 72, 81, 31, 8, 60, 18, 55, 43,
-        ],
-        [
-            49, 52, 60, 85, 78, 44, 15, 43, 69, 76, 30, 40, 37, 34, 13, 25, 55, 92, 7, 98, 31, 66,
-            72, 16, 89, 54, 20, 48, 91, 86, 23, 50, 61, 46, 32, 19, 75, 93, 0, 22, 28, 58, 82, 79,
-            41, 65, 74, 5, 47, 94, 8, 51, 17, 10, 14, 33, 70, 21, 63, 100, 68, 35, 29, 81, 71, 38,
-            59, 99, 80, 77, 73, 12, 95, 39, 90, 42, 24, 6, 57, 97, 26, 67, 1, 18, 96, 83, 4, 87,
-            36, 84, 2, 62, 9, 56, 3, 27, 53, 88, 45, 11, 64,
-        ],
-        [
-            46, 7, 84, 12, 57, 89, 70, 4, 45, 52, 73, 8, 69, 58, 10, 9, 40, 47, 97, 74, 85, 20, 65,
-            68, 67, 19, 66, 92, 36, 64, 14, 42, 83, 53, 33, 13, 87, 15, 94, 31, 86, 80, 55, 3, 71,
-            43, 6, 72, 2, 22, 54, 90, 100, 63, 61, 76, 49, 78, 95, 29, 23, 62, 38, 5, 25, 98, 0,
-            39, 21, 27, 56, 60, 34, 51, 18, 88, 77, 81, 16, 99, 91, 82, 37, 79, 59, 35, 48, 32, 96,
-            30, 93, 17, 24, 44, 41, 50, 11, 26, 75, 1, 28,
-        ],
-        [
-            63, 79, 9, 28, 73, 48, 90, 52, 69, 29, 70, 20, 57, 72, 65, 61, 36, 40, 92, 82, 39, 21,
-            76, 89, 26, 75, 55, 0, 93, 44, 43, 11, 62, 23, 98, 8, 46, 67, 60, 71, 47, 91, 58, 35,
-            3, 31, 51, 16, 95, 24, 50, 100, 13, 17, 37, 10, 12, 34, 7, 87, 6, 19, 97, 41, 25, 66,
-            85, 54, 18, 81, 4, 2, 74, 5, 45, 94, 68, 83, 33, 49, 27, 99, 1, 64, 59, 38, 78, 14, 86,
-            96, 84, 15, 56, 53, 30, 88, 77, 80, 42, 22, 32,
-        ],
-        [
-            3, 53, 15, 49, 24, 27, 90, 9, 10, 44, 13, 63, 31, 8, 97, 38, 45, 4, 76, 32, 92, 69, 35,
-            67, 1, 95, 26, 59, 51, 80, 83, 29, 40, 22, 91, 2, 7, 46, 43, 0, 70, 18, 37, 14, 64, 42,
-            75, 23, 19, 100, 78, 21, 48, 98, 60, 73, 84, 77, 30, 12, 33, 47, 89, 28, 20, 82, 93,
-            85, 88, 62, 65, 99, 11, 66, 87, 5, 50, 25, 86, 96, 52, 41, 36, 71, 57, 68, 94, 17, 34,
-            58, 54, 6, 16, 81, 79, 39, 55, 74, 61, 72, 56,
-        ],
-        [
-            64, 97, 78, 69, 60, 25, 41, 54, 7, 32, 59, 4, 37, 27, 12, 86, 93, 28, 65, 76, 5, 80,
-            22, 92, 26, 61, 71, 35, 89, 62, 2, 19, 81, 98, 40, 77, 11, 58, 36, 73, 70, 20, 55, 84,
-            91, 24, 0, 100, 74, 8, 57, 87, 94, 39, 72, 52, 63, 14, 43, 45, 83, 82, 95, 49, 88, 75,
-            46, 34, 48, 3, 30, 1, 13, 23, 67, 51, 47, 6, 68, 31, 53, 21, 85, 29, 90, 15, 18, 96,
-            66, 99, 9, 50, 17, 56, 33, 16, 79, 44, 42, 38, 10,
-        ],
-        [
-            38, 44, 51, 17, 20, 18, 4, 49, 95, 9, 61, 92, 70, 39, 41, 65, 69, 27, 42, 63, 5, 53,
-            64, 28, 50, 12, 97, 43, 48, 10, 14, 71, 89, 67, 81, 21, 78, 57, 72, 2, 16, 90, 80, 56,
-            47, 88, 29, 66, 68, 85, 33, 75, 45, 34, 0, 31, 19, 84, 6, 32, 37, 11, 26, 99, 36, 96,
-            46, 35, 25, 58, 86, 1, 93, 76, 62, 74, 79, 82, 77, 59, 3, 40, 60, 7, 83, 13, 87, 8, 91,
-            94, 54, 73, 30, 22, 52, 98, 100, 23, 55, 15, 24,
-        ],
-        [
-            62, 63, 74, 16, 9, 70, 59, 28, 48, 3, 0, 93, 31, 53, 78, 45, 100, 77, 39, 69, 41, 20,
-            99, 43, 8, 89, 61, 35, 25, 26, 87, 85, 60, 52, 83, 32, 75, 76, 86, 94, 14, 1, 7, 58,
-            81, 55, 44, 82, 92, 30, 88, 17, 54, 11, 57, 95, 40, 21, 2, 24, 84, 42, 29, 67, 5, 64,
-            19, 72, 71, 22, 49, 37, 79, 12, 46, 33, 27, 18, 4, 73, 65, 91, 66, 36, 13, 34, 56, 38,
-            10, 96, 6, 47, 98, 80, 68, 97, 50, 15, 23, 51, 90,
-        ],
-        [
-            31, 25, 60, 50, 74, 30, 4, 28, 55, 64, 78, 86, 9, 17, 85, 100, 0, 80, 27, 10, 19, 13,
-            14, 84, 44, 91, 69, 47, 8, 23, 54, 48, 62, 7, 24, 87, 59, 42, 56, 43, 83, 89, 41, 33,
-            36, 71, 20, 38, 90, 39, 34, 68, 26, 63, 6, 92, 70, 51, 72, 57, 29, 79, 12, 1, 65, 21,
-            2, 11, 73, 3, 52, 76, 32, 40, 66, 46, 5, 18, 53, 58, 98, 16, 88, 96, 81, 97, 77, 35,
-            82, 15, 95, 67, 99, 22, 37, 49, 93, 45, 94, 61, 75,
-        ],
-        [
-            12, 93, 40, 30, 29, 41, 24, 44, 34, 28, 49, 8, 4, 2, 76, 60, 39, 21, 18, 0, 27, 47, 48,
-            53, 19, 56, 33, 25, 13, 71, 90, 84, 69, 65, 45, 7, 35, 50, 46, 26, 57, 67, 22, 62, 66,
-            37, 32, 80, 72, 64, 96, 58, 98, 94, 36, 82, 10, 77, 43, 17, 95, 1, 85, 59, 100, 23, 42,
-            20, 79, 16, 92, 6, 81, 88, 63, 3, 55, 38, 52, 89, 91, 68, 5, 14, 9, 73, 99, 15, 97, 54,
-            78, 11, 87, 70, 74, 86, 51, 83, 31, 75, 61,
-        ],
-        [
-            34, 97, 80, 74, 17, 79, 10, 67, 83, 15, 52, 76, 72, 54, 14, 37, 44, 16, 51, 1, 58, 32,
-            2, 7, 64, 39, 81, 84, 92, 59, 22, 35, 86, 66, 55, 56, 8, 41, 95, 27, 99, 45, 89, 61,
-            100, 4, 26, 48, 77, 85, 11, 46, 0, 68, 49, 19, 6, 87, 36, 31, 62, 25, 33, 88, 63, 38,
-            50, 70, 91, 60, 65, 94, 29, 21, 73, 23, 90, 53, 93, 9, 42, 75, 28, 13, 57, 98, 43, 20,
-            5, 71, 82, 40, 78, 18, 12, 96, 30, 24, 69, 47, 3,
-        ],
-        [
-            0, 28, 9, 92, 36, 89, 96, 23, 74, 90, 69, 20, 76, 75, 99, 53, 26, 93, 34, 63, 91, 32,
-            72, 7, 70, 5, 65, 42, 50, 55, 30, 41, 24, 95, 39, 71, 44, 10, 45, 84, 8, 14, 77, 100,
-            22, 67, 46, 47, 48, 1, 25, 51, 12, 43, 78, 79, 18, 83, 13, 56, 27, 54, 11, 35, 82, 17,
-            37, 3, 98, 61, 86, 52, 80, 31, 15, 73, 38, 62, 97, 58, 81, 66, 19, 6, 87, 94, 59, 40,
-            60, 2, 64, 88, 49, 29, 57, 21, 68, 4, 16, 33, 85,
-        ],
-        [
-            51, 30, 34, 87, 89, 80, 22, 77, 72, 58, 38, 11, 37, 59, 54, 98, 48, 96, 90, 95, 99, 5,
-            52, 94, 83, 88, 92, 62, 55, 9, 31, 75, 61, 47, 16, 49, 78, 1, 69, 35, 85, 0, 28, 24, 6,
-            56, 32, 81, 10, 97, 2, 82, 73, 53, 43, 86, 76, 67, 100, 23, 84, 7, 14, 79, 12, 63, 44,
-            93, 65, 64, 40, 57, 41, 91, 36, 3, 20, 25, 45, 27, 13, 29, 39, 33, 50, 26, 18, 60, 70,
-            17, 4, 71, 15, 21, 42, 46, 68, 19, 74, 8, 66,
-        ],
-        [
-            69, 57, 82, 87, 4, 19, 95, 23, 17, 70, 47, 94, 50, 2, 34, 43, 92, 25, 40, 30, 6, 67,
-            96, 15, 37, 54, 7, 78, 81, 20, 88, 62, 52, 71, 44, 64, 100, 12, 11, 22, 1, 26, 36, 9,
-            83, 84, 8, 18, 33, 46, 41, 66, 55, 61, 59, 35, 77, 93, 10, 38, 99, 49, 73, 0, 45, 76,
-            5, 24, 89, 42, 72, 79, 60, 58, 85, 68, 27, 63, 74, 3, 91, 86, 28, 14, 21, 13, 51, 75,
-            65, 97, 39, 32, 90, 53, 29, 98, 16, 31, 80, 56, 48,
-        ],
-        [
-            99, 80, 5, 6, 87, 44, 94, 17, 52, 64, 66, 39, 1, 27, 69, 56, 42, 65, 61, 74, 63, 18,
-            31, 49, 71, 77, 91, 85, 97, 16, 7, 78, 72, 38, 81, 10, 15, 28, 55, 79, 0, 45, 59, 92,
-            8, 98, 46, 34, 19, 12, 36, 24, 35, 96, 20, 32, 68, 83, 37, 90, 54, 29, 75, 2, 43, 70,
-            86, 13, 40, 41, 48, 62, 89, 67, 82, 84, 33, 25, 50, 60, 58, 14, 73, 23, 26, 76, 3, 22,
-            9, 57, 4, 95, 93, 100, 51, 30, 47, 53, 11, 21, 88,
-        ],
-        [
-            75, 63, 97, 88, 93, 86, 34, 98, 21, 42, 13, 18, 84, 48, 79, 12, 99, 50, 7, 66, 56, 29,
-            65, 24, 71, 81, 46, 91, 17, 57, 20, 54, 62, 22, 96, 78, 80, 49, 67, 100, 6, 11, 52, 36,
-            90, 3, 64, 2, 61, 70, 59, 73, 14, 72, 27, 55, 51, 77, 28, 30, 25, 0, 60, 94, 76, 1, 26,
-            95, 85, 58, 23, 68, 19, 16, 32, 31, 5, 10, 69, 87, 33, 92, 45, 40, 47, 4, 41, 39, 89,
-            8, 15, 43, 44, 35, 38, 74, 83, 37, 9, 82, 53,
-        ],
-        [
-            10, 35, 17, 61, 66, 48, 55, 18, 26, 37, 93, 75, 78, 70, 49, 32, 43, 5, 16, 42, 30, 50,
-            21, 85, 12, 67, 84, 40, 71, 99, 63, 64, 68, 86, 94, 13, 29, 81, 76, 51, 8, 97, 19, 59,
-            80, 24, 54, 82, 28, 74, 52, 91, 89, 9, 62, 33, 53, 1, 14, 39, 0, 56, 72, 57, 47, 60,
-            58, 96, 44, 31, 7, 98, 41, 90, 4, 2, 3, 34, 15, 20, 69, 65, 87, 100, 46, 23, 45, 83,
-            92, 73, 25, 77, 22, 6, 95, 36, 27, 38, 79, 11, 88,
-        ],
-        [
-            49, 0, 46, 16, 78, 99, 3, 56, 86, 20, 1, 68, 13, 4, 14, 2, 12, 54, 70, 97, 25, 41, 79,
-            63, 38, 28, 11, 60, 6, 33, 48, 50, 76, 31, 23, 61, 66, 47, 21, 65, 80, 96, 8, 87, 34,
-            9, 53, 17, 81, 35, 44, 77, 29, 5, 37, 82, 100, 40, 19, 74, 94, 90, 55, 85, 52, 42, 27,
-            62, 83, 71, 84, 64, 98, 91, 26, 88, 89, 95, 92, 10, 75, 15, 39, 45, 18, 32, 51, 67, 36,
-            24, 58, 7, 69, 59, 93, 57, 30, 73, 72, 22, 43,
-        ],
-        [
-            45, 35, 93, 64, 86, 60, 100, 4, 22, 7, 16, 82, 36, 79, 38, 92, 1, 66, 90, 85, 53, 13,
-            80, 8, 42, 84, 67, 56, 20, 57, 41, 61, 40, 73, 52, 33, 55, 28, 24, 63, 70, 31, 6, 26,
-            83, 62, 81, 34, 59, 71, 11, 37, 65, 3, 39, 18, 23, 58, 17, 29, 25, 32, 0, 54, 78, 76,
-            10, 88, 69, 30, 46, 50, 9, 27, 44, 74, 51, 77, 12, 15, 47, 49, 94, 43, 99, 48, 14, 96,
-            91, 72, 95, 75, 97, 19, 98, 89, 68, 21, 5, 2, 87,
-        ],
-        [
-            46, 56, 18, 26, 57, 42, 59, 69, 11, 40, 71, 54, 49, 68, 88, 22, 21, 20, 74, 3, 52, 63,
-            47, 85, 66, 77, 4, 73, 95, 6, 35, 86, 83, 97, 32, 19, 53, 27, 43, 34, 100, 50, 62, 28,
-            93, 55, 51, 48, 84, 96, 81, 9, 91, 8, 72, 41, 60, 78, 33, 7, 90, 75, 37, 98, 58, 5, 31,
-            23, 14, 13, 44, 29, 45, 79, 64, 82, 30, 89, 76, 87, 16, 0, 80, 67, 1, 94, 2, 65, 36,
-            70, 25, 10, 61, 92, 17, 38, 15, 39, 12, 24, 99,
-        ],
-        [
-            82, 14, 68, 8, 52, 48, 18, 1, 80, 0, 27, 50, 97, 72, 76, 64, 57, 95, 34, 69, 7, 99, 83,
-            35, 23, 40, 93, 59, 53, 10, 79, 63, 39, 11, 36, 16, 42, 73, 77, 47, 61, 26, 100, 44,
-            49, 37, 96, 85, 65, 4, 28, 29, 45, 13, 33, 88, 15, 86, 58, 71, 22, 60, 66, 3, 31, 43,
-            19, 9, 6, 94, 87, 38, 32, 2, 21, 5, 78, 84, 51, 30, 56, 92, 81, 90, 91, 46, 17, 67, 24,
-            54, 98, 74, 89, 12, 55, 25, 75, 20, 70, 41, 62,
-        ],
-        [
-            55, 68, 50, 31, 79, 34, 95, 74, 86, 66, 52, 58, 53, 97, 43, 56, 1, 77, 88, 10, 40, 35,
-            46, 18, 62, 12, 9, 65, 5, 28, 51, 47, 81, 41, 14, 33, 3, 44, 20, 0, 30, 78, 96, 45,
-            100, 19, 92, 48, 93, 70, 61, 98, 80, 22, 82, 7, 24, 37, 39, 49, 75, 63, 4, 59, 23, 13,
-            29, 21, 36, 69, 84, 60, 8, 99, 89, 25, 32, 27, 90, 11, 91, 64, 94, 16, 72, 83, 54, 76,
-            73, 57, 67, 17, 42, 38, 85, 87, 15, 26, 6, 2, 71,
-        ],
-        [
-            64, 71, 39, 37, 47, 54, 82, 94, 51, 53, 35, 72, 1, 79, 69, 96, 33, 84, 81, 31, 8, 77,
-            30, 11, 45, 60, 40, 83, 95, 85, 32, 49, 2, 74, 20, 24, 92, 14, 50, 90, 42, 17, 76, 18,
-            26, 89, 48, 7, 87, 65, 97, 93, 29, 38, 41, 62, 19, 43, 58, 100, 10, 57, 16, 59, 63, 99,
-            21, 68, 3, 56, 55, 91, 70, 34, 86, 5, 27, 52, 9, 6, 23, 4, 0, 98, 67, 46, 61, 13, 25,
-            28, 15, 80, 36, 78, 44, 22, 75, 73, 66, 88, 12,
-        ],
-        [
-            72, 73, 91, 97, 26, 2, 29, 52, 70, 75, 69, 90, 8, 24, 17, 80, 82, 57, 10, 65, 60, 46,
-            58, 39, 14, 16, 27, 7, 36, 15, 49, 33, 40, 56, 4, 85, 74, 1, 55, 30, 86, 20, 21, 92, 3,
-            100, 67, 13, 71, 42, 83, 32, 41, 9, 18, 12, 23, 89, 45, 54, 48, 81, 93, 37, 64, 79, 50,
-            96, 62, 94, 19, 5, 59, 66, 61, 78, 6, 28, 44, 88, 53, 51, 31, 22, 76, 34, 77, 25, 63,
-            87, 84, 47, 99, 11, 0, 43, 98, 95, 35, 38, 68,
-        ],
-        [
-            34, 45, 38, 3, 56, 66, 40, 48, 94, 7, 86, 43, 55, 71, 35, 36, 61, 78, 49, 87, 5, 32,
-            60, 6, 39, 63, 33, 99, 85, 83, 52, 30, 16, 90, 9, 46, 89, 88, 100, 67, 41, 79, 13, 37,
-            53, 14, 72, 42, 25, 98, 54, 50, 64, 23, 96, 2, 21, 11, 10, 93, 59, 15, 68, 76, 91, 70,
-            84, 29, 47, 65, 92, 75, 26, 51, 58, 82, 17, 74, 24, 8, 22, 20, 0, 27, 18, 44, 73, 4,
-            80, 1, 31, 95, 97, 12, 69, 28, 19, 57, 62, 81, 77,
-        ],
-        [
-            26, 37, 47, 49, 54, 74, 39, 17, 86, 60, 94, 72, 61, 32, 80, 7, 82, 59, 0, 30, 100, 3,
-            64, 95, 63, 4, 18, 48, 8, 66, 53, 97, 55, 79, 83, 33, 93, 36, 81, 58, 38, 20, 71, 40,
-            98, 85, 88, 2, 31, 6, 23, 27, 42, 12, 50, 45, 41, 43, 67, 78, 22, 73, 21, 89, 91, 11,
-            96, 35, 99, 51, 62, 57, 9, 25, 5, 70, 15, 16, 52, 19, 29, 34, 92, 75, 28, 44, 10, 87,
-            84, 69, 1, 56, 68, 76, 24, 14, 46, 77, 13, 90, 65,
-        ],
-        [
-            1, 98, 31, 74, 62, 58, 90, 16, 30, 48, 6, 3, 49, 19, 65, 61, 84, 28, 39, 13, 51, 7, 82,
-            99, 54, 14, 64, 2, 68, 17, 4, 24, 35, 33, 46, 45, 70, 26, 59, 29, 20, 96, 23, 52, 25,
-            76, 87, 34, 40, 71, 80, 69, 21, 10, 73, 95, 41, 94, 83, 60, 27, 77, 88, 97, 53, 56, 79,
-            91, 38, 67, 55, 36, 57, 44, 11, 100, 93, 0, 12, 72, 8, 63, 78, 15, 37, 47, 22, 50, 42,
-            18, 9, 66, 81, 32, 5, 86, 85, 43, 89, 75, 92,
-        ],
-        [
-            52, 88, 86, 64, 76, 93, 20, 71, 48, 14, 57, 98, 94, 11, 10, 6, 77, 23, 1, 50, 32, 39,
-            25, 2, 70, 87, 67, 0, 31, 60, 5, 45, 58, 43, 68, 53, 80, 92, 27, 19, 12, 96, 29, 33,
-            54, 24, 85, 13, 84, 79, 4, 15, 95, 59, 37, 82, 90, 74, 21, 47, 49, 63, 89, 56, 40, 99,
-            78, 9, 22, 91, 55, 51, 65, 62, 34, 46, 72, 3, 100, 44, 28, 69, 36, 17, 30, 7, 75, 35,
-            8, 16, 18, 97, 83, 61, 81, 73, 42, 38, 66, 26, 41,
-        ],
-        [
-            85, 66, 32, 11, 90, 44, 2, 27, 73, 92, 62, 55, 48, 7, 64, 21, 29, 6, 63, 47, 59, 17,
-            10, 18, 34, 20, 3, 60, 43, 98, 52, 88, 86, 69, 68, 80, 99, 49, 12, 50, 5, 70, 14, 38,
-            79, 77, 39, 28, 23, 100, 57, 0, 76, 9, 51, 78, 74, 53, 93, 33, 54, 95, 1, 83, 84, 22,
-            96, 30, 13, 87, 81, 72, 56, 36, 31, 26, 89, 45, 71, 25, 4, 58, 41, 97, 67, 24, 19, 46,
-            37, 75, 8, 40, 15, 94, 65, 42, 91, 16, 61, 82, 35,
-        ],
-        [
-            95, 44, 87, 8, 17, 27, 84, 45, 78, 22, 56, 31, 88, 12, 50, 2, 10, 55, 58, 97, 51, 26,
-            0, 32, 94, 76, 29, 6, 20, 75, 98, 74, 68, 48, 64, 52, 11, 77, 96, 61, 16, 37, 79, 90,
-            41, 35, 28, 5, 83, 43, 24, 82, 70, 47, 46, 66, 33, 63, 1, 69, 89, 38, 85, 42, 57, 49,
-            40, 4, 9, 60, 91, 19, 30, 80, 15, 36, 18, 13, 81, 93, 7, 14, 25, 92, 62, 100, 23, 67,
-            72, 59, 71, 73, 53, 34, 54, 39, 21, 86, 99, 3, 65,
-        ],
-        [
-            56, 88, 18, 95, 100, 40, 21, 54, 97, 51, 22, 26, 19, 8, 52, 67, 48, 78, 3, 68, 74, 20,
-            50, 58, 39, 91, 7, 31, 14, 0, 79, 86, 1, 83, 45, 12, 64, 37, 11, 72, 98, 73, 96, 9, 99,
-            29, 25, 65, 53, 2, 42, 55, 75, 13, 32, 47, 60, 63, 77, 81, 23, 92, 24, 94, 76, 61, 17,
-            43, 46, 35, 84, 4, 49, 5, 59, 57, 70, 16, 6, 28, 93, 10, 27, 15, 82, 90, 66, 36, 33,
-            30, 89, 38, 41, 34, 87, 71, 62, 85, 80, 69, 44,
-        ],
-        [
-            31, 26, 33, 74, 45, 6, 67, 55, 18, 3, 36, 68, 90, 12, 58, 9, 79, 1, 21, 10, 64, 38, 30,
-            96, 65, 91, 39, 77, 0, 93, 54, 57, 81, 95, 8, 48, 41, 29, 40, 92, 72, 63, 42, 78, 94,
-            7, 86, 75, 89, 50, 59, 99, 84, 37, 13, 85, 98, 44, 16, 97, 51, 2, 4, 49, 82, 83, 53,
-            60, 47, 19, 35, 76, 11, 24, 62, 20, 80, 56, 14, 17, 73, 69, 22, 100, 43, 28, 87, 52,
-            23, 71, 25, 46, 32, 34, 70, 27, 61, 5, 66, 15, 88,
-        ],
-        [
-            45, 29, 38, 19, 92, 48, 4, 18, 10, 89, 47, 61, 36, 100, 21, 50, 74, 72, 6, 14, 37, 93,
-            96, 97, 69, 42, 46, 76, 11, 41, 81, 60, 27, 35, 94, 53, 33, 65, 75, 15, 32, 57, 85, 28,
-            13, 67, 82, 55, 84, 49, 30, 70, 8, 23, 1, 22, 58, 56, 98, 40, 64, 91, 31, 80, 90, 87,
-            20, 26, 24, 78, 59, 62, 79, 68, 88, 0, 5, 71, 17, 95, 7, 2, 99, 73, 51, 25, 86, 3, 44,
-            9, 66, 54, 52, 77, 34, 16, 12, 83, 39, 63, 43,
-        ],
-        [
-            42, 99, 25, 2, 84, 52, 71, 76, 11, 54, 93, 18, 91, 4, 95, 19, 60, 13, 58, 89, 55, 24,
-            5, 63, 98, 29, 22, 33, 51, 9, 23, 40, 0, 72, 66, 3, 30, 34, 74, 83, 59, 31, 57, 96, 48,
-            78, 79, 85, 14, 92, 97, 16, 47, 39, 88, 37, 17, 64, 94, 15, 1, 32, 75, 67, 7, 26, 80,
-            38, 86, 69, 87, 61, 68, 50, 81, 62, 28, 8, 10, 53, 56, 43, 41, 65, 27, 49, 90, 44, 73,
-            36, 12, 82, 46, 100, 70, 6, 45, 20, 77, 35, 21,
-        ],
-        [
-            42, 26, 31, 28, 41, 20, 57, 98, 73, 100, 91, 33, 38, 96, 61, 95, 66, 22, 19, 16, 84, 0,
-            27, 74, 43, 9, 79, 51, 53, 12, 40, 99, 11, 4, 56, 69, 76, 17, 60, 52, 49, 87, 81, 62,
-            64, 25, 72, 71, 59, 36, 83, 55, 8, 58, 48, 39, 34, 13, 46, 75, 7, 65, 93, 85, 23, 77,
-            30, 97, 67, 5, 45, 54, 92, 2, 15, 1, 78, 80, 21, 82, 89, 24, 90, 94, 37, 68, 44, 63,
-            70, 47, 50, 14, 6, 18, 3, 35, 10, 29, 88, 32, 86,
-        ],
-        [
-            46, 32, 91, 27, 63, 38, 23, 29, 36, 1, 99, 89, 68, 3, 61, 88, 25, 50, 33, 41, 44, 37,
-            96, 70, 18, 67, 94, 72, 100, 35, 14, 97, 92, 71, 19, 45, 15, 39, 87, 13, 79, 47, 6, 56,
-            83, 58, 57, 59, 90, 60, 54, 22, 86, 78, 21, 48, 49, 51, 80, 2, 98, 30, 0, 4, 85, 74,
-            52, 5, 8, 10, 12, 55, 77, 9, 11, 82, 7, 95, 84, 76, 93, 62, 31, 28, 24, 69, 43, 64, 53,
-            42, 16, 73, 81, 26, 66, 65, 34, 40, 17, 20, 75,
-        ],
-        [
-            67, 78, 24, 73, 57, 89, 34, 15, 31, 59, 13, 55, 9, 40, 88, 17, 75, 27, 5, 99, 65, 86,
-            79, 11, 52, 46, 74, 26, 29, 71, 3, 85, 25, 0, 45, 53, 72, 97, 91, 56, 81, 33, 95, 12,
-            21, 47, 20, 38, 6, 22, 87, 63, 36, 18, 23, 1, 58, 51, 10, 93, 43, 80, 49, 28, 92, 83,
-            8, 64, 77, 98, 4, 62, 19, 39, 16, 68, 42, 66, 41, 76, 54, 61, 90, 69, 50, 48, 44, 37,
-            94, 14, 35, 70, 96, 84, 100, 32, 60, 2, 82, 30, 7,
-        ],
-        [
-            20, 76, 79, 34, 25, 91, 51, 11, 97, 17, 43, 92, 57, 72, 64, 88, 42, 59, 22, 39, 0, 49,
-            60, 46, 37, 8, 90, 32, 9, 99, 27, 38, 40, 96, 82, 55, 93, 87, 95, 68, 14, 78, 23, 100,
-            80, 35, 33, 7, 3, 47, 65, 36, 73, 19, 61, 4, 21, 6, 94, 44, 12, 98, 67, 75, 81, 56, 70,
-            69, 10, 71, 29, 26, 63, 89, 28, 48, 66, 83, 45, 13, 85, 18, 2, 16, 62, 53, 77, 24, 84,
-            58, 50, 52, 1, 31, 5, 41, 86, 30, 15, 54, 74,
-        ],
-        [
-            23, 57, 95, 84, 1, 14, 78, 89, 0, 4, 62, 73, 70, 67, 21, 49, 16, 36, 82, 5, 86, 69, 38,
-            59, 100, 88, 17, 45, 31, 74, 9, 51, 22, 96, 53, 32, 90, 27, 52, 75, 58, 7, 20, 87, 18,
-            91, 99, 77, 83, 6, 44, 15, 19, 76, 12, 10, 54, 98, 61, 41, 24, 28, 37, 71, 47, 79, 72,
-            66, 92, 35, 33, 29, 3, 80, 42, 64, 25, 11, 50, 46, 55, 60, 40, 81, 56, 30, 26, 48, 13,
-            85, 68, 39, 2, 65, 63, 34, 93, 43, 97, 94, 8,
-        ],
-        [
-            50, 29, 47, 65, 37, 80, 14, 96, 97, 83, 7, 87, 32, 39, 100, 3, 16, 51, 94, 53, 52, 41,
-            61, 89, 59, 95, 25, 69, 49, 92, 30, 57, 75, 72, 74, 12, 40, 46, 93, 79, 86, 23, 70, 73,
-            98, 88, 20, 6, 62, 26, 90, 78, 21, 77, 58, 42, 81, 60, 76, 48, 22, 64, 24, 13, 36, 11,
-            0, 8, 33, 2, 63, 82, 15, 55, 1, 27, 35, 99, 45, 9, 18, 43, 31, 44, 54, 67, 10, 4, 5,
-            56, 84, 68, 85, 66, 34, 71, 38, 91, 19, 17, 28,
-        ],
-        [
-            50, 86, 39, 66, 90, 6, 84, 22, 14, 99, 69, 16, 12, 44, 85, 91, 63, 87, 51, 45, 62, 89,
-            47, 9, 52, 55, 21, 41, 49, 25, 26, 33, 36, 80, 8, 57, 11, 48, 10, 93, 100, 30, 29, 17,
-            54, 1, 70, 95, 67, 27, 74, 42, 7, 5, 34, 64, 31, 60, 75, 56, 94, 18, 72, 88, 79, 82,
-            98, 20, 3, 61, 73, 24, 37, 59, 71, 65, 43, 53, 77, 68, 81, 13, 46, 15, 96, 35, 58, 40,
-            2, 0, 23, 38, 97, 78, 76, 28, 92, 19, 32, 4, 83,
-        ],
-        [
-            63, 52, 31, 38, 23, 68, 76, 79, 55, 56, 15, 37, 12, 81, 28, 32, 24, 92, 11, 91, 25, 64,
-            93, 7, 58, 46, 51, 50, 47, 94, 41, 100, 66, 18, 14, 45, 33, 9, 73, 3, 70, 83, 42, 34,
-            71, 48, 67, 26, 2, 0, 61, 85, 98, 17, 6, 74, 30, 44, 65, 60, 13, 75, 96, 54, 29, 80, 5,
-            49, 59, 90, 72, 95, 39, 35, 21, 10, 53, 89, 97, 43, 4, 82, 19, 86, 36, 99, 62, 40, 27,
-            20, 22, 78, 1, 57, 87, 88, 16, 8, 77, 69, 84,
-        ],
-        [
-            15, 43, 95, 38, 9, 88, 41, 62, 46, 6, 23, 3, 98, 56, 44, 5, 84, 94, 14, 48, 37, 4, 50,
-            35, 91, 47, 34, 86, 64, 85, 60, 21, 24, 74, 75, 67, 81, 63, 80, 11, 65, 29, 0, 57, 89,
-            71, 90, 33, 51, 54, 1, 20, 22, 79, 92, 42, 99, 13, 76, 70, 8, 49, 59, 36, 28, 30, 73,
-            2, 61, 17, 18, 55, 82, 39, 96, 53, 100, 40, 19, 52, 66, 26, 27, 83, 10, 58, 12, 31, 72,
-            32, 7, 25, 68, 97, 77, 69, 93, 78, 45, 16, 87,
-        ],
-        [
-            85, 41, 70, 28, 26, 98, 8, 52, 73, 47, 11, 42, 88, 99, 67, 80, 30, 91, 27, 25, 13, 71,
-            76, 38, 37, 58, 84, 81, 51, 10, 49, 64, 17, 4, 97, 31, 9, 3, 68, 57, 45, 74, 86, 16,
-            60, 56, 24, 69, 94, 43, 15, 36, 19, 90, 65, 93, 32, 6, 95, 92, 63, 5, 1, 62, 53, 18,
-            79, 77, 21, 44, 83, 40, 0, 78, 35, 23, 72, 29, 12, 50, 54, 82, 22, 59, 48, 87, 89, 33,
-            46, 20, 55, 61, 7, 34, 2, 39, 100, 66, 96, 14, 75,
-        ],
-        [
-            79, 23, 56, 66, 57, 93, 70, 68, 2, 49, 19, 12, 62, 14, 58, 86, 16, 78, 37, 73, 87, 20,
-            5, 95, 71, 44, 53, 98, 18, 8, 9, 31, 38, 22, 42, 40, 99, 26, 84, 81, 7, 59, 29, 21, 11,
-            52, 54, 89, 77, 61, 82, 34, 88, 67, 97, 75, 72, 45, 36, 17, 64, 33, 96, 27, 94, 91, 65,
-            0, 80, 69, 4, 13, 10, 39, 51, 76, 35, 83, 24, 6, 43, 92, 85, 60, 46, 48, 47, 3, 55, 74,
-            90, 32, 25, 15, 50, 41, 100, 28, 30, 1, 63,
-        ],
-        [
-            78, 21, 89, 96, 88, 68, 73, 92, 22, 61, 28, 8, 56, 30, 84, 65, 33, 47, 36, 11, 52, 98,
-            20, 99, 71, 85, 29, 42, 60, 50, 48, 17, 2, 53, 82, 35, 25, 81, 23, 5, 66, 70, 27, 76,
-            6, 77, 54, 3, 97, 62, 72, 44, 75, 1, 69, 51, 38, 90, 0, 94, 37, 4, 100, 15, 43, 26, 40,
-            12, 63, 86, 64, 39, 93, 79, 10, 34, 87, 59, 16, 32, 80, 31, 83, 18, 55, 14, 46, 7, 49,
-            19, 58, 57, 74, 41, 24, 13, 45, 91, 9, 67, 95,
-        ],
-        [
-            55, 3, 91, 2, 20, 34, 74, 96, 47, 40, 95, 49, 6, 23, 5, 27, 39, 19, 69, 57, 33, 21, 60,
-            72, 44, 89, 31, 99, 25, 42, 18, 70, 16, 82, 81, 32, 41, 65, 0, 75, 59, 53, 50, 43, 73,
-            10, 46, 7, 1, 9, 14, 28, 71, 100, 92, 4, 24, 76, 67, 35, 66, 36, 22, 79, 15, 87, 83,
-            88, 98, 56, 54, 13, 77, 12, 45, 80, 85, 26, 64, 8, 84, 62, 52, 37, 68, 61, 78, 17, 86,
-            63, 97, 29, 93, 58, 38, 11, 90, 30, 94, 51, 48,
-        ],
-        [
-            81, 22, 92, 30, 55, 76, 80, 7, 4, 78, 44, 49, 39, 91, 84, 24, 79, 46, 72, 36, 16, 65,
-            67, 42, 50, 87, 69, 63, 13, 21, 53, 86, 90, 5, 51, 89, 77, 15, 59, 47, 40, 43, 60, 52,
-            8, 95, 66, 96, 26, 10, 1, 61, 12, 2, 33, 31, 88, 97, 45, 85, 34, 32, 41, 17, 28, 58,
-            83, 93, 64, 73, 14, 19, 54, 100, 98, 48, 70, 29, 23, 56, 82, 9, 35, 27, 11, 25, 3, 57,
-            0, 62, 71, 18, 99, 37, 6, 20, 38, 94, 68, 74, 75,
-        ],
-        [
-            41, 97, 58, 54, 90, 62, 80, 19, 86, 89, 20, 42, 26, 52, 18, 99, 46, 84, 70, 63, 32, 64,
-            15, 81, 43, 27, 56, 57, 79, 48, 29, 2, 92, 5, 10, 45, 22, 3, 0, 17, 9, 33, 96, 77, 85,
-            53, 14, 34, 38, 61, 74, 55, 4, 13, 16, 47, 87, 25, 78, 36, 31, 6, 93, 98, 82, 91, 51,
-            83, 69, 50, 67, 60, 12, 28, 72, 49, 94, 7, 44, 71, 24, 75, 40, 30, 73, 95, 8, 66, 65,
-            59, 21, 100, 88, 1, 23, 35, 11, 76, 68, 37, 39,
-        ],
-        [
-            19, 13, 21, 78, 64, 96, 1, 3, 11, 92, 38, 5, 56, 50, 83, 86, 68, 22, 90, 53, 24, 98,
-            97, 88, 35, 63, 15, 8, 65, 43, 44, 4, 61, 45, 75, 52, 80, 48, 6, 32, 74, 73, 66, 42,
-            40, 76, 99, 79, 81, 59, 57, 47, 16, 87, 84, 67, 58, 72, 30, 93, 14, 77, 12, 51, 54, 62,
-            7, 20, 39, 9, 10, 100, 55, 34, 18, 27, 31, 2, 91, 0, 85, 95, 49, 36, 37, 41, 33, 26,
-            25, 71, 70, 28, 94, 23, 29, 17, 89, 69, 60, 82, 46,
-        ],
-        [
-            35, 77, 90, 87, 95, 61, 55, 74, 97, 30, 11, 51, 99, 31, 45, 24, 20, 23, 17, 16, 14, 8,
-            25, 22, 13, 48, 56, 59, 29, 65, 83, 66, 44, 38, 0, 80, 98, 46, 78, 2, 67, 63, 64, 89,
-            5, 92, 37, 100, 73, 39, 26, 15, 58, 84, 32, 3, 12, 6, 18, 75, 49, 91, 53, 94, 52, 71,
-            72, 82, 81, 1, 68, 33, 50, 42, 43, 69, 54, 10, 96, 79, 86, 93, 40, 21, 19, 27, 9, 4,
-            62, 70, 88, 57, 41, 28, 60, 85, 36, 76, 34, 7, 47,
-        ],
-        [
-            28, 21, 80, 96, 23, 10, 0, 18, 81, 51, 94, 77, 24, 52, 9, 2, 100, 41, 61, 30, 42, 98,
-            25, 97, 57, 36, 12, 56, 85, 71, 15, 39, 38, 54, 3, 68, 31, 90, 91, 69, 33, 83, 63, 87,
-            67, 89, 26, 86, 16, 49, 40, 13, 37, 76, 53, 99, 29, 27, 11, 82, 55, 46, 32, 4, 62, 47,
-            34, 8, 50, 44, 84, 1, 58, 88, 6, 72, 93, 78, 20, 19, 35, 59, 95, 45, 43, 48, 60, 17,
-            22, 73, 75, 5, 70, 92, 79, 66, 65, 7, 64, 74, 14,
-        ],
-        [
-            15, 22, 18, 45, 47, 67, 40, 93, 55, 12, 34, 54, 56, 38, 14, 2, 31, 66, 0, 13, 25, 41,
-            53, 79, 86, 35, 68, 27, 39, 83, 3, 90, 73, 58, 64, 16, 8, 63, 51, 21, 69, 94, 19, 61,
-            5, 82, 20, 37, 28, 57, 60, 4, 87, 88, 59, 24, 80, 36, 76, 100, 70, 91, 23, 74, 17, 72,
-            99, 43, 42, 84, 33, 6, 78, 49, 97, 71, 26, 75, 1, 9, 48, 50, 65, 98, 11, 44, 46, 30,
-            10, 52, 81, 95, 77, 29, 96, 85, 62, 32, 7, 92, 89,
-        ],
-        [
-            84, 44, 50, 24, 19, 23, 86, 20, 6, 60, 8, 55, 68, 66, 33, 14, 62, 89, 16, 22, 28, 21,
-            25, 72, 40, 35, 31, 27, 69, 3, 41, 100, 57, 58, 53, 81, 5, 30, 96, 43, 78, 9, 79, 39,
-            59, 46, 97, 38, 90, 11, 56, 36, 99, 42, 71, 82, 51, 95, 67, 32, 73, 93, 74, 77, 15, 98,
-            52, 37, 34, 65, 45, 13, 87, 91, 29, 54, 80, 7, 83, 10, 85, 47, 63, 18, 2, 17, 26, 88,
-            4, 94, 76, 75, 70, 48, 0, 92, 49, 1, 64, 12, 61,
-        ],
-        [
-            35, 79, 85, 68, 83, 80, 89, 4, 43, 6, 61, 91, 86, 65, 92, 81, 76, 5, 40, 84, 28, 7, 16,
-            88, 46, 38, 100, 62, 10, 69, 44, 42, 26, 64, 97, 23, 21, 87, 71, 56, 8, 34, 49, 63, 45,
-            74, 0, 70, 24, 9, 77, 17, 93, 72, 11, 25, 55, 30, 59, 96, 95, 15, 66, 82, 47, 27, 20,
-            41, 37, 94, 60, 52, 22, 98, 73, 32, 18, 58, 2, 99, 90, 3, 50, 14, 1, 54, 36, 33, 19,
-            53, 75, 67, 31, 51, 48, 57, 12, 13, 39, 29, 78,
-        ],
-        [
-            67, 94, 8, 89, 84, 39, 83, 11, 37, 53, 24, 2, 74, 98, 76, 91, 100, 30, 63, 27, 43, 6,
-            60, 50, 5, 69, 25, 68, 73, 14, 96, 86, 82, 44, 0, 26, 34, 12, 1, 61, 3, 81, 16, 92, 21,
-            95, 10, 97, 4, 48, 28, 78, 52, 54, 77, 51, 33, 99, 49, 15, 18, 38, 85, 9, 65, 29, 87,
-            40, 62, 71, 13, 32, 7, 46, 64, 20, 59, 80, 45, 93, 35, 47, 88, 57, 72, 41, 58, 55, 70,
-            31, 19, 75, 42, 36, 79, 17, 56, 23, 22, 90, 66,
-        ],
-        [
-            39, 58, 59, 91, 2, 67, 14, 29, 35, 3, 69, 74, 92, 18, 22, 57, 51, 70, 4, 65, 49, 0, 77,
-            21, 38, 44, 83, 68, 25, 89, 56, 34, 24, 99, 8, 7, 16, 9, 52, 50, 6, 13, 53, 28, 98, 45,
-            33, 100, 76, 10, 81, 71, 82, 84, 42, 94, 88, 30, 26, 27, 1, 48, 86, 62, 54, 96, 95, 61,
-            5, 31, 80, 79, 19, 17, 78, 73, 23, 60, 93, 75, 72, 37, 63, 46, 43, 15, 11, 64, 36, 55,
-            66, 32, 41, 85, 12, 40, 47, 20, 97, 87, 90,
-        ],
-        [
-            28, 3, 32, 100, 83, 92, 88, 70, 37, 41, 44, 26, 68, 93, 66, 98, 1, 63, 25, 47, 13, 2,
-            45, 43, 53, 16, 65, 82, 33, 29, 73, 87, 17, 31, 42, 0, 90, 8, 34, 99, 22, 19, 20, 46,
-            71, 54, 23, 36, 76, 10, 52, 69, 57, 86, 62, 59, 84, 81, 21, 85, 12, 60, 48, 38, 97, 96,
-            35, 51, 91, 61, 15, 4, 75, 78, 24, 27, 94, 64, 39, 7, 9, 30, 14, 50, 72, 55, 49, 18,
-            77, 95, 74, 5, 79, 67, 56, 11, 40, 80, 58, 89, 6,
-        ],
-        [
-            64, 44, 65, 56, 59, 74, 70, 43, 19, 85, 51, 100, 94, 30, 89, 1, 16, 33, 63, 75, 61, 8,
-            5, 31, 88, 78, 48, 97, 73, 83, 41, 98, 7, 95, 79, 21, 69, 18, 84, 93, 17, 22, 28, 3,
-            92, 9, 27, 68, 62, 60, 52, 6, 66, 14, 36, 35, 82, 50, 55, 39, 10, 0, 87, 54, 25, 76,
-            23, 11, 86, 46, 40, 72, 4, 26, 12, 67, 53, 90, 47, 2, 45, 15, 58, 32, 49, 80, 20, 96,
-            37, 13, 34, 29, 57, 81, 42, 99, 71, 24, 91, 77, 38,
-        ],
-        [
-            22, 78, 19, 83, 7, 5, 85, 53, 18, 10, 98, 91, 63, 21, 31, 26, 11, 28, 62, 29, 92, 79,
-            73, 60, 42, 61, 13, 8, 23, 25, 76, 66, 27, 3, 35, 69, 41, 30, 84, 39, 34, 50, 51, 36,
-            71, 75, 74, 4, 58, 56, 2, 0, 82, 46, 48, 20, 44, 72, 14, 57, 45, 90, 89, 77, 86, 95,
-            47, 68, 52, 49, 93, 15, 40, 65, 17, 100, 88, 32, 33, 9, 55, 16, 70, 54, 43, 38, 87, 24,
-            1, 99, 12, 37, 96, 59, 6, 67, 97, 94, 64, 81, 80,
-        ],
-        [
-            61, 26, 57, 11, 96, 74, 10, 86, 36, 68, 46, 49, 95, 79, 34, 3, 35, 81, 98, 90, 78, 24,
-            97, 45, 51, 92, 23, 0, 70, 84, 91, 64, 28, 43, 4, 29, 1, 7, 48, 2, 67, 18, 66, 83, 94,
-            72, 42, 53, 73, 33, 71, 38, 19, 59, 88, 52, 40, 82, 22, 50, 75, 99, 76, 15, 17, 87, 80,
-            65, 41, 31, 20, 58, 37, 13, 39, 30, 63, 16, 47, 12, 60, 6, 56, 14, 93, 32, 8, 5, 62,
-            77, 44, 21, 9, 55, 27, 54, 85, 25, 89, 100, 69,
-        ],
-        [
-            82, 70, 77, 80, 9, 21, 64, 8, 24, 15, 34, 6, 52, 95, 79, 13, 19, 30, 33, 42, 4, 18, 39,
-            93, 53, 59, 71, 87, 62, 81, 46, 20, 56, 31, 68, 91, 67, 32, 90, 37, 1, 45, 73, 61, 27,
-            17, 41, 11, 57, 35, 89, 94, 86, 28, 26, 69, 84, 38, 88, 63, 23, 14, 29, 98, 60, 40, 78,
-            16, 25, 55, 7, 100, 50, 0, 22, 99, 51, 2, 48, 75, 72, 10, 54, 74, 92, 85, 36, 3, 76, 5,
-            83, 49, 47, 58, 43, 66, 44, 65, 97, 96, 12,
-        ],
-        [
-            69, 48, 95, 25, 68, 46, 6, 67, 65, 76, 11, 20, 26, 64, 73, 40, 51, 27, 5, 42, 87, 71,
-            79, 34, 2, 36, 80, 72, 38, 23, 13, 97, 98, 74, 30, 54, 18, 47, 28, 83, 55, 22, 89, 8,
-            75, 50, 10, 66, 45, 49, 63, 81, 15, 33, 43, 53, 78, 86, 21, 91, 84, 92, 16, 39, 77, 37,
-            24, 4, 82, 35, 52, 60, 100, 44, 59, 0, 70, 7, 58, 9, 62, 56, 1, 61, 96, 29, 99, 41, 57,
-            90, 94, 93, 88, 85, 17, 12, 32, 14, 31, 19, 3,
-        ],
-        [
-            35, 53, 100, 67, 15, 52, 14, 97, 10, 5, 62, 30, 29, 44, 1, 36, 93, 31, 74, 77, 9, 47,
-            78, 70, 16, 83, 50, 0, 64, 71, 24, 61, 84, 94, 59, 4, 49, 72, 34, 32, 82, 38, 2, 96,
-            58, 48, 19, 95, 33, 66, 98, 75, 13, 12, 41, 17, 57, 87, 79, 46, 68, 20, 60, 91, 81, 6,
-            51, 76, 43, 99, 37, 42, 89, 25, 85, 27, 40, 8, 86, 63, 88, 3, 22, 56, 7, 39, 69, 11,
-            28, 73, 26, 80, 18, 21, 65, 55, 92, 90, 23, 45, 54,
-        ],
-        [
-            38, 52, 74, 3, 0, 31, 11, 26, 60, 18, 63, 13, 46, 27, 10, 57, 91, 92, 32, 24, 58, 76,
-            99, 2, 15, 69, 93, 41, 81, 64, 21, 39, 75, 72, 62, 100, 66, 44, 37, 95, 88, 45, 36, 35,
-            19, 22, 40, 47, 97, 53, 17, 98, 86, 73, 79, 23, 61, 14, 25, 80, 49, 78, 68, 33, 48, 83,
-            82, 5, 77, 85, 87, 43, 89, 28, 65, 96, 9, 30, 84, 67, 71, 4, 12, 1, 94, 29, 6, 55, 51,
-            42, 50, 34, 54, 70, 20, 90, 59, 16, 7, 56, 8,
-        ],
-        [
-            57, 5, 92, 9, 88, 40, 28, 66, 99, 67, 26, 8, 55, 52, 35, 64, 50, 60, 20, 18, 53, 21,
-            37, 72, 3, 84, 19, 4, 16, 23, 24, 41, 81, 51, 87, 85, 74, 75, 69, 44, 27, 96, 6, 98,
-            49, 63, 34, 11, 17, 77, 13, 15, 0, 2, 93, 59, 14, 47, 76, 79, 45, 48, 89, 33, 36, 10,
-            73, 65, 68, 58, 25, 30, 100, 12, 62, 71, 94, 70, 83, 1, 91, 95, 39, 86, 56, 22, 80, 82,
-            43, 38, 61, 54, 42, 46, 78, 32, 29, 7, 97, 90, 31,
-        ],
-        [
-            42, 14, 16, 70, 17, 38, 49, 8, 23, 61, 57, 28, 58, 71, 90, 50, 51, 29, 76, 53, 47, 7,
-            36, 0, 94, 65, 80, 60, 15, 59, 37, 26, 66, 55, 18, 20, 6, 46, 63, 2, 52, 44, 33, 84,
-            95, 74, 12, 56, 83, 45, 68, 87, 27, 79, 62, 43, 64, 13, 40, 1, 99, 81, 4, 5, 21, 72,
-            98, 54, 73, 19, 78, 39, 10, 48, 88, 97, 69, 93, 96, 25, 89, 24, 31, 41, 86, 92, 100,
-            77, 75, 34, 11, 35, 30, 82, 67, 91, 22, 9, 85, 32, 3,
-        ],
-        [
-            81, 92, 88, 42, 37, 34, 67, 84, 89, 18, 62, 19, 68, 9, 82, 48, 16, 57, 26, 64, 29, 52,
-            54, 58, 63, 1, 27, 96, 66, 23, 83, 77, 98, 28, 25, 33, 69, 97, 99, 22, 35, 31, 73, 71,
-            70, 75, 87, 38, 39, 0, 13, 4, 65, 79, 14, 20, 45, 11, 49, 85, 17, 94, 43, 50, 15, 53,
-            41, 60, 55, 3, 72, 56, 91, 32, 5, 80, 47, 36, 44, 24, 30, 8, 61, 100, 74, 59, 2, 76,
-            10, 40, 51, 6, 93, 12, 78, 21, 46, 7, 86, 90, 95,
-        ],
-    ],
-};
@@ -31,4 +31,72 @@ pub enum ErrorCode {
 
     #[msg("Not Implemented")]
     NotImplemented,
+
+    #[msg("Staking pool has not started yet")]
+    PoolNotStarted,
+
+    #[msg("Staking pool has ended")]
+    PoolEnded,
+
+    #[msg("Mint is not allowed in this staking pool")]
+    MintNotAllowed,
+
+    #[msg("Operation is not supported by this kind of staking pool")]
+    InvalidPoolKind,
+
+    #[msg("Invalid token amount")]
+    InvalidAmount,
+
+    #[msg("No multiplier at this index")]
+    InvalidMultiplierIndex,
+
+    #[msg("Multiplier type does not match the kind of the multiplier")]
+    InvalidMultiplierType,
+
+    #[msg("Traits merkle root is not set")]
+    TraitsMerkleRootNotSet,
+
+    #[msg("Invalid merkle proof")]
+    InvalidProof,
+
+    #[msg("Address is not registered in the project")]
+    CriteriaNotRegistered,
+
+    #[msg("Criteria is already added to the staking pool")]
+    CriteriaAlreadyAdded,
+
+    #[msg("Criteria is not part of the staking pool")]
+    CriteriaNotFound,
+
+    #[msg("Staking pool is paused")]
+    PoolPaused,
+
+    #[msg("Staking pool still has staked assets")]
+    PoolNotEmpty,
+
+    #[msg("Currency accounts are required to settle rewards on unstake")]
+    RewardsAccountsNotProvided,
+
+    #[msg("Too many NFTs provided to claim rewards for")]
+    TooManyNfts,
+
+    #[msg("NFT provided more than once")]
+    DuplicateNFT,
+
+    #[msg(
+        "Emission epochs must be in ascending start_time order with a rate of at most 10_000 bps"
+    )]
+    InvalidEmissionSchedule,
+
+    #[msg("Staking pool does not belong to the project")]
+    InvalidProject,
+
+    #[msg("Staking pool is already migrated")]
+    AlreadyMigrated,
+
+    #[msg("Compressed rewards ratio must be at most 10_000 bps")]
+    InvalidCompressedRewardsRatio,
+
+    #[msg("Staking pool has not been migrated yet")]
+    PoolNotMigrated,
 }
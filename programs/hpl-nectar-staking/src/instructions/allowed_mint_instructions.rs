@@ -0,0 +1,117 @@
+use {
+    crate::state::*,
+    anchor_lang::prelude::*,
+    hpl_hive_control::{
+        program::HplHiveControl,
+        state::{DelegateAuthority, Project},
+    },
+    hpl_utils::traits::Default,
+};
+
+/// Accounts used in add allowed mint instruction
+#[derive(Accounts)]
+pub struct AddAllowedMint<'info> {
+    /// StakingPool state account
+    #[account(has_one = project)]
+    pub staking_pool: Box<Account<'info, StakingPool>>,
+
+    /// AllowedMint state account
+    #[account(
+        init, payer = payer,
+        space = AllowedMint::LEN,
+        seeds = [
+            b"allowed_mint",
+            staking_pool.key().as_ref(),
+            mint.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub allowed_mint: Account<'info, AllowedMint>,
+
+    /// Mint address of the NFT
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub mint: AccountInfo<'info>,
+
+    /// The wallet that holds authority for this action
+    #[account()]
+    pub authority: Signer<'info>,
+
+    /// The wallet that pays for the rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// NATIVE SYSTEM PROGRAM
+    pub system_program: Program<'info, System>,
+
+    /// HPL Hive Control Program
+    pub hive_control: Program<'info, HplHiveControl>,
+
+    /// NATIVE INSTRUCTIONS SYSVAR
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    // HIVE CONTROL
+    #[account()]
+    pub project: Box<Account<'info, Project>>,
+    #[account(has_one = authority)]
+    pub delegate_authority: Option<Account<'info, DelegateAuthority>>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub vault: AccountInfo<'info>,
+}
+
+/// Add a mint to the allowlist of the staking_pool
+pub fn add_allowed_mint(ctx: Context<AddAllowedMint>) -> Result<()> {
+    let allowed_mint = &mut ctx.accounts.allowed_mint;
+    allowed_mint.set_defaults();
+    allowed_mint.bump = ctx.bumps["allowed_mint"];
+    allowed_mint.staking_pool = ctx.accounts.staking_pool.key();
+    allowed_mint.mint = ctx.accounts.mint.key();
+    Ok(())
+}
+
+/// Accounts used in remove allowed mint instruction
+#[derive(Accounts)]
+pub struct RemoveAllowedMint<'info> {
+    /// StakingPool state account
+    #[account(has_one = project)]
+    pub staking_pool: Box<Account<'info, StakingPool>>,
+
+    /// AllowedMint state account
+    #[account(mut, has_one = staking_pool, close = payer)]
+    pub allowed_mint: Account<'info, AllowedMint>,
+
+    /// The wallet that holds authority for this action
+    #[account()]
+    pub authority: Signer<'info>,
+
+    /// The wallet that receives the rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// NATIVE SYSTEM PROGRAM
+    pub system_program: Program<'info, System>,
+
+    /// HPL Hive Control Program
+    pub hive_control: Program<'info, HplHiveControl>,
+
+    /// NATIVE INSTRUCTIONS SYSVAR
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    // HIVE CONTROL
+    #[account()]
+    pub project: Box<Account<'info, Project>>,
+    #[account(has_one = authority)]
+    pub delegate_authority: Option<Account<'info, DelegateAuthority>>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub vault: AccountInfo<'info>,
+}
+
+/// Remove a mint from the allowlist of the staking_pool
+pub fn remove_allowed_mint(_ctx: Context<RemoveAllowedMint>) -> Result<()> {
+    Ok(())
+}
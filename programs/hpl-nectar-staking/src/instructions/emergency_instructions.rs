@@ -0,0 +1,410 @@
+use {
+    crate::{errors::ErrorCode, state::*},
+    anchor_lang::prelude::*,
+    anchor_spl::{
+        associated_token::AssociatedToken,
+        token::{self, CloseAccount, Mint, Token, TokenAccount},
+    },
+    hpl_events::HplEvents,
+    hpl_hive_control::{
+        program::HplHiveControl,
+        state::{DelegateAuthority, Project},
+    },
+    mpl_bubblegum::program::Bubblegum,
+    mpl_token_metadata::{
+        instruction::RevokeArgs,
+        state::{Metadata, TokenMetadataAccount},
+    },
+    spl_account_compression::{program::SplAccountCompression, Noop},
+};
+
+/// Accounts used in emergency unstake instruction
+#[derive(Accounts)]
+pub struct EmergencyUnstake<'info> {
+    /// StakingPool state account
+    #[account(mut, has_one = project)]
+    pub staking_pool: Box<Account<'info, StakingPool>>,
+
+    /// NFT state account
+    #[account(mut, has_one = staking_pool, constraint = nft.staker.is_some() && nft.staker.unwrap().eq(&staker.key()), close = wallet)]
+    pub nft: Box<Account<'info, NFTv1>>,
+
+    /// Mint address of the NFT
+    #[account(mut, constraint = nft_mint.key() == nft.mint)]
+    pub nft_mint: Box<Account<'info, Mint>>,
+
+    /// Token account of the NFT
+    #[account(mut, constraint = nft_account.mint == nft_mint.key() && nft_account.owner == wallet.key())]
+    pub nft_account: Box<Account<'info, TokenAccount>>,
+
+    /// NFT token metadata
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub nft_metadata: AccountInfo<'info>,
+
+    /// NFT edition
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub nft_edition: AccountInfo<'info>,
+
+    /// NFT token record
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub nft_token_record: Option<AccountInfo<'info>>,
+
+    /// The account that holds the custodied nft
+    #[account(
+        mut,
+        token::mint = nft_mint,
+        token::authority = staker,
+    )]
+    pub deposit_account: Option<Account<'info, TokenAccount>>,
+
+    /// Deposit token_record
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub deposit_token_record: Option<AccountInfo<'info>>,
+
+    /// Staker state account
+    #[account(mut, has_one = staking_pool, has_one = wallet)]
+    pub staker: Box<Account<'info, Staker>>,
+
+    /// The wallet of the staker, receives the NFT and the rent
+    /// CHECK: This is not dangerous because it is checked against the staker
+    #[account(mut)]
+    pub wallet: AccountInfo<'info>,
+
+    /// The wallet that holds authority for this action
+    #[account()]
+    pub authority: Signer<'info>,
+
+    /// The wallet that pays for the rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// NATIVE SYSTEM PROGRAM
+    pub system_program: Program<'info, System>,
+
+    /// HPL Hive Control Program
+    pub hive_control: Program<'info, HplHiveControl>,
+
+    /// NATIVE TOKEN PROGRAM
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    /// ASSOCIATED TOKEN PROGRAM
+    pub associated_token_program: Program<'info, AssociatedToken>,
+
+    /// METAPLEX TOKEN METADATA PROGRAM
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(address = mpl_token_metadata::ID)]
+    pub token_metadata_program: AccountInfo<'info>,
+
+    /// HPL Events Program
+    pub hpl_events: Program<'info, HplEvents>,
+
+    /// NATIVE CLOCK SYSVAR
+    pub clock: Sysvar<'info, Clock>,
+
+    /// NATIVE INSTRUCTIONS SYSVAR
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    // HIVE CONTROL
+    #[account()]
+    pub project: Box<Account<'info, Project>>,
+    #[account(has_one = authority)]
+    pub delegate_authority: Option<Account<'info, DelegateAuthority>>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub vault: AccountInfo<'info>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub authorization_rules_program: Option<AccountInfo<'info>>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    pub authorization_rules: Option<AccountInfo<'info>>,
+}
+
+/// Return a staked NFT to its owner, ignoring the stake duration and hpl service usage
+/// Frozen NFTs are thawed and the staker revokes its own delegation, as the owner doesn't sign
+pub fn emergency_unstake(ctx: Context<EmergencyUnstake>) -> Result<()> {
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    let staker = &mut ctx.accounts.staker;
+    let nft = &mut ctx.accounts.nft;
+
+    nft.last_unstaked_at = ctx.accounts.clock.unix_timestamp;
+    nft.staker = None;
+    staker.total_staked -= 1;
+    staking_pool.total_staked -= 1;
+    staking_pool.remove_weight(nft.weight, ctx.accounts.clock.unix_timestamp);
+    nft.weight = 0;
+    if staker.total_staked == 0 {
+        staking_pool.total_stakers = staking_pool.total_stakers.saturating_sub(1);
+    }
+    if nft.used_by == NFTUsedBy::Missions {
+        staking_pool.total_used_by_missions = staking_pool.total_used_by_missions.saturating_sub(1);
+    }
+
+    let wallet_key = ctx.accounts.wallet.key();
+    let pool_key = staking_pool.key();
+    let staker_seeds = &[
+        b"staker",
+        wallet_key.as_ref(),
+        pool_key.as_ref(),
+        &[staker.bump],
+    ];
+    let staker_signer = &[&staker_seeds[..]];
+
+    match staking_pool.lock_type {
+        LockType::Freeze => {
+            let metadata_account_info = &ctx.accounts.nft_metadata;
+            if metadata_account_info.data_is_empty() {
+                return Err(ErrorCode::InvalidMetadata.into());
+            }
+
+            let metadata: Metadata = Metadata::from_account_info(metadata_account_info)?;
+            if metadata.mint != ctx.accounts.nft_mint.key() {
+                return Err(ErrorCode::InvalidMetadata.into());
+            }
+
+            let args: Result<RevokeArgs> = match metadata.token_standard {
+                Some(token_standard) => match token_standard {
+                    mpl_token_metadata::state::TokenStandard::ProgrammableNonFungible => {
+                        Ok(RevokeArgs::StakingV1)
+                    }
+                    _ => Ok(RevokeArgs::StandardV1),
+                },
+                None => Err(ErrorCode::InvalidMetadata.into()),
+            };
+
+            hpl_utils::unlock(
+                staker.to_account_info(),
+                ctx.accounts.nft_mint.to_account_info(),
+                ctx.accounts.nft_account.to_account_info(),
+                Some(ctx.accounts.wallet.to_account_info()),
+                ctx.accounts.nft_metadata.to_account_info(),
+                Some(ctx.accounts.nft_edition.to_account_info()),
+                ctx.accounts.nft_token_record.clone(),
+                ctx.accounts.payer.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+                ctx.accounts.instructions_sysvar.to_account_info(),
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.authorization_rules_program.clone(),
+                ctx.accounts.authorization_rules.clone(),
+                Some(staker_signer),
+            )?;
+
+            hpl_utils::revoke(
+                args.unwrap(),
+                None,
+                staker.to_account_info(),
+                ctx.accounts.nft_metadata.to_account_info(),
+                Some(ctx.accounts.nft_edition.to_account_info()),
+                ctx.accounts.nft_token_record.clone(),
+                ctx.accounts.nft_mint.to_account_info(),
+                ctx.accounts.nft_account.to_account_info(),
+                staker.to_account_info(),
+                ctx.accounts.payer.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+                ctx.accounts.instructions_sysvar.to_account_info(),
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.authorization_rules_program.clone(),
+                ctx.accounts.authorization_rules.clone(),
+                Some(staker_signer),
+            )?;
+        }
+        LockType::Custoday => {
+            if let Some(deposit_account) = &ctx.accounts.deposit_account {
+                hpl_utils::transfer(
+                    1,
+                    deposit_account.to_account_info(),
+                    staker.to_account_info(),
+                    ctx.accounts.nft_account.to_account_info(),
+                    ctx.accounts.wallet.to_account_info(),
+                    ctx.accounts.nft_mint.to_account_info(),
+                    ctx.accounts.nft_metadata.to_account_info(),
+                    Some(ctx.accounts.nft_edition.to_account_info()),
+                    ctx.accounts.deposit_token_record.clone(),
+                    ctx.accounts.nft_token_record.clone(),
+                    staker.to_account_info(),
+                    ctx.accounts.payer.to_account_info(),
+                    ctx.accounts.system_program.to_account_info(),
+                    ctx.accounts.token_program.to_account_info(),
+                    ctx.accounts.associated_token_program.to_account_info(),
+                    ctx.accounts.instructions_sysvar.to_account_info(),
+                    ctx.accounts.authorization_rules_program.clone(),
+                    ctx.accounts.authorization_rules.clone(),
+                    Some(staker_signer),
+                )?;
+
+                token::close_account(CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    CloseAccount {
+                        account: deposit_account.to_account_info(),
+                        destination: ctx.accounts.wallet.to_account_info(),
+                        authority: staker.to_account_info(),
+                    },
+                    staker_signer,
+                ))?;
+            } else {
+                return Err(ErrorCode::DepositAccountNotProvided.into());
+            }
+        }
+    }
+
+    Event::unstake(
+        nft.key(),
+        nft.try_to_vec().unwrap(),
+        staker.key(),
+        staker.try_to_vec().unwrap(),
+        &ctx.accounts.clock,
+    )
+    .emit(ctx.accounts.hpl_events.to_account_info())?;
+
+    Ok(())
+}
+
+/// Accounts used in emergency unstake cnft instruction
+#[derive(Accounts)]
+pub struct EmergencyUnstakeCNFT<'info> {
+    /// StakingPool state account
+    #[account(mut, has_one = project)]
+    pub staking_pool: Box<Account<'info, StakingPool>>,
+
+    /// NFT state account
+    #[account(mut, has_one = staking_pool, constraint = nft.staker.is_some() && nft.staker.unwrap().eq(&staker.key()), close = wallet)]
+    pub nft: Box<Account<'info, NFTv1>>,
+
+    /// CHECK: unsafe
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: unsafe
+    pub tree_authority: UncheckedAccount<'info>,
+
+    /// CHECK: unsafe
+    pub creator_hash: UncheckedAccount<'info>,
+
+    /// CHECK: unsafe
+    pub data_hash: UncheckedAccount<'info>,
+
+    /// CHECK: unsafe
+    pub root: UncheckedAccount<'info>,
+
+    /// Staker state account
+    #[account(mut, has_one = staking_pool, has_one = wallet)]
+    pub staker: Box<Account<'info, Staker>>,
+
+    /// The wallet of the staker, receives the cNFT and the rent
+    /// CHECK: This is not dangerous because it is checked against the staker
+    #[account(mut)]
+    pub wallet: AccountInfo<'info>,
+
+    /// The wallet that holds authority for this action
+    #[account()]
+    pub authority: Signer<'info>,
+
+    /// The wallet that pays for the rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// NATIVE SYSTEM PROGRAM
+    pub system_program: Program<'info, System>,
+
+    /// HPL Hive Control Program
+    pub hive_control: Program<'info, HplHiveControl>,
+
+    /// MPL Bubblegum program for cNFTs
+    pub bubblegum_program: Program<'info, Bubblegum>,
+
+    /// SPL Compression Program
+    pub compression_program: Program<'info, SplAccountCompression>,
+
+    /// HPL Events Program
+    pub hpl_events: Program<'info, HplEvents>,
+
+    /// SPL NOOP Program
+    pub log_wrapper: Program<'info, Noop>,
+
+    /// NATIVE CLOCK SYSVAR
+    pub clock: Sysvar<'info, Clock>,
+
+    /// NATIVE INSTRUCTIONS SYSVAR
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    // HIVE CONTROL
+    #[account()]
+    pub project: Box<Account<'info, Project>>,
+    #[account(has_one = authority)]
+    pub delegate_authority: Option<Account<'info, DelegateAuthority>>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub vault: AccountInfo<'info>,
+}
+
+/// Return a staked cNFT to its owner, ignoring the stake duration and hpl service usage
+pub fn emergency_unstake_cnft<'info>(
+    ctx: Context<'_, '_, '_, 'info, EmergencyUnstakeCNFT<'info>>,
+    args: super::CNFTArgs,
+) -> Result<()> {
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    let staker = &mut ctx.accounts.staker;
+    let nft = &mut ctx.accounts.nft;
+
+    nft.last_unstaked_at = ctx.accounts.clock.unix_timestamp;
+    nft.staker = None;
+    staker.total_staked -= 1;
+    staking_pool.total_staked -= 1;
+    staking_pool.remove_weight(nft.weight, ctx.accounts.clock.unix_timestamp);
+    nft.weight = 0;
+    if staker.total_staked == 0 {
+        staking_pool.total_stakers = staking_pool.total_stakers.saturating_sub(1);
+    }
+    if nft.used_by == NFTUsedBy::Missions {
+        staking_pool.total_used_by_missions = staking_pool.total_used_by_missions.saturating_sub(1);
+    }
+
+    let wallet_key = ctx.accounts.wallet.key();
+    let pool_key = staking_pool.key();
+    let staker_seeds = &[
+        b"staker",
+        wallet_key.as_ref(),
+        pool_key.as_ref(),
+        &[staker.bump],
+    ];
+    let staker_signer = &[&staker_seeds[..]];
+
+    crate::bubblegum::transfer_cnft_cpi(
+        ctx.accounts.tree_authority.to_account_info(),
+        staker.to_account_info(),
+        staker.to_account_info(),
+        ctx.accounts.wallet.to_account_info(),
+        ctx.accounts.merkle_tree.to_account_info(),
+        ctx.accounts.log_wrapper.to_account_info(),
+        ctx.accounts.compression_program.to_account_info(),
+        ctx.accounts.system_program.to_account_info(),
+        ctx.accounts.bubblegum_program.to_account_info(),
+        ctx.remaining_accounts.to_vec(),
+        ctx.accounts.root.key().to_bytes(),
+        ctx.accounts.data_hash.key().to_bytes(),
+        ctx.accounts.creator_hash.key().to_bytes(),
+        args.nonce,
+        args.index,
+        Some(staker_signer),
+    )?;
+
+    Event::unstake(
+        nft.key(),
+        nft.try_to_vec().unwrap(),
+        staker.key(),
+        staker.try_to_vec().unwrap(),
+        &ctx.accounts.clock,
+    )
+    .emit(ctx.accounts.hpl_events.to_account_info())?;
+
+    Ok(())
+}
@@ -1,12 +1,16 @@
+pub mod allowed_mint_instructions;
 pub mod compressed_stake_instructions;
+pub mod emergency_instructions;
 pub mod multiplier_instructions;
 pub mod nft_instructions;
 pub mod pool_instructions;
 pub mod reward_instructions;
 pub mod stake_instructions;
 pub mod staker_instructions;
+pub mod token_stake_instructions;
 
 pub use {
-    compressed_stake_instructions::*, multiplier_instructions::*, nft_instructions::*,
-    pool_instructions::*, reward_instructions::*, stake_instructions::*, staker_instructions::*,
+    allowed_mint_instructions::*, compressed_stake_instructions::*, emergency_instructions::*,
+    multiplier_instructions::*, nft_instructions::*, pool_instructions::*, reward_instructions::*,
+    stake_instructions::*, staker_instructions::*, token_stake_instructions::*,
 };
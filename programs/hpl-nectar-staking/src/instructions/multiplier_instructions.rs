@@ -1,5 +1,5 @@
 use {
-    crate::state::*,
+    crate::{errors::ErrorCode, state::*},
     anchor_lang::prelude::*,
     hpl_hive_control::{
         program::HplHiveControl,
@@ -59,6 +59,8 @@ pub struct InitMultipliers<'info> {
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
 pub struct InitMultipliersArgs {
     decimals: u8,
+    stacking: Option<MultiplierStacking>,
+    max_multiplier: Option<u64>,
 }
 
 /// Initialize multiplier state
@@ -67,10 +69,80 @@ pub fn init_multipliers(ctx: Context<InitMultipliers>, args: InitMultipliersArgs
     multipliers.set_defaults();
     multipliers.bump = ctx.bumps["multipliers"];
     multipliers.decimals = args.decimals;
+    multipliers.stacking = args.stacking.unwrap_or(MultiplierStacking::Additive);
+    multipliers.max_multiplier = args.max_multiplier;
     multipliers.staking_pool = ctx.accounts.staking_pool.key();
     Ok(())
 }
 
+/// Accounts used in update multipliers instruction
+#[derive(Accounts)]
+pub struct UpdateMultipliers<'info> {
+    /// StakingPool state account
+    #[account(has_one = project)]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    /// Multiplier state account
+    #[account(mut, has_one = staking_pool)]
+    pub multipliers: Account<'info, Multipliers>,
+
+    /// The wallet that holds authority for this action
+    #[account()]
+    pub authority: Signer<'info>,
+
+    /// The wallet that pays for the rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// NATIVE SYSTEM PROGRAM
+    pub system_program: Program<'info, System>,
+
+    /// HPL Hive Control Program
+    pub hive_control: Program<'info, HplHiveControl>,
+
+    /// NATIVE INSTRUCTIONS SYSVAR
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    // HIVE CONTROL
+    #[account()]
+    pub project: Box<Account<'info, Project>>,
+    #[account(has_one = authority)]
+    pub delegate_authority: Option<Account<'info, DelegateAuthority>>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub vault: AccountInfo<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UpdateMultipliersArgs {
+    pub traits_merkle_root: Option<[u8; 32]>,
+    pub stacking: Option<MultiplierStacking>,
+    /// Setting the maximum multiplier to 0 removes the cap
+    pub max_multiplier: Option<u64>,
+}
+
+/// Update multipliers state
+pub fn update_multipliers(
+    ctx: Context<UpdateMultipliers>,
+    args: UpdateMultipliersArgs,
+) -> Result<()> {
+    let multipliers = &mut ctx.accounts.multipliers;
+    multipliers.traits_merkle_root = args
+        .traits_merkle_root
+        .unwrap_or(multipliers.traits_merkle_root);
+    multipliers.stacking = args.stacking.unwrap_or(multipliers.stacking);
+    if let Some(max_multiplier) = args.max_multiplier {
+        multipliers.max_multiplier = if max_multiplier == 0 {
+            None
+        } else {
+            Some(max_multiplier)
+        };
+    }
+    Ok(())
+}
+
 /// Accounts used in add multiplier instruction
 #[derive(Accounts)]
 pub struct AddMultiplier<'info> {
@@ -120,7 +192,7 @@ pub struct AddMultiplierArgs {
     pub multiplier_type: MultiplierType,
 }
 
-/// Initialize multiplier state
+/// Add a multiplier
 pub fn add_multiplier(ctx: Context<AddMultiplier>, args: AddMultiplierArgs) -> Result<()> {
     let multipliers = &mut ctx.accounts.multipliers;
 
@@ -132,48 +204,160 @@ pub fn add_multiplier(ctx: Context<AddMultiplier>, args: AddMultiplierArgs) -> R
         &ctx.accounts.system_program,
     )?;
 
-    match args.multiplier_type {
-        MultiplierType::StakeDuration { min_duration: _ } => {
-            multipliers.duration_multipliers.push(Multiplier {
-                value: args.value,
-                multiplier_type: args.multiplier_type,
-            });
-            multipliers
-                .duration_multipliers
-                .sort_by_key(|x| match x.multiplier_type {
-                    MultiplierType::StakeDuration { min_duration } => min_duration,
-                    _ => 0,
-                });
-        }
-        MultiplierType::NFTCount { min_count: _ } => {
-            multipliers.count_multipliers.push(Multiplier {
-                value: args.value,
-                multiplier_type: args.multiplier_type,
-            });
-            multipliers
-                .count_multipliers
-                .sort_by_key(|x| match x.multiplier_type {
-                    MultiplierType::NFTCount { min_count } => min_count,
-                    _ => 0,
-                });
-        }
-        MultiplierType::Creator { creator: _ } => {
-            multipliers.creator_multipliers.push(Multiplier {
-                value: args.value,
-                multiplier_type: args.multiplier_type,
-            });
-            multipliers.creator_multipliers.sort_by_key(|x| x.value)
-        }
-        MultiplierType::Collection { collection: _ } => {
-            multipliers.collection_multipliers.push(Multiplier {
-                value: args.value,
-                multiplier_type: args.multiplier_type,
-            });
-            multipliers
-                .collection_multipliers
-                .sort_by(|a, b| b.value.cmp(&a.value))
+    let kind = args.multiplier_type.kind();
+    multipliers.list_mut(kind).push(Multiplier {
+        value: args.value,
+        multiplier_type: args.multiplier_type,
+    });
+    multipliers.sort(kind);
+
+    Ok(())
+}
+
+/// Accounts used in update multiplier instruction
+#[derive(Accounts)]
+pub struct UpdateMultiplier<'info> {
+    /// StakingPool state account
+    #[account(has_one = project)]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    /// Multiplier state account
+    #[account(mut, has_one = staking_pool)]
+    pub multipliers: Account<'info, Multipliers>,
+
+    /// The wallet that holds authority for this action
+    #[account()]
+    pub authority: Signer<'info>,
+
+    /// The wallet that pays for the rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// NATIVE SYSTEM PROGRAM
+    pub system_program: Program<'info, System>,
+
+    /// HPL Hive Control Program
+    pub hive_control: Program<'info, HplHiveControl>,
+
+    /// RENT SYSVAR
+    pub rent_sysvar: Sysvar<'info, Rent>,
+
+    /// NATIVE INSTRUCTIONS SYSVAR
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    // HIVE CONTROL
+    #[account()]
+    pub project: Box<Account<'info, Project>>,
+    #[account(has_one = authority)]
+    pub delegate_authority: Option<Account<'info, DelegateAuthority>>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub vault: AccountInfo<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UpdateMultiplierArgs {
+    pub kind: MultiplierKind,
+    pub index: u8,
+    pub value: Option<u64>,
+    pub multiplier_type: Option<MultiplierType>,
+}
+
+/// Update a multiplier by its kind and index
+pub fn update_multiplier(ctx: Context<UpdateMultiplier>, args: UpdateMultiplierArgs) -> Result<()> {
+    let multipliers = &mut ctx.accounts.multipliers;
+    let list = multipliers.list_mut(args.kind);
+
+    let multiplier = list
+        .get_mut(usize::from(args.index))
+        .ok_or(ErrorCode::InvalidMultiplierIndex)?;
+
+    if let Some(value) = args.value {
+        multiplier.value = value;
+    }
+
+    if let Some(multiplier_type) = args.multiplier_type {
+        if multiplier_type.kind() != args.kind {
+            return Err(ErrorCode::InvalidMultiplierType.into());
         }
+        multiplier.multiplier_type = multiplier_type;
     }
 
+    multipliers.sort(args.kind);
+
+    Ok(())
+}
+
+/// Accounts used in remove multiplier instruction
+#[derive(Accounts)]
+pub struct RemoveMultiplier<'info> {
+    /// StakingPool state account
+    #[account(has_one = project)]
+    pub staking_pool: Account<'info, StakingPool>,
+
+    /// Multiplier state account
+    #[account(mut, has_one = staking_pool)]
+    pub multipliers: Account<'info, Multipliers>,
+
+    /// The wallet that holds authority for this action
+    #[account()]
+    pub authority: Signer<'info>,
+
+    /// The wallet that receives the rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// NATIVE SYSTEM PROGRAM
+    pub system_program: Program<'info, System>,
+
+    /// HPL Hive Control Program
+    pub hive_control: Program<'info, HplHiveControl>,
+
+    /// RENT SYSVAR
+    pub rent_sysvar: Sysvar<'info, Rent>,
+
+    /// NATIVE INSTRUCTIONS SYSVAR
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    // HIVE CONTROL
+    #[account()]
+    pub project: Box<Account<'info, Project>>,
+    #[account(has_one = authority)]
+    pub delegate_authority: Option<Account<'info, DelegateAuthority>>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub vault: AccountInfo<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RemoveMultiplierArgs {
+    pub kind: MultiplierKind,
+    pub index: u8,
+}
+
+/// Remove a multiplier by its kind and index
+pub fn remove_multiplier(ctx: Context<RemoveMultiplier>, args: RemoveMultiplierArgs) -> Result<()> {
+    let multipliers = &mut ctx.accounts.multipliers;
+    let list = multipliers.list_mut(args.kind);
+
+    if usize::from(args.index) >= list.len() {
+        return Err(ErrorCode::InvalidMultiplierIndex.into());
+    }
+
+    // Removing keeps the remaining multipliers in their sorted order
+    list.remove(usize::from(args.index));
+
+    hpl_utils::reallocate(
+        -isize::try_from(Multiplier::LEN).unwrap(),
+        multipliers.to_account_info(),
+        ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.rent_sysvar,
+        &ctx.accounts.system_program,
+    )?;
+
     Ok(())
 }
@@ -41,6 +41,10 @@ pub struct InitNFT<'info> {
     #[account(mut)]
     pub nft_metadata: AccountInfo<'info>,
 
+    /// Allowlist entry of the NFT mint, required when the staking_pool only accepts allowed mints
+    #[account(has_one = staking_pool, constraint = allowed_mint.mint == nft_mint.key())]
+    pub allowed_mint: Option<Account<'info, AllowedMint>>,
+
     /// The wallet that pays for the rent
     #[account(mut)]
     pub wallet: Signer<'info>,
@@ -82,6 +86,11 @@ pub fn init_nft(ctx: Context<InitNFT>) -> Result<()> {
     nft.staking_pool = ctx.accounts.staking_pool.key();
     nft.mint = ctx.accounts.nft_mint.key();
 
+    if staking_pool.allowed_mints && ctx.accounts.allowed_mint.is_none() {
+        msg!("NFT mint is not in the allowlist");
+        return Err(ErrorCode::MintNotAllowed.into());
+    }
+
     let metadata_account_info = &ctx.accounts.nft_metadata;
 
     if metadata_account_info.data_is_empty() {
@@ -150,13 +159,10 @@ pub fn init_nft(ctx: Context<InitNFT>) -> Result<()> {
             return Ok(());
         }
         Err(_) => {
-            // if staking_pool.allowed_mints {
-            //     if staking_pool.authority == ctx.accounts.wallet.key() {
-            //         return Ok(());
-            //     } else if staking_pool.authority != ctx.accounts.wallet.key() {
-            //         return Err(ErrorCode::OnlyOwner.into());
-            //     }
-            // }
+            if staking_pool.allowed_mints {
+                msg!("Allowed mint: {:?}", nft.mint);
+                return Ok(());
+            }
             return Err(ErrorCode::InvalidMetadata.into());
         }
     }
@@ -321,7 +327,7 @@ pub struct UseNft<'info> {
     pub project: Box<Account<'info, Project>>,
 
     /// StakingPool state account
-    #[account()]
+    #[account(mut)]
     pub staking_pool: Box<Account<'info, StakingPool>>,
 
     /// StakingPool state account
@@ -365,6 +371,13 @@ pub fn use_nft<'info>(ctx: Context<UseNft>, used_by: NFTUsedBy) -> Result<()> {
         return Err(ErrorCode::NFTAlreadyUsed.into());
     }
 
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    if used_by == NFTUsedBy::Missions && ctx.accounts.nft.used_by != NFTUsedBy::Missions {
+        staking_pool.total_used_by_missions += 1;
+    } else if used_by != NFTUsedBy::Missions && ctx.accounts.nft.used_by == NFTUsedBy::Missions {
+        staking_pool.total_used_by_missions = staking_pool.total_used_by_missions.saturating_sub(1);
+    }
+
     ctx.accounts.nft.used_by = used_by;
 
     Event::nft_used(
@@ -377,6 +390,106 @@ pub fn use_nft<'info>(ctx: Context<UseNft>, used_by: NFTUsedBy) -> Result<()> {
     Ok(())
 }
 
+/// Accounts used in prove NFT trait instruction
+#[derive(Accounts)]
+pub struct ProveNftTrait<'info> {
+    // Hive Control Project
+    #[account()]
+    pub project: Box<Account<'info, Project>>,
+
+    /// StakingPool state account
+    #[account(has_one = project)]
+    pub staking_pool: Box<Account<'info, StakingPool>>,
+
+    /// Multiplier state account
+    #[account(has_one = staking_pool)]
+    pub multipliers: Box<Account<'info, Multipliers>>,
+
+    /// NFT state account
+    #[account(mut, has_one = staking_pool)]
+    pub nft: Box<Account<'info, NFTv1>>,
+
+    /// The wallet that pays for the rent
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// NATIVE SYSTEM PROGRAM
+    pub system_program: Program<'info, System>,
+
+    /// HPL Hive Control Program
+    pub hive_control: Program<'info, HplHiveControl>,
+
+    /// HPL Events Program
+    pub hpl_events: Program<'info, HplEvents>,
+
+    /// RENT SYSVAR
+    pub rent_sysvar: Sysvar<'info, Rent>,
+
+    /// NATIVE CLOCK SYSVAR
+    pub clock: Sysvar<'info, Clock>,
+
+    /// NATIVE INSTRUCTIONS SYSVAR
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub vault: AccountInfo<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ProveNftTraitArgs {
+    pub trait_type: String,
+    pub value: String,
+    pub merkle_proof: Vec<[u8; 32]>,
+}
+
+/// Prove a trait of the NFT against the traits merkle root of the staking_pool multipliers
+///
+/// Proven traits are stored on the NFT state account, which is closed on unstake,
+/// so a proof only lasts for one staking period and must be resubmitted after restaking
+pub fn prove_nft_trait(ctx: Context<ProveNftTrait>, args: ProveNftTraitArgs) -> Result<()> {
+    let multipliers = &ctx.accounts.multipliers;
+    let nft = &mut ctx.accounts.nft;
+
+    if multipliers.traits_merkle_root == [0; 32] {
+        return Err(ErrorCode::TraitsMerkleRootNotSet.into());
+    }
+
+    // The leaf commits to the separator-encoded trait hash so that
+    // (trait_type, value) pairs can't be shifted into each other
+    let trait_hash = MultiplierType::trait_hash(&args.trait_type, &args.value);
+    let node = hpl_utils::merkle_tree::create_node(&[&[0x00], nft.mint.as_ref(), &trait_hash]);
+    if !hpl_utils::merkle_tree::verify_merkle(
+        args.merkle_proof,
+        multipliers.traits_merkle_root,
+        node.0,
+    ) {
+        return Err(ErrorCode::InvalidProof.into());
+    }
+
+    if nft.traits.contains(&trait_hash) {
+        msg!("Trait already proven");
+        return Ok(());
+    }
+
+    hpl_utils::reallocate(
+        32,
+        nft.to_account_info(),
+        ctx.accounts.wallet.to_account_info(),
+        &ctx.accounts.rent_sysvar,
+        &ctx.accounts.system_program,
+    )?;
+
+    nft.traits.push(trait_hash);
+
+    Event::nft_trait_proven(nft.key(), nft.try_to_vec().unwrap(), &ctx.accounts.clock)
+        .emit(ctx.accounts.hpl_events.to_account_info())?;
+
+    Ok(())
+}
+
 /// Accounts used in use NFT instruction
 #[derive(Accounts)]
 pub struct CloseNft<'info> {
@@ -412,3 +525,67 @@ pub struct CloseNft<'info> {
 pub fn close_nft<'info>(_ctx: Context<CloseNft>) -> Result<()> {
     Ok(())
 }
+
+/// Accounts used in migrate NFT instruction
+#[derive(Accounts)]
+pub struct MigrateNft<'info> {
+    // Hive Control Project
+    #[account()]
+    pub project: Box<Account<'info, Project>>,
+
+    /// StakingPool state account
+    #[account(has_one = project)]
+    pub staking_pool: Box<Account<'info, StakingPool>>,
+
+    /// NFT state account in the layout it was staked with
+    /// CHECK: This is not dangerous because the owner is checked and the account is deserialized once reallocated
+    #[account(mut, owner = crate::ID)]
+    pub nft: AccountInfo<'info>,
+
+    /// The wallet that pays for the rent
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// NATIVE SYSTEM PROGRAM
+    pub system_program: Program<'info, System>,
+
+    /// HPL Hive Control Program
+    pub hive_control: Program<'info, HplHiveControl>,
+
+    /// RENT SYSVAR
+    pub rent_sysvar: Sysvar<'info, Rent>,
+
+    /// NATIVE INSTRUCTIONS SYSVAR
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub vault: AccountInfo<'info>,
+}
+
+/// Migrate an NFT staked before the reward_index to the current layout
+/// The appended fields are zero filled, the NFT keeps its last_claim and accrues the time
+/// until the staking_pool migration through StakingPool::legacy_rewards
+pub fn migrate_nft(ctx: Context<MigrateNft>) -> Result<()> {
+    ctx.accounts.staking_pool.assert_migrated()?;
+
+    let nft_info = &ctx.accounts.nft;
+    if NFTv1::try_deserialize(&mut &nft_info.try_borrow_data()?[..]).is_err() {
+        hpl_utils::reallocate(
+            isize::try_from(NFTv1::APPENDED_LEN).unwrap(),
+            nft_info.to_account_info(),
+            ctx.accounts.wallet.to_account_info(),
+            &ctx.accounts.rent_sysvar,
+            &ctx.accounts.system_program,
+        )?;
+    }
+
+    let nft = Account::<NFTv1>::try_from(nft_info)?;
+    if nft.staking_pool != ctx.accounts.staking_pool.key() {
+        return Err(ErrorCode::InvalidNFT.into());
+    }
+
+    Ok(())
+}
@@ -1,12 +1,14 @@
 use {
-    crate::state::*,
+    crate::{errors::ErrorCode, state::*},
     anchor_lang::prelude::*,
     anchor_spl::token::{self, Mint, Token},
     hpl_currency_manager::state::Currency,
     hpl_events::HplEvents,
     hpl_hive_control::{
+        cpi::{accounts::AddRemoveService, add_remove_service},
+        instructions::AddRemoveServiceArgs,
         program::HplHiveControl,
-        state::{DelegateAuthority, Project},
+        state::{DelegateAuthority, Project, Service},
     },
     hpl_utils::traits::Default,
 };
@@ -86,8 +88,14 @@ pub struct CreateStakingPoolArgs {
     pub min_stake_duration: Option<u64>,
     pub cooldown_duration: Option<u64>,
     pub reset_stake_duration: Option<bool>,
+    pub allowed_mints: Option<bool>,
     pub start_time: Option<i64>,
     pub end_time: Option<i64>,
+    pub kind: Option<PoolKind>,
+    pub compressed_rewards_ratio: Option<u16>,
+    pub settle_on_unstake: Option<bool>,
+    pub emission_mode: Option<EmissionMode>,
+    pub max_total_rewards: Option<u64>,
 }
 
 /// Create a new staking_pool
@@ -106,12 +114,29 @@ pub fn create_staking_pool(
     staking_pool.lock_type = args.lock_type.unwrap_or(LockType::Freeze);
     staking_pool.rewards_per_duration = args.rewards_per_duration;
     staking_pool.rewards_duration = args.rewards_duration.unwrap_or(1);
+    staking_pool.reward_index_updated_at = ctx.accounts.clock_sysvar.unix_timestamp;
     staking_pool.max_rewards_duration = args.max_rewards_duration;
     staking_pool.min_stake_duration = args.min_stake_duration;
     staking_pool.cooldown_duration = args.cooldown_duration;
     staking_pool.reset_stake_duration = args.reset_stake_duration.unwrap_or(true);
+    staking_pool.allowed_mints = args.allowed_mints.unwrap_or(false);
     staking_pool.start_time = args.start_time;
     staking_pool.end_time = args.end_time;
+    staking_pool.kind = args.kind.unwrap_or(PoolKind::Nft);
+    staking_pool.compressed_rewards_ratio = args
+        .compressed_rewards_ratio
+        .unwrap_or(StakingPool::DEFAULT_COMPRESSED_REWARDS_RATIO);
+    staking_pool.settle_on_unstake = args.settle_on_unstake.unwrap_or(true);
+    staking_pool.emission_mode = args.emission_mode.unwrap_or(EmissionMode::PerNft);
+    staking_pool.max_total_rewards = args.max_total_rewards;
+
+    if staking_pool.is_token_pool() && staking_pool.emission_mode == EmissionMode::Shared {
+        return Err(ErrorCode::InvalidPoolKind.into());
+    }
+
+    if staking_pool.compressed_rewards_ratio > 10_000 {
+        return Err(ErrorCode::InvalidCompressedRewardsRatio.into());
+    }
 
     Ok(())
 }
@@ -154,6 +179,9 @@ pub struct UpdateStakingPool<'info> {
     /// SYSVAR RENT
     pub rent: Sysvar<'info, Rent>,
 
+    /// SYSVAR CLOCK
+    pub clock: Sysvar<'info, Clock>,
+
     /// NATIVE INSTRUCTIONS SYSVAR
     /// CHECK: This is not dangerous because we don't read or write from this account
     #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
@@ -178,8 +206,72 @@ pub struct UpdateStakingPoolArgs {
     pub min_stake_duration: Option<u64>,
     pub cooldown_duration: Option<u64>,
     pub reset_stake_duration: Option<bool>,
+    pub allowed_mints: Option<bool>,
     pub start_time: Option<i64>,
     pub end_time: Option<i64>,
+    pub compressed_rewards_ratio: Option<u16>,
+    pub paused: Option<bool>,
+    pub settle_on_unstake: Option<bool>,
+    /// Can only be changed while nothing is staked in the staking_pool
+    pub emission_mode: Option<EmissionMode>,
+    /// Setting the maximum total rewards to 0 removes the cap
+    pub max_total_rewards: Option<u64>,
+    /// Replaces the emission epochs of the staking_pool, for NFT and token staking_pools alike
+    pub emission_epochs: Option<Vec<EmissionEpoch>>,
+    /// Whether the provided collection, creator and merkle_tree are added or removed, defaults to add
+    pub criteria_action: Option<CriteriaAction>,
+}
+
+/// Position of the address in the addresses registered in the project
+fn project_index(addresses: &[Pubkey], address: &Pubkey) -> Result<u8> {
+    let index = addresses
+        .iter()
+        .position(|x| x.eq(address))
+        .ok_or(ErrorCode::CriteriaNotRegistered)?;
+    u8::try_from(index).map_err(|_| ErrorCode::Overflow.into())
+}
+
+/// Adds or removes a project index from one of the criteria lists of the staking_pool
+fn update_criteria<'info>(
+    staking_pool: &mut Account<'info, StakingPool>,
+    list: fn(&mut StakingPool) -> &mut Vec<u8>,
+    index: u8,
+    action: &CriteriaAction,
+    payer: &Signer<'info>,
+    rent: &Sysvar<'info, Rent>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    match action {
+        CriteriaAction::Add => {
+            if list(staking_pool).contains(&index) {
+                return Err(ErrorCode::CriteriaAlreadyAdded.into());
+            }
+            hpl_utils::reallocate(
+                1,
+                staking_pool.to_account_info(),
+                payer.to_account_info(),
+                rent,
+                system_program,
+            )?;
+            list(staking_pool).push(index);
+        }
+        CriteriaAction::Remove => {
+            let indexes = list(staking_pool);
+            let position = indexes
+                .iter()
+                .position(|x| *x == index)
+                .ok_or(ErrorCode::CriteriaNotFound)?;
+            indexes.remove(position);
+            hpl_utils::reallocate(
+                -1,
+                staking_pool.to_account_info(),
+                payer.to_account_info(),
+                rent,
+                system_program,
+            )?;
+        }
+    }
+    Ok(())
 }
 
 /// Update a staking_pool
@@ -189,6 +281,9 @@ pub fn update_staking_pool(
 ) -> Result<()> {
     let staking_pool = &mut ctx.accounts.staking_pool;
 
+    // Rewards accrued so far keep the rate and window they accrued at
+    staking_pool.checkpoint_reward_index(ctx.accounts.clock.unix_timestamp);
+
     staking_pool.name = args.name.unwrap_or(staking_pool.name.clone());
     staking_pool.rewards_per_duration = args
         .rewards_per_duration
@@ -215,6 +310,7 @@ pub fn update_staking_pool(
     staking_pool.reset_stake_duration = args
         .reset_stake_duration
         .unwrap_or(staking_pool.reset_stake_duration);
+    staking_pool.allowed_mints = args.allowed_mints.unwrap_or(staking_pool.allowed_mints);
     staking_pool.start_time = if args.start_time.is_some() {
         args.start_time
     } else {
@@ -225,63 +321,314 @@ pub fn update_staking_pool(
     } else {
         staking_pool.end_time
     };
+    staking_pool.compressed_rewards_ratio = args
+        .compressed_rewards_ratio
+        .unwrap_or(staking_pool.compressed_rewards_ratio);
+    if staking_pool.compressed_rewards_ratio > 10_000 {
+        return Err(ErrorCode::InvalidCompressedRewardsRatio.into());
+    }
+    staking_pool.paused = args.paused.unwrap_or(staking_pool.paused);
+    staking_pool.settle_on_unstake = args
+        .settle_on_unstake
+        .unwrap_or(staking_pool.settle_on_unstake);
+
+    if let Some(emission_mode) = args.emission_mode {
+        if emission_mode != staking_pool.emission_mode {
+            if staking_pool.total_staked > 0 {
+                return Err(ErrorCode::PoolNotEmpty.into());
+            }
+            if staking_pool.is_token_pool() && emission_mode == EmissionMode::Shared {
+                return Err(ErrorCode::InvalidPoolKind.into());
+            }
+            staking_pool.emission_mode = emission_mode;
+        }
+    }
+
+    if let Some(max_total_rewards) = args.max_total_rewards {
+        staking_pool.max_total_rewards = if max_total_rewards == 0 {
+            None
+        } else {
+            Some(max_total_rewards)
+        };
+    }
+
+    if let Some(emission_epochs) = args.emission_epochs {
+        let ordered = emission_epochs
+            .windows(2)
+            .all(|pair| pair[0].start_time < pair[1].start_time);
+        if !ordered || emission_epochs.iter().any(|epoch| epoch.rate_bps > 10_000) {
+            return Err(ErrorCode::InvalidEmissionSchedule.into());
+        }
+
+        let len_diff = (emission_epochs.len() as isize
+            - staking_pool.emission_epochs.len() as isize)
+            * EmissionEpoch::LEN as isize;
+        staking_pool.emission_epochs = emission_epochs;
+        if len_diff != 0 {
+            hpl_utils::reallocate(
+                len_diff,
+                staking_pool.to_account_info(),
+                ctx.accounts.payer.to_account_info(),
+                &ctx.accounts.rent,
+                &ctx.accounts.system_program,
+            )?;
+        }
+    }
 
     if let Some(currency) = &ctx.accounts.currency {
         staking_pool.currency = currency.key();
     }
 
+    let criteria_action = args.criteria_action.unwrap_or(CriteriaAction::Add);
+
     if let Some(collection) = &ctx.accounts.collection {
-        let index = ctx
-            .accounts
-            .project
-            .collections
-            .iter()
-            .position(|x| x.eq(&collection.key()))
-            .unwrap();
-        hpl_utils::reallocate(
-            1,
-            staking_pool.to_account_info(),
-            ctx.accounts.payer.to_account_info(),
+        let index = project_index(&ctx.accounts.project.collections, &collection.key())?;
+        update_criteria(
+            staking_pool,
+            |pool| &mut pool.collections,
+            index,
+            &criteria_action,
+            &ctx.accounts.payer,
             &ctx.accounts.rent,
             &ctx.accounts.system_program,
         )?;
-        staking_pool.collections.push(index as u8);
     }
 
     if let Some(creator) = &ctx.accounts.creator {
-        let index = ctx
-            .accounts
-            .project
-            .creators
-            .iter()
-            .position(|x| x.eq(&creator.key()))
-            .unwrap();
-        hpl_utils::reallocate(
-            1,
-            staking_pool.to_account_info(),
-            ctx.accounts.payer.to_account_info(),
+        let index = project_index(&ctx.accounts.project.creators, &creator.key())?;
+        update_criteria(
+            staking_pool,
+            |pool| &mut pool.creators,
+            index,
+            &criteria_action,
+            &ctx.accounts.payer,
             &ctx.accounts.rent,
             &ctx.accounts.system_program,
         )?;
-        staking_pool.creators.push(index as u8);
     }
 
     if let Some(merkle_tree) = &ctx.accounts.merkle_tree {
-        let index = ctx
-            .accounts
-            .project
-            .merkle_trees
-            .iter()
-            .position(|x| x.eq(&merkle_tree.key()))
-            .unwrap();
+        let index = project_index(&ctx.accounts.project.merkle_trees, &merkle_tree.key())?;
+        update_criteria(
+            staking_pool,
+            |pool| &mut pool.merkle_trees,
+            index,
+            &criteria_action,
+            &ctx.accounts.payer,
+            &ctx.accounts.rent,
+            &ctx.accounts.system_program,
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Accounts used in close staking_pool instruction
+#[derive(Accounts)]
+pub struct CloseStakingPool<'info> {
+    /// StakingPool state account
+    #[account(mut, has_one = project, close = authority)]
+    pub staking_pool: Box<Account<'info, StakingPool>>,
+
+    /// Multiplier state account of the staking_pool, closed when it was initialized
+    /// CHECK: This is not dangerous because the address is checked by the seeds
+    #[account(mut, seeds = [b"multipliers", staking_pool.key().as_ref()], bump)]
+    pub multipliers: AccountInfo<'info>,
+
+    /// HIVE CONTROL
+    #[account(mut)]
+    pub project: Box<Account<'info, Project>>,
+
+    #[account(has_one = authority)]
+    pub delegate_authority: Option<Account<'info, DelegateAuthority>>,
+
+    /// The wallet that holds authority for this action and receives the rent
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// The wallet that pays for the rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub vault: AccountInfo<'info>,
+
+    /// SYSTEM PROGRAM
+    pub system_program: Program<'info, System>,
+
+    /// HPL Events Program
+    pub hpl_events: Program<'info, HplEvents>,
+
+    /// SYSVAR CLOCK
+    pub clock_sysvar: Sysvar<'info, Clock>,
+
+    /// RENT SYSVAR
+    pub rent_sysvar: Sysvar<'info, Rent>,
+
+    /// NATIVE INSTRUCTIONS SYSVAR
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    /// HIVE CONTROL PROGRAM
+    pub hive_control: Program<'info, HplHiveControl>,
+}
+
+/// Close a staking_pool and its multipliers once nothing is staked in it
+/// NFTs still staked after the end_time can be released with emergency unstake
+pub fn close_staking_pool(ctx: Context<CloseStakingPool>) -> Result<()> {
+    let staking_pool = &ctx.accounts.staking_pool;
+
+    if staking_pool.total_staked > 0 || staking_pool.total_staked_amount > 0 {
+        msg!(
+            "{} NFTs and {} tokens are still staked",
+            staking_pool.total_staked,
+            staking_pool.total_staked_amount
+        );
+        return Err(ErrorCode::PoolNotEmpty.into());
+    }
+
+    if !ctx.accounts.multipliers.data_is_empty() {
+        let multipliers = Account::<Multipliers>::try_from(&ctx.accounts.multipliers)?;
+        multipliers.close(ctx.accounts.authority.to_account_info())?;
+    }
+
+    add_remove_service(
+        CpiContext::new(
+            ctx.accounts.hive_control.to_account_info(),
+            AddRemoveService {
+                project: ctx.accounts.project.to_account_info(),
+                delegate_authority: ctx
+                    .accounts
+                    .delegate_authority
+                    .as_ref()
+                    .map(|x| x.to_account_info()),
+                authority: ctx.accounts.authority.to_account_info(),
+                payer: ctx.accounts.payer.to_account_info(),
+                vault: ctx.accounts.vault.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                hpl_events: ctx.accounts.hpl_events.to_account_info(),
+                clock_sysvar: ctx.accounts.clock_sysvar.to_account_info(),
+                rent_sysvar: ctx.accounts.rent_sysvar.to_account_info(),
+                instructions_sysvar: ctx.accounts.instructions_sysvar.to_account_info(),
+            },
+        ),
+        AddRemoveServiceArgs {
+            service: Service::Staking {
+                pool_id: staking_pool.key(),
+            },
+            remove: Some(true),
+        },
+    )
+}
+
+/// Accounts used in migrate staking_pool instruction
+#[derive(Accounts)]
+pub struct MigrateStakingPool<'info> {
+    /// StakingPool state account in the layout it was created with
+    /// CHECK: This is not dangerous because the owner is checked and the account is deserialized once reallocated
+    #[account(mut, owner = crate::ID)]
+    pub staking_pool: AccountInfo<'info>,
+
+    /// Multiplier state account of the staking_pool, migrated when it was initialized
+    /// CHECK: This is not dangerous because the address is checked by the seeds
+    #[account(mut, seeds = [b"multipliers", staking_pool.key().as_ref()], bump)]
+    pub multipliers: AccountInfo<'info>,
+
+    /// HIVE CONTROL
+    #[account()]
+    pub project: Box<Account<'info, Project>>,
+
+    #[account(has_one = authority)]
+    pub delegate_authority: Option<Account<'info, DelegateAuthority>>,
+
+    /// The wallet that holds authority for this action
+    #[account()]
+    pub authority: Signer<'info>,
+
+    /// The wallet that pays for the rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub vault: AccountInfo<'info>,
+
+    /// SYSTEM PROGRAM
+    pub system_program: Program<'info, System>,
+
+    /// SYSVAR CLOCK
+    pub clock_sysvar: Sysvar<'info, Clock>,
+
+    /// RENT SYSVAR
+    pub rent_sysvar: Sysvar<'info, Rent>,
+
+    /// NATIVE INSTRUCTIONS SYSVAR
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    /// HIVE CONTROL PROGRAM
+    pub hive_control: Program<'info, HplHiveControl>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct MigrateStakingPoolArgs {
+    /// The NFTs currently staked, recounted off-chain since unstaking did not decrement total_staked before
+    pub total_staked: u64,
+    /// The wallets currently staking, recounted off-chain since they were not counted before
+    pub total_stakers: u64,
+}
+
+/// Migrate a staking_pool and its multipliers created before the reward_index to the current layout
+/// The appended fields are zero filled, which keeps the behaviour the staking_pool was created with,
+/// and the reward_index starts accruing now instead of from the unix epoch
+/// NFTs staked before the migration accrue at the current rate until it, see StakingPool::legacy_rewards
+pub fn migrate_staking_pool(
+    ctx: Context<MigrateStakingPool>,
+    args: MigrateStakingPoolArgs,
+) -> Result<()> {
+    let staking_pool_info = &ctx.accounts.staking_pool;
+    if StakingPool::try_deserialize(&mut &staking_pool_info.try_borrow_data()?[..]).is_err() {
         hpl_utils::reallocate(
-            1,
-            staking_pool.to_account_info(),
+            isize::try_from(StakingPool::APPENDED_LEN).unwrap(),
+            staking_pool_info.to_account_info(),
             ctx.accounts.payer.to_account_info(),
-            &ctx.accounts.rent,
+            &ctx.accounts.rent_sysvar,
+            &ctx.accounts.system_program,
+        )?;
+    }
+
+    let mut staking_pool = Account::<StakingPool>::try_from(staking_pool_info)?;
+    if staking_pool.project != ctx.accounts.project.key() {
+        return Err(ErrorCode::InvalidProject.into());
+    }
+    if staking_pool.reward_index_updated_at != 0 {
+        return Err(ErrorCode::AlreadyMigrated.into());
+    }
+
+    let now = ctx.accounts.clock_sysvar.unix_timestamp;
+    staking_pool.compressed_rewards_ratio = StakingPool::DEFAULT_COMPRESSED_REWARDS_RATIO;
+    staking_pool.reward_index = 0;
+    staking_pool.reward_index_updated_at = now;
+    staking_pool.migrated_at = now;
+    staking_pool.legacy_rewards_rate = staking_pool.rewards_rate();
+    staking_pool.total_staked = args.total_staked;
+    staking_pool.total_stakers = args.total_stakers;
+    staking_pool.exit(&crate::ID)?;
+
+    let multipliers_info = &ctx.accounts.multipliers;
+    if !multipliers_info.data_is_empty()
+        && Multipliers::try_deserialize(&mut &multipliers_info.try_borrow_data()?[..]).is_err()
+    {
+        hpl_utils::reallocate(
+            isize::try_from(Multipliers::APPENDED_LEN).unwrap(),
+            multipliers_info.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.rent_sysvar,
             &ctx.accounts.system_program,
         )?;
-        staking_pool.merkle_trees.push(index as u8);
     }
 
     Ok(())
@@ -1,5 +1,5 @@
 use {
-    crate::state::*,
+    crate::{errors::ErrorCode, state::*},
     anchor_lang::prelude::*,
     anchor_spl::token::{Mint, Token, TokenAccount},
     hpl_currency_manager::{
@@ -14,19 +14,111 @@ use {
     },
 };
 
+/// The stake rewards are calculated for
+enum Staked<'a> {
+    Nft {
+        nft: &'a NFTv1,
+        staker: &'a Staker,
+        now: i64,
+    },
+    /// `accrued` is the rewards of the staked amount, scaled by REWARD_INDEX_PRECISION
+    Tokens { accrued: u128 },
+}
+
+/// Seconds of rewards claimable since last_claim, `None` when no rewards are available yet
+fn claimable_seconds(staking_pool: &StakingPool, last_claim: i64, now: i64) -> Option<u64> {
+    let mut seconds_elapsed: u64 = staking_pool.seconds_in_window(last_claim, now);
+
+    if seconds_elapsed == 0 {
+        msg!("No rewards accrued since last claim");
+        return None;
+    }
+
+    if seconds_elapsed < staking_pool.rewards_duration && !staking_pool.has_ended(now) {
+        msg!("Minimum Reward duration not reached yet so rewards not available yet");
+        return None;
+    }
+
+    if let Some(max_rewards_duration) = staking_pool.max_rewards_duration {
+        if max_rewards_duration < seconds_elapsed {
+            seconds_elapsed = max_rewards_duration;
+        }
+    }
+
+    Some(seconds_elapsed)
+}
+
 fn calculate_rewards(
     staking_pool: &StakingPool,
     multipliers: Option<Account<'_, Multipliers>>,
-    nft: &NFTv1,
-    staker: &Staker,
+    staked: Staked,
     seconds_elapsed: u64,
 ) -> (u64, u64) {
-    let rewards_per_second = staking_pool.rewards_per_duration
-        / staking_pool.rewards_duration
-        / (if nft.is_compressed { 10 } else { 1 });
+    let (mut rewards_amount, criteria, staked_count, traits, mint) = match staked {
+        Staked::Nft { nft, staker, now } => {
+            // Each period accrues at the rate in force, see StakingPool::reward_index_at,
+            // NFTs staked before the migration also accrue the time until it
+            let mut accrued = staking_pool
+                .reward_index_at(now)
+                .saturating_sub(nft.reward_index)
+                + staking_pool.legacy_rewards(nft.last_claim);
+
+            // Only seconds_elapsed of the claimable period are rewarded with max_rewards_duration
+            let period = staking_pool.seconds_in_window(nft.last_claim, now);
+            if seconds_elapsed < period {
+                accrued = accrued * seconds_elapsed as u128 / period as u128;
+            }
+
+            if staking_pool.emission_mode == EmissionMode::Shared {
+                // The multipliers are part of the weight the NFT was staked with
+                let rewards = accrued * nft.weight as u128 / StakingPool::REWARD_INDEX_PRECISION;
+                return (rewards as u64, nft.weight);
+            }
 
-    let mut rewards_amount = rewards_per_second * seconds_elapsed;
+            if nft.is_compressed {
+                accrued = accrued * staking_pool.compressed_rewards_ratio as u128 / 10_000;
+            }
 
+            (
+                (accrued / StakingPool::REWARD_INDEX_PRECISION) as u64,
+                nft.criteria,
+                Some(staker.total_staked),
+                nft.traits.as_slice(),
+                Some(nft.mint),
+            )
+        }
+        Staked::Tokens { accrued } => (
+            (accrued / StakingPool::REWARD_INDEX_PRECISION) as u64,
+            NFTCriteria::None,
+            None,
+            &[][..],
+            None,
+        ),
+    };
+
+    let (total_multipliers, multplier_decimals) = total_multiplier(
+        multipliers,
+        Some(seconds_elapsed),
+        criteria,
+        staked_count,
+        traits,
+        mint,
+    );
+    rewards_amount = (rewards_amount * total_multipliers) / multplier_decimals;
+
+    (rewards_amount, total_multipliers)
+}
+
+/// The combined multiplier of a staked asset and the scale it is expressed in
+/// The duration multipliers are skipped when `seconds_elapsed` is `None`
+fn total_multiplier(
+    multipliers: Option<Account<'_, Multipliers>>,
+    seconds_elapsed: Option<u64>,
+    criteria: NFTCriteria,
+    staked_count: Option<u64>,
+    traits: &[[u8; 32]],
+    mint: Option<Pubkey>,
+) -> (u64, u64) {
     let mut total_multipliers = 1u64;
     let mut multplier_decimals = 1u64;
     if let Some(multipliers) = multipliers {
@@ -34,41 +126,43 @@ fn calculate_rewards(
         total_multipliers = multplier_decimals;
 
         let mut duration_multiplier = multplier_decimals;
-        for multiplier in multipliers.duration_multipliers.iter() {
-            match multiplier.multiplier_type {
-                MultiplierType::StakeDuration { min_duration } => {
-                    if seconds_elapsed < min_duration {
-                        duration_multiplier = multiplier.value;
-                    } else {
-                        break;
+        if let Some(seconds_elapsed) = seconds_elapsed {
+            for multiplier in multipliers.duration_multipliers.iter() {
+                match multiplier.multiplier_type {
+                    MultiplierType::StakeDuration { min_duration } => {
+                        if seconds_elapsed < min_duration {
+                            duration_multiplier = multiplier.value;
+                        } else {
+                            break;
+                        }
                     }
+                    _ => {}
                 }
-                _ => {}
             }
         }
-        duration_multiplier -= multplier_decimals;
-        total_multipliers += duration_multiplier;
+        total_multipliers = multipliers.stack(total_multipliers, duration_multiplier);
 
         let mut count_multiplier = multplier_decimals;
-        for multiplier in multipliers.count_multipliers.iter() {
-            match multiplier.multiplier_type {
-                MultiplierType::NFTCount { min_count } => {
-                    if min_count <= staker.total_staked {
-                        count_multiplier = multiplier.value;
-                    } else {
-                        break;
+        if let Some(staked_count) = staked_count {
+            for multiplier in multipliers.count_multipliers.iter() {
+                match multiplier.multiplier_type {
+                    MultiplierType::NFTCount { min_count } => {
+                        if min_count <= staked_count {
+                            count_multiplier = multiplier.value;
+                        } else {
+                            break;
+                        }
                     }
+                    _ => {}
                 }
-                _ => {}
             }
         }
-        count_multiplier -= multplier_decimals;
-        total_multipliers += count_multiplier;
+        total_multipliers = multipliers.stack(total_multipliers, count_multiplier);
 
         let mut creator_multiplier = multplier_decimals;
         for multiplier in multipliers.creator_multipliers.iter() {
             match multiplier.multiplier_type {
-                MultiplierType::Creator { creator } => match nft.criteria {
+                MultiplierType::Creator { creator } => match criteria {
                     NFTCriteria::Creator { address } => {
                         if address.eq(&creator) {
                             creator_multiplier = multiplier.value;
@@ -80,13 +174,12 @@ fn calculate_rewards(
                 _ => {}
             }
         }
-        creator_multiplier -= multplier_decimals;
-        total_multipliers += creator_multiplier;
+        total_multipliers = multipliers.stack(total_multipliers, creator_multiplier);
 
         let mut collection_multiplier = multplier_decimals;
         for multiplier in multipliers.collection_multipliers.iter() {
             match multiplier.multiplier_type {
-                MultiplierType::Collection { collection } => match nft.criteria {
+                MultiplierType::Collection { collection } => match criteria {
                     NFTCriteria::Collection { address } => {
                         if collection.eq(&address) {
                             collection_multiplier = multiplier.value;
@@ -98,12 +191,134 @@ fn calculate_rewards(
                 _ => {}
             }
         }
-        collection_multiplier -= multplier_decimals;
-        total_multipliers += collection_multiplier;
+        total_multipliers = multipliers.stack(total_multipliers, collection_multiplier);
+
+        let mut merkle_tree_multiplier = multplier_decimals;
+        for multiplier in multipliers.merkle_tree_multipliers.iter() {
+            match multiplier.multiplier_type {
+                MultiplierType::MerkleTree { merkle_tree } => match criteria {
+                    NFTCriteria::MerkleTree { address } => {
+                        if merkle_tree.eq(&address) {
+                            merkle_tree_multiplier = multiplier.value;
+                            break;
+                        }
+                    }
+                    _ => {}
+                },
+                _ => {}
+            }
+        }
+        total_multipliers = multipliers.stack(total_multipliers, merkle_tree_multiplier);
+
+        let mut trait_multiplier = multplier_decimals;
+        for multiplier in multipliers.trait_multipliers.iter() {
+            match multiplier.multiplier_type {
+                MultiplierType::Trait { trait_hash } => {
+                    if traits.contains(&trait_hash) {
+                        trait_multiplier = multiplier.value;
+                        break;
+                    }
+                }
+                _ => {}
+            }
+        }
+        total_multipliers = multipliers.stack(total_multipliers, trait_multiplier);
+
+        let mut mint_multiplier = multplier_decimals;
+        if let Some(mint) = mint {
+            for multiplier in multipliers.mint_multipliers.iter() {
+                match multiplier.multiplier_type {
+                    MultiplierType::Mint { mint: address } => {
+                        if address.eq(&mint) {
+                            mint_multiplier = multiplier.value;
+                            break;
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        total_multipliers = multipliers.stack(total_multipliers, mint_multiplier);
+
+        if let Some(max_multiplier) = multipliers.max_multiplier {
+            total_multipliers = total_multipliers.min(max_multiplier);
+        }
     }
-    rewards_amount = (rewards_amount * total_multipliers) / multplier_decimals;
 
-    (rewards_amount, total_multipliers)
+    (total_multipliers, multplier_decimals)
+}
+
+/// The weight an NFT is staked with in EmissionMode::Shared, fixed at stake time
+/// It is the multiplier of the NFT scaled by the compressed_rewards_ratio for cNFTs
+pub(crate) fn nft_weight(
+    staking_pool: &StakingPool,
+    multipliers: Option<Account<'_, Multipliers>>,
+    nft: &NFTv1,
+    staker: &Staker,
+) -> u64 {
+    let (multiplier, _) = total_multiplier(
+        multipliers,
+        None,
+        nft.criteria,
+        Some(staker.total_staked),
+        nft.traits.as_slice(),
+        Some(nft.mint),
+    );
+
+    let ratio = if nft.is_compressed {
+        staking_pool.compressed_rewards_ratio
+    } else {
+        10_000
+    };
+    multiplier * u64::from(ratio)
+}
+
+/// Mints the rewards an NFT accrued since its last claim, used to settle them on unstake
+pub(crate) fn settle_rewards<'info>(
+    staking_pool: &mut Account<'info, StakingPool>,
+    multipliers: Option<Account<'info, Multipliers>>,
+    nft: &mut NFTv1,
+    staker: &Staker,
+    mint_accounts: MintCurrency<'info>,
+    currency_manager_program: AccountInfo<'info>,
+    now: i64,
+) -> Result<u64> {
+    let seconds_elapsed = match claimable_seconds(staking_pool, nft.last_claim, now) {
+        Some(seconds_elapsed) => seconds_elapsed,
+        None => return Ok(0),
+    };
+
+    let (rewards_amount, _) = calculate_rewards(
+        staking_pool,
+        multipliers,
+        Staked::Nft { nft, staker, now },
+        seconds_elapsed,
+    );
+    let rewards_amount = staking_pool.cap_rewards(rewards_amount);
+
+    nft.last_claim = now;
+    nft.reward_index = staking_pool.reward_index_at(now);
+
+    if rewards_amount == 0 {
+        return Ok(0);
+    }
+
+    let pool_seeds = &[
+        b"staking_pool".as_ref(),
+        staking_pool.project.as_ref(),
+        staking_pool.key.as_ref(),
+        &[staking_pool.bump],
+    ];
+    let pool_signer = &[&pool_seeds[..]];
+
+    mint_currency(
+        CpiContext::new_with_signer(currency_manager_program, mint_accounts, pool_signer),
+        rewards_amount,
+    )?;
+
+    staking_pool.total_rewards_minted += rewards_amount;
+
+    Ok(rewards_amount)
 }
 
 /// Accounts used in claim rewards instruction
@@ -114,7 +329,7 @@ pub struct ClaimRewards<'info> {
     pub project: Box<Account<'info, Project>>,
 
     /// StakingPool state account
-    #[account(has_one = project, has_one = currency)]
+    #[account(mut, has_one = project, has_one = currency)]
     pub staking_pool: Box<Account<'info, StakingPool>>,
 
     /// StakingPool delegate account for this project
@@ -183,19 +398,17 @@ pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
     let staking_pool = &ctx.accounts.staking_pool;
     let nft = &mut ctx.accounts.nft;
 
-    let mut seconds_elapsed: u64 =
-        u64::try_from(ctx.accounts.clock.unix_timestamp - nft.last_claim).unwrap();
-
-    if seconds_elapsed < staking_pool.rewards_duration {
-        msg!("Minimum Reward duration not reached yet so rewards not available yet");
-        return Ok(());
-    }
+    staking_pool.assert_not_paused()?;
+    staking_pool.assert_migrated()?;
 
-    if let Some(max_rewards_duration) = staking_pool.max_rewards_duration {
-        if max_rewards_duration < seconds_elapsed {
-            seconds_elapsed = max_rewards_duration;
-        }
-    }
+    let seconds_elapsed = match claimable_seconds(
+        staking_pool,
+        nft.last_claim,
+        ctx.accounts.clock.unix_timestamp,
+    ) {
+        Some(seconds_elapsed) => seconds_elapsed,
+        None => return Ok(()),
+    };
 
     let (rewards_amount, _) = calculate_rewards(
         &ctx.accounts.staking_pool,
@@ -204,12 +417,21 @@ pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
         } else {
             None
         },
-        &nft,
-        &ctx.accounts.staker,
+        Staked::Nft {
+            nft,
+            staker: &ctx.accounts.staker,
+            now: ctx.accounts.clock.unix_timestamp,
+        },
         seconds_elapsed,
     );
+    let rewards_amount = staking_pool.cap_rewards(rewards_amount);
 
     nft.last_claim = ctx.accounts.clock.unix_timestamp;
+    nft.reward_index = staking_pool.reward_index_at(nft.last_claim);
+
+    if rewards_amount == 0 {
+        return Ok(());
+    }
 
     let pool_seeds = &[
         b"staking_pool".as_ref(),
@@ -242,6 +464,8 @@ pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
         rewards_amount,
     )?;
 
+    ctx.accounts.staking_pool.total_rewards_minted += rewards_amount;
+
     Event::claim_rewards(
         nft.key(),
         nft.try_to_vec().unwrap(),
@@ -254,6 +478,187 @@ pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
     Ok(())
 }
 
+/// The maximum number of NFTs claim_all_rewards accepts, bounded by the compute budget
+pub const MAX_CLAIM_ALL_NFTS: usize = 20;
+
+/// Accounts used in claim all rewards instruction
+/// The NFT state accounts of the staker are passed as writable remaining accounts
+#[derive(Accounts)]
+pub struct ClaimAllRewards<'info> {
+    // HIVE CONTROL
+    #[account(mut)]
+    pub project: Box<Account<'info, Project>>,
+
+    /// StakingPool state account
+    #[account(mut, has_one = project, has_one = currency)]
+    pub staking_pool: Box<Account<'info, StakingPool>>,
+
+    /// StakingPool delegate account for this project
+    /// It is required to mint rewards
+    #[account(has_one = project, constraint = staking_pool_delegate.authority.eq(&staking_pool.key()))]
+    pub staking_pool_delegate: Box<Account<'info, DelegateAuthority>>,
+
+    /// Multpliers state account
+    #[account(has_one = staking_pool)]
+    pub multipliers: Option<Account<'info, Multipliers>>,
+
+    #[account(has_one = mint)]
+    pub currency: Box<Account<'info, Currency>>,
+
+    #[account(mut)]
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(has_one = currency, has_one = token_account, constraint = holder_account.owner == wallet.key())]
+    pub holder_account: Account<'info, HolderAccount>,
+
+    #[account(mut)]
+    pub token_account: Account<'info, TokenAccount>,
+
+    /// Staker state account
+    #[account(has_one = staking_pool, has_one = wallet)]
+    pub staker: Account<'info, Staker>,
+
+    /// The wallet that pays for the rent
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub vault: AccountInfo<'info>,
+
+    /// NATIVE SYSTEM PROGRAM
+    pub system_program: Program<'info, System>,
+
+    /// HPL Hive Control Program
+    pub hive_control: Program<'info, HplHiveControl>,
+
+    /// NATIVE TOKEN PROGRAM
+    pub token_program: Program<'info, Token>,
+
+    /// HPL Currency Manager Program
+    pub currency_manager_program: Program<'info, HplCurrencyManager>,
+
+    /// HPL Events Program
+    pub hpl_events: Program<'info, HplEvents>,
+
+    /// SYSVAR CLOCK
+    pub clock: Sysvar<'info, Clock>,
+
+    /// NATIVE INSTRUCTIONS SYSVAR
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+/// Claim rewards of all the NFTs passed as remaining accounts with a single mint
+pub fn claim_all_rewards<'info>(
+    ctx: Context<'_, '_, '_, 'info, ClaimAllRewards<'info>>,
+) -> Result<()> {
+    let staking_pool = &ctx.accounts.staking_pool;
+    let staker = &ctx.accounts.staker;
+    let now = ctx.accounts.clock.unix_timestamp;
+
+    staking_pool.assert_not_paused()?;
+    staking_pool.assert_migrated()?;
+
+    if ctx.remaining_accounts.len() > MAX_CLAIM_ALL_NFTS {
+        msg!(
+            "At most {} NFTs can be claimed in a single instruction",
+            MAX_CLAIM_ALL_NFTS
+        );
+        return Err(ErrorCode::TooManyNfts.into());
+    }
+
+    let mut nft_addresses: Vec<Pubkey> = Vec::with_capacity(ctx.remaining_accounts.len());
+    let mut rewards_amount = 0u64;
+    for nft_info in ctx.remaining_accounts.iter() {
+        if nft_addresses.contains(nft_info.key) {
+            return Err(ErrorCode::DuplicateNFT.into());
+        }
+
+        let mut nft = Account::<NFTv1>::try_from(nft_info)?;
+        if !nft_info.is_writable
+            || nft.staking_pool != staking_pool.key()
+            || nft.staker != Some(staker.key())
+        {
+            msg!("NFT {} is not staked by this staker", nft_info.key);
+            return Err(ErrorCode::InvalidNFT.into());
+        }
+
+        let seconds_elapsed = match claimable_seconds(staking_pool, nft.last_claim, now) {
+            Some(seconds_elapsed) => seconds_elapsed,
+            None => continue,
+        };
+
+        let (nft_rewards, _) = calculate_rewards(
+            staking_pool,
+            ctx.accounts.multipliers.clone(),
+            Staked::Nft {
+                nft: &nft,
+                staker,
+                now,
+            },
+            seconds_elapsed,
+        );
+
+        rewards_amount = rewards_amount
+            .checked_add(nft_rewards)
+            .ok_or(ErrorCode::Overflow)?;
+        nft.last_claim = now;
+        nft.reward_index = staking_pool.reward_index_at(now);
+        nft.exit(&crate::ID)?;
+        nft_addresses.push(nft.key());
+    }
+
+    let rewards_amount = staking_pool.cap_rewards(rewards_amount);
+    if rewards_amount == 0 {
+        return Ok(());
+    }
+
+    let pool_seeds = &[
+        b"staking_pool".as_ref(),
+        staking_pool.project.as_ref(),
+        staking_pool.key.as_ref(),
+        &[staking_pool.bump],
+    ];
+    let pool_signer = &[&pool_seeds[..]];
+
+    mint_currency(
+        CpiContext::new_with_signer(
+            ctx.accounts.currency_manager_program.to_account_info(),
+            MintCurrency {
+                project: ctx.accounts.project.to_account_info(),
+                currency: ctx.accounts.currency.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                holder_account: ctx.accounts.holder_account.to_account_info(),
+                token_account: ctx.accounts.token_account.to_account_info(),
+                delegate_authority: Some(ctx.accounts.staking_pool_delegate.to_account_info()),
+                authority: ctx.accounts.staking_pool.to_account_info(),
+                payer: ctx.accounts.wallet.to_account_info(),
+                vault: ctx.accounts.vault.to_account_info(),
+                instructions_sysvar: ctx.accounts.instructions_sysvar.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                hive_control: ctx.accounts.hive_control.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            },
+            pool_signer,
+        ),
+        rewards_amount,
+    )?;
+
+    ctx.accounts.staking_pool.total_rewards_minted += rewards_amount;
+
+    Event::claim_all_rewards(
+        ctx.accounts.staker.key(),
+        nft_addresses,
+        rewards_amount,
+        &ctx.accounts.clock,
+    )
+    .emit(ctx.accounts.hpl_events.to_account_info())?;
+
+    Ok(())
+}
+
 /// Accounts used in distribute rewards instruction
 #[derive(Accounts)]
 pub struct DistriuteRewards<'info> {
@@ -341,19 +746,17 @@ pub fn distribute_rewards(ctx: Context<DistriuteRewards>) -> Result<()> {
     let staking_pool = &ctx.accounts.staking_pool;
     let nft = &mut ctx.accounts.nft;
 
-    let mut seconds_elapsed: u64 =
-        u64::try_from(ctx.accounts.clock.unix_timestamp - nft.last_claim).unwrap();
-
-    if seconds_elapsed < staking_pool.rewards_duration {
-        msg!("Minimum Reward duration not reached yet so rewards not available yet");
-        return Ok(());
-    }
+    staking_pool.assert_not_paused()?;
+    staking_pool.assert_migrated()?;
 
-    if let Some(max_rewards_duration) = staking_pool.max_rewards_duration {
-        if max_rewards_duration < seconds_elapsed {
-            seconds_elapsed = max_rewards_duration;
-        }
-    }
+    let seconds_elapsed = match claimable_seconds(
+        staking_pool,
+        nft.last_claim,
+        ctx.accounts.clock.unix_timestamp,
+    ) {
+        Some(seconds_elapsed) => seconds_elapsed,
+        None => return Ok(()),
+    };
 
     let (rewards_amount, _) = calculate_rewards(
         &ctx.accounts.staking_pool,
@@ -362,12 +765,21 @@ pub fn distribute_rewards(ctx: Context<DistriuteRewards>) -> Result<()> {
         } else {
             None
         },
-        nft,
-        &ctx.accounts.staker,
+        Staked::Nft {
+            nft,
+            staker: &ctx.accounts.staker,
+            now: ctx.accounts.clock.unix_timestamp,
+        },
         seconds_elapsed,
     );
+    let rewards_amount = staking_pool.cap_rewards(rewards_amount);
 
     nft.last_claim = ctx.accounts.clock.unix_timestamp;
+    nft.reward_index = staking_pool.reward_index_at(nft.last_claim);
+
+    if rewards_amount == 0 {
+        return Ok(());
+    }
 
     let pool_seeds = &[
         b"staking_pool".as_ref(),
@@ -400,6 +812,8 @@ pub fn distribute_rewards(ctx: Context<DistriuteRewards>) -> Result<()> {
         rewards_amount,
     )?;
 
+    ctx.accounts.staking_pool.total_rewards_minted += rewards_amount;
+
     // Event::claim_rewards(
     //     nft.key(),
     //     &nft,
@@ -411,3 +825,164 @@ pub fn distribute_rewards(ctx: Context<DistriuteRewards>) -> Result<()> {
 
     Ok(())
 }
+
+/// Accounts used in claim token rewards instruction
+#[derive(Accounts)]
+pub struct ClaimTokenRewards<'info> {
+    // HIVE CONTROL
+    #[account(mut)]
+    pub project: Box<Account<'info, Project>>,
+
+    /// StakingPool state account
+    #[account(mut, has_one = project, has_one = currency, constraint = staking_pool.kind == PoolKind::Token { mint: token_mint.key() })]
+    pub staking_pool: Box<Account<'info, StakingPool>>,
+
+    /// StakingPool delegate account for this project
+    /// It is required to mint rewards
+    #[account(has_one = project, constraint = staking_pool_delegate.authority.eq(&staking_pool.key()))]
+    pub staking_pool_delegate: Box<Account<'info, DelegateAuthority>>,
+
+    /// Multpliers state account
+    #[account(has_one = staking_pool)]
+    pub multipliers: Option<Account<'info, Multipliers>>,
+
+    /// TokenPosition state account
+    #[account(mut, has_one = staking_pool, has_one = wallet)]
+    pub position: Box<Account<'info, TokenPosition>>,
+
+    /// Mint of the staked token
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    #[account(has_one = mint)]
+    pub currency: Box<Account<'info, Currency>>,
+
+    #[account(mut)]
+    pub mint: Box<Account<'info, Mint>>,
+
+    #[account(has_one = currency, has_one = token_account, constraint = holder_account.owner == wallet.key())]
+    pub holder_account: Account<'info, HolderAccount>,
+
+    #[account(mut)]
+    pub token_account: Account<'info, TokenAccount>,
+
+    /// The wallet that pays for the rent
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub vault: AccountInfo<'info>,
+
+    /// NATIVE SYSTEM PROGRAM
+    pub system_program: Program<'info, System>,
+
+    /// HPL Hive Control Program
+    pub hive_control: Program<'info, HplHiveControl>,
+
+    /// NATIVE TOKEN PROGRAM
+    pub token_program: Program<'info, Token>,
+
+    /// HPL Currency Manager Program
+    pub currency_manager_program: Program<'info, HplCurrencyManager>,
+
+    /// HPL Events Program
+    pub hpl_events: Program<'info, HplEvents>,
+
+    /// SYSVAR CLOCK
+    pub clock: Sysvar<'info, Clock>,
+
+    /// NATIVE INSTRUCTIONS SYSVAR
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+/// Claim rewards of staked tokens
+pub fn claim_token_rewards(ctx: Context<ClaimTokenRewards>) -> Result<()> {
+    let staking_pool = &ctx.accounts.staking_pool;
+    let position = &mut ctx.accounts.position;
+    let now = ctx.accounts.clock.unix_timestamp;
+
+    staking_pool.assert_not_paused()?;
+    staking_pool.assert_migrated()?;
+
+    position.accrue(staking_pool, ctx.accounts.token_mint.decimals, now);
+
+    let mut seconds_elapsed: u64 = staking_pool.seconds_in_window(position.last_claim, now);
+
+    if seconds_elapsed == 0 || position.accrued == 0 {
+        msg!("No rewards accrued since last claim");
+        return Ok(());
+    }
+
+    if seconds_elapsed < staking_pool.rewards_duration && !staking_pool.has_ended(now) {
+        msg!("Minimum Reward duration not reached yet so rewards not available yet");
+        return Ok(());
+    }
+
+    let mut accrued = position.accrued;
+    if let Some(max_rewards_duration) = staking_pool.max_rewards_duration {
+        if max_rewards_duration < seconds_elapsed {
+            accrued = accrued * max_rewards_duration as u128 / seconds_elapsed as u128;
+            seconds_elapsed = max_rewards_duration;
+        }
+    }
+
+    let (rewards_amount, _) = calculate_rewards(
+        &ctx.accounts.staking_pool,
+        ctx.accounts.multipliers.clone(),
+        Staked::Tokens { accrued },
+        seconds_elapsed,
+    );
+    let rewards_amount = staking_pool.cap_rewards(rewards_amount);
+
+    position.accrued = 0;
+    position.last_claim = now;
+
+    if rewards_amount == 0 {
+        return Ok(());
+    }
+
+    let pool_seeds = &[
+        b"staking_pool".as_ref(),
+        staking_pool.project.as_ref(),
+        staking_pool.key.as_ref(),
+        &[ctx.accounts.staking_pool.bump],
+    ];
+    let pool_signer = &[&pool_seeds[..]];
+
+    mint_currency(
+        CpiContext::new_with_signer(
+            ctx.accounts.currency_manager_program.to_account_info(),
+            MintCurrency {
+                project: ctx.accounts.project.to_account_info(),
+                currency: ctx.accounts.currency.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                holder_account: ctx.accounts.holder_account.to_account_info(),
+                token_account: ctx.accounts.token_account.to_account_info(),
+                delegate_authority: Some(ctx.accounts.staking_pool_delegate.to_account_info()),
+                authority: ctx.accounts.staking_pool.to_account_info(),
+                payer: ctx.accounts.wallet.to_account_info(),
+                vault: ctx.accounts.vault.to_account_info(),
+                instructions_sysvar: ctx.accounts.instructions_sysvar.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                hive_control: ctx.accounts.hive_control.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            },
+            pool_signer,
+        ),
+        rewards_amount,
+    )?;
+
+    ctx.accounts.staking_pool.total_rewards_minted += rewards_amount;
+
+    Event::claim_token_rewards(
+        position.key(),
+        position.try_to_vec().unwrap(),
+        rewards_amount,
+        &ctx.accounts.clock,
+    )
+    .emit(ctx.accounts.hpl_events.to_account_info())?;
+
+    Ok(())
+}
@@ -5,8 +5,16 @@ use {
         associated_token::AssociatedToken,
         token::{self, CloseAccount, Mint, Token, TokenAccount},
     },
+    hpl_currency_manager::{
+        cpi::accounts::MintCurrency,
+        program::HplCurrencyManager,
+        state::{Currency, HolderAccount},
+    },
     hpl_events::HplEvents,
-    hpl_hive_control::{program::HplHiveControl, state::Project},
+    hpl_hive_control::{
+        program::HplHiveControl,
+        state::{DelegateAuthority, Project},
+    },
     hpl_utils::Default,
     mpl_token_metadata::{
         instruction::{DelegateArgs, RevokeArgs},
@@ -55,10 +63,18 @@ pub struct Stake<'info> {
     #[account(mut)]
     pub nft_token_record: Option<AccountInfo<'info>>,
 
+    /// Allowlist entry of the NFT mint, required when the staking_pool only accepts allowed mints
+    #[account(has_one = staking_pool, constraint = allowed_mint.mint == nft_mint.key())]
+    pub allowed_mint: Option<Account<'info, AllowedMint>>,
+
     /// Staker state account
     #[account(mut, has_one = staking_pool, has_one = wallet)]
     pub staker: Box<Account<'info, Staker>>,
 
+    /// Multpliers state account, used to weigh the NFT in EmissionMode::Shared
+    #[account(has_one = staking_pool)]
+    pub multipliers: Option<Account<'info, Multipliers>>,
+
     /// The account that will hold the nft sent on expedition
     #[account(
         init,
@@ -130,6 +146,16 @@ pub fn stake(ctx: Context<Stake>) -> Result<()> {
     let staking_pool = &mut ctx.accounts.staking_pool;
     let nft = &mut ctx.accounts.nft;
 
+    if staking_pool.is_token_pool() {
+        return Err(ErrorCode::InvalidPoolKind.into());
+    }
+    staking_pool.assert_active(ctx.accounts.clock.unix_timestamp)?;
+
+    if staking_pool.allowed_mints && ctx.accounts.allowed_mint.is_none() {
+        msg!("NFT mint is not in the allowlist");
+        return Err(ErrorCode::MintNotAllowed.into());
+    }
+
     if !nft.staking_pool.eq(&staking_pool.key()) {
         nft.set_defaults();
         nft.bump = ctx.bumps["nft"];
@@ -201,18 +227,19 @@ pub fn stake(ctx: Context<Stake>) -> Result<()> {
                 }
             },
             Err(_) => {
-                // if staking_pool.allowed_mints {
-                //     if staking_pool.authority == ctx.accounts.wallet.key() {
-                //         return Ok(());
-                //     } else if staking_pool.authority != ctx.accounts.wallet.key() {
-                //         return Err(ErrorCode::OnlyOwner.into());
-                //     }
-                // }
-                return Err(ErrorCode::InvalidMetadata.into());
+                if !staking_pool.allowed_mints {
+                    return Err(ErrorCode::InvalidMetadata.into());
+                }
+                msg!("Allowed mint: {:?}", nft.mint);
             }
         };
     }
 
+    if !staking_pool.accepts_criteria(&ctx.accounts.project, &nft.criteria) {
+        msg!("NFT criteria is not accepted by the staking pool");
+        return Err(ErrorCode::InvalidNFT.into());
+    }
+
     let staker = &mut ctx.accounts.staker;
 
     staking_pool.total_staked += 1;
@@ -336,10 +363,22 @@ pub fn stake(ctx: Context<Stake>) -> Result<()> {
 
     if nft.last_claim == 0 || staking_pool.reset_stake_duration {
         nft.last_claim = ctx.accounts.clock.unix_timestamp;
+        nft.reward_index = staking_pool.reward_index_at(nft.last_claim);
     }
 
+    if staker.total_staked == 0 {
+        staking_pool.total_stakers += 1;
+    }
     staker.total_staked += 1;
 
+    if staking_pool.emission_mode == EmissionMode::Shared {
+        // Pending rewards of a previous stake are only kept when settled on unstake
+        nft.last_claim = ctx.accounts.clock.unix_timestamp;
+        nft.weight = super::nft_weight(staking_pool, ctx.accounts.multipliers.clone(), nft, staker);
+        staking_pool.add_weight(nft.weight, nft.last_claim);
+        nft.reward_index = staking_pool.reward_index;
+    }
+
     Event::stake(
         nft.key(),
         nft.try_to_vec().unwrap(),
@@ -404,6 +443,31 @@ pub struct Unstake<'info> {
     #[account(mut, has_one = staking_pool, has_one = wallet)]
     pub staker: Account<'info, Staker>,
 
+    /// StakingPool delegate account for this project
+    /// It is required to settle rewards
+    #[account(has_one = project, constraint = staking_pool_delegate.authority.eq(&staking_pool.key()))]
+    pub staking_pool_delegate: Option<Box<Account<'info, DelegateAuthority>>>,
+
+    /// Multpliers state account
+    #[account(has_one = staking_pool)]
+    pub multipliers: Option<Account<'info, Multipliers>>,
+
+    /// Currency of the staking_pool, required to settle rewards
+    #[account(has_one = mint, constraint = currency.key() == staking_pool.currency)]
+    pub currency: Option<Box<Account<'info, Currency>>>,
+
+    /// Mint of the currency, required to settle rewards
+    #[account(mut)]
+    pub mint: Option<Box<Account<'info, Mint>>>,
+
+    /// Holder account of the wallet, required to settle rewards
+    #[account(has_one = currency, has_one = token_account, constraint = holder_account.owner == wallet.key())]
+    pub holder_account: Option<Account<'info, HolderAccount>>,
+
+    /// Token account of the holder account, required to settle rewards
+    #[account(mut)]
+    pub token_account: Option<Account<'info, TokenAccount>>,
+
     /// The wallet that pays for the rent
     #[account(mut)]
     pub wallet: Signer<'info>,
@@ -421,6 +485,9 @@ pub struct Unstake<'info> {
     /// ASSOCIATED TOKEN PROGRAM
     pub associated_token_program: Program<'info, AssociatedToken>,
 
+    /// HPL Currency Manager Program, required to settle rewards
+    pub currency_manager_program: Option<Program<'info, HplCurrencyManager>>,
+
     /// METAPLEX TOKEN METADATA PROGRAM
     /// CHECK: This is not dangerous because we don't read or write from this account
     #[account(address = mpl_token_metadata::ID)]
@@ -438,7 +505,7 @@ pub struct Unstake<'info> {
     pub instructions_sysvar: AccountInfo<'info>,
 
     // HIVE CONTROL
-    #[account()]
+    #[account(mut)]
     pub project: Box<Account<'info, Project>>,
     /// CHECK: This is not dangerous because we don't read or write from this account
     #[account(mut)]
@@ -473,9 +540,76 @@ pub fn unstake(ctx: Context<Unstake>) -> Result<()> {
         }
     }
 
+    if staking_pool.settle_on_unstake {
+        // Unstaking closes the NFT, so it has to wait until the pending rewards can be paid out
+        staking_pool.assert_not_paused()?;
+        staking_pool.assert_migrated()?;
+
+        if let (
+            Some(staking_pool_delegate),
+            Some(currency),
+            Some(mint),
+            Some(holder_account),
+            Some(token_account),
+            Some(currency_manager_program),
+        ) = (
+            &ctx.accounts.staking_pool_delegate,
+            &ctx.accounts.currency,
+            &ctx.accounts.mint,
+            &ctx.accounts.holder_account,
+            &ctx.accounts.token_account,
+            &ctx.accounts.currency_manager_program,
+        ) {
+            let mint_accounts = MintCurrency {
+                project: ctx.accounts.project.to_account_info(),
+                currency: currency.to_account_info(),
+                mint: mint.to_account_info(),
+                holder_account: holder_account.to_account_info(),
+                token_account: token_account.to_account_info(),
+                delegate_authority: Some(staking_pool_delegate.to_account_info()),
+                authority: staking_pool.to_account_info(),
+                payer: ctx.accounts.wallet.to_account_info(),
+                vault: ctx.accounts.vault.to_account_info(),
+                instructions_sysvar: ctx.accounts.instructions_sysvar.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+                hive_control: ctx.accounts.hive_control.to_account_info(),
+                token_program: ctx.accounts.token_program.to_account_info(),
+            };
+
+            let rewards_amount = super::settle_rewards(
+                staking_pool,
+                ctx.accounts.multipliers.clone(),
+                nft,
+                staker,
+                mint_accounts,
+                currency_manager_program.to_account_info(),
+                ctx.accounts.clock.unix_timestamp,
+            )?;
+
+            if rewards_amount > 0 {
+                Event::claim_rewards(
+                    nft.key(),
+                    nft.try_to_vec().unwrap(),
+                    staker.key(),
+                    rewards_amount,
+                    &ctx.accounts.clock,
+                )
+                .emit(ctx.accounts.hpl_events.to_account_info())?;
+            }
+        } else {
+            return Err(ErrorCode::RewardsAccountsNotProvided.into());
+        }
+    }
+
     nft.last_unstaked_at = ctx.accounts.clock.unix_timestamp;
     nft.staker = None;
     staker.total_staked -= 1;
+    staking_pool.total_staked -= 1;
+    staking_pool.remove_weight(nft.weight, ctx.accounts.clock.unix_timestamp);
+    nft.weight = 0;
+    if staker.total_staked == 0 {
+        staking_pool.total_stakers = staking_pool.total_stakers.saturating_sub(1);
+    }
 
     let wallet_key = ctx.accounts.wallet.key();
     let pool_key = staking_pool.key();
@@ -0,0 +1,268 @@
+use {
+    crate::{errors::ErrorCode, state::*},
+    anchor_lang::prelude::*,
+    anchor_spl::token::{self, Mint, Token, TokenAccount, Transfer},
+    hpl_events::HplEvents,
+    hpl_hive_control::{program::HplHiveControl, state::Project},
+    hpl_utils::Default,
+};
+
+/// Accounts used in stake tokens instruction
+#[derive(Accounts)]
+pub struct StakeTokens<'info> {
+    /// StakingPool state account
+    #[account(mut, has_one = project, constraint = staking_pool.kind == PoolKind::Token { mint: token_mint.key() })]
+    pub staking_pool: Box<Account<'info, StakingPool>>,
+
+    /// TokenPosition state account
+    #[account(init_if_needed, payer = wallet, space = TokenPosition::LEN,
+      seeds = [
+        b"token_position",
+        staking_pool.key().as_ref(),
+        wallet.key().as_ref(),
+      ],
+      bump
+    )]
+    pub position: Box<Account<'info, TokenPosition>>,
+
+    /// Mint of the staked token
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    /// Token account of the wallet
+    #[account(mut, constraint = token_account.mint == token_mint.key() && token_account.owner == wallet.key())]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// The account that holds the tokens staked in the staking_pool
+    #[account(
+        init_if_needed,
+        payer = wallet,
+        seeds = [
+            b"token_deposit",
+            staking_pool.key().as_ref(),
+        ],
+        bump,
+        token::mint = token_mint,
+        token::authority = staking_pool,
+    )]
+    pub deposit_account: Box<Account<'info, TokenAccount>>,
+
+    /// The wallet that pays for the rent
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// NATIVE SYSTEM PROGRAM
+    pub system_program: Program<'info, System>,
+
+    /// HPL Hive Control Program
+    pub hive_control: Program<'info, HplHiveControl>,
+
+    /// NATIVE TOKEN PROGRAM
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    /// HPL Events Program
+    pub hpl_events: Program<'info, HplEvents>,
+
+    /// NATIVE CLOCK SYSVAR
+    pub clock: Sysvar<'info, Clock>,
+
+    /// NATIVE INSTRUCTIONS SYSVAR
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    // HIVE CONTROL
+    #[account()]
+    pub project: Box<Account<'info, Project>>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub vault: AccountInfo<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct StakeTokensArgs {
+    pub amount: u64,
+}
+
+/// Stake tokens
+pub fn stake_tokens(ctx: Context<StakeTokens>, args: StakeTokensArgs) -> Result<()> {
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    let position = &mut ctx.accounts.position;
+    let now = ctx.accounts.clock.unix_timestamp;
+
+    staking_pool.assert_active(now)?;
+
+    if args.amount == 0 {
+        return Err(ErrorCode::InvalidAmount.into());
+    }
+
+    if !position.staking_pool.eq(&staking_pool.key()) {
+        position.set_defaults();
+        position.bump = ctx.bumps["position"];
+        position.staking_pool = staking_pool.key();
+        position.wallet = ctx.accounts.wallet.key();
+        position.staked_at = now;
+        position.last_claim = now;
+        position.reward_index = staking_pool.reward_index_at(now);
+    } else {
+        position.accrue(staking_pool, ctx.accounts.token_mint.decimals, now);
+        if staking_pool.reset_stake_duration || position.amount == 0 {
+            position.staked_at = now;
+        }
+    }
+
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.token_account.to_account_info(),
+                to: ctx.accounts.deposit_account.to_account_info(),
+                authority: ctx.accounts.wallet.to_account_info(),
+            },
+        ),
+        args.amount,
+    )?;
+
+    if position.amount == 0 {
+        staking_pool.total_stakers += 1;
+    }
+    position.amount += args.amount;
+    staking_pool.total_staked_amount += args.amount;
+
+    Event::stake_tokens(
+        position.key(),
+        position.try_to_vec().unwrap(),
+        args.amount,
+        &ctx.accounts.clock,
+    )
+    .emit(ctx.accounts.hpl_events.to_account_info())?;
+
+    Ok(())
+}
+
+/// Accounts used in unstake tokens instruction
+#[derive(Accounts)]
+pub struct UnstakeTokens<'info> {
+    /// StakingPool state account
+    #[account(mut, has_one = project, constraint = staking_pool.kind == PoolKind::Token { mint: token_mint.key() })]
+    pub staking_pool: Box<Account<'info, StakingPool>>,
+
+    /// TokenPosition state account
+    #[account(mut, has_one = staking_pool, has_one = wallet)]
+    pub position: Box<Account<'info, TokenPosition>>,
+
+    /// Mint of the staked token
+    pub token_mint: Box<Account<'info, Mint>>,
+
+    /// Token account of the wallet
+    #[account(mut, constraint = token_account.mint == token_mint.key() && token_account.owner == wallet.key())]
+    pub token_account: Box<Account<'info, TokenAccount>>,
+
+    /// The account that holds the tokens staked in the staking_pool
+    #[account(
+        mut,
+        seeds = [
+            b"token_deposit",
+            staking_pool.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub deposit_account: Box<Account<'info, TokenAccount>>,
+
+    /// The wallet that owns the position
+    #[account(mut)]
+    pub wallet: Signer<'info>,
+
+    /// NATIVE SYSTEM PROGRAM
+    pub system_program: Program<'info, System>,
+
+    /// HPL Hive Control Program
+    pub hive_control: Program<'info, HplHiveControl>,
+
+    /// NATIVE TOKEN PROGRAM
+    #[account(address = token::ID)]
+    pub token_program: Program<'info, Token>,
+
+    /// HPL Events Program
+    pub hpl_events: Program<'info, HplEvents>,
+
+    /// NATIVE CLOCK SYSVAR
+    pub clock: Sysvar<'info, Clock>,
+
+    /// NATIVE INSTRUCTIONS SYSVAR
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+
+    // HIVE CONTROL
+    #[account()]
+    pub project: Box<Account<'info, Project>>,
+    /// CHECK: This is not dangerous because we don't read or write from this account
+    #[account(mut)]
+    pub vault: AccountInfo<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UnstakeTokensArgs {
+    pub amount: u64,
+}
+
+/// Unstake tokens
+pub fn unstake_tokens(ctx: Context<UnstakeTokens>, args: UnstakeTokensArgs) -> Result<()> {
+    let staking_pool = &mut ctx.accounts.staking_pool;
+    let position = &mut ctx.accounts.position;
+    let now = ctx.accounts.clock.unix_timestamp;
+
+    if args.amount == 0 || args.amount > position.amount {
+        return Err(ErrorCode::InvalidAmount.into());
+    }
+
+    if let Some(min_stake_duration) = staking_pool.min_stake_duration {
+        let duration = position.staked_at + i64::try_from(min_stake_duration).unwrap();
+        if now < duration {
+            msg!(
+                "Min stake duration not reached, remaining {} seconds",
+                duration - now
+            );
+            return Err(ErrorCode::CantUnstakeYet.into());
+        }
+    }
+
+    position.accrue(staking_pool, ctx.accounts.token_mint.decimals, now);
+    position.amount -= args.amount;
+    staking_pool.total_staked_amount -= args.amount;
+    if position.amount == 0 {
+        staking_pool.total_stakers = staking_pool.total_stakers.saturating_sub(1);
+    }
+
+    let pool_seeds = &[
+        b"staking_pool".as_ref(),
+        staking_pool.project.as_ref(),
+        staking_pool.key.as_ref(),
+        &[staking_pool.bump],
+    ];
+    let pool_signer = &[&pool_seeds[..]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.deposit_account.to_account_info(),
+                to: ctx.accounts.token_account.to_account_info(),
+                authority: staking_pool.to_account_info(),
+            },
+            pool_signer,
+        ),
+        args.amount,
+    )?;
+
+    Event::unstake_tokens(
+        position.key(),
+        position.try_to_vec().unwrap(),
+        args.amount,
+        &ctx.accounts.clock,
+    )
+    .emit(ctx.accounts.hpl_events.to_account_info())?;
+
+    Ok(())
+}
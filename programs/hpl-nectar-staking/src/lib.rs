@@ -43,6 +43,43 @@ pub mod hpl_nectar_staking {
         instructions::update_staking_pool(ctx, args)
     }
 
+    pub fn close_staking_pool(ctx: Context<CloseStakingPool>) -> Result<()> {
+        platform_gate_cpi(
+            hpl_hive_control::state::SerializableActions::ManageStakingPool,
+            None,
+            ctx.accounts.project.to_account_info(),
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            &ctx.accounts.delegate_authority,
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.hive_control.to_account_info(),
+            ctx.accounts.instructions_sysvar.to_account_info(),
+        )?;
+
+        instructions::close_staking_pool(ctx)
+    }
+
+    pub fn migrate_staking_pool(
+        ctx: Context<MigrateStakingPool>,
+        args: MigrateStakingPoolArgs,
+    ) -> Result<()> {
+        platform_gate_cpi(
+            hpl_hive_control::state::SerializableActions::ManageStakingPool,
+            None,
+            ctx.accounts.project.to_account_info(),
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            &ctx.accounts.delegate_authority,
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.hive_control.to_account_info(),
+            ctx.accounts.instructions_sysvar.to_account_info(),
+        )?;
+
+        instructions::migrate_staking_pool(ctx, args)
+    }
+
     pub fn init_multipliers(
         ctx: Context<InitMultipliers>,
         args: InitMultipliersArgs,
@@ -63,6 +100,26 @@ pub mod hpl_nectar_staking {
         instructions::init_multipliers(ctx, args)
     }
 
+    pub fn update_multipliers(
+        ctx: Context<UpdateMultipliers>,
+        args: UpdateMultipliersArgs,
+    ) -> Result<()> {
+        platform_gate_cpi(
+            hpl_hive_control::state::SerializableActions::ManageStakingPool,
+            None,
+            ctx.accounts.project.to_account_info(),
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            &ctx.accounts.delegate_authority,
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.hive_control.to_account_info(),
+            ctx.accounts.instructions_sysvar.to_account_info(),
+        )?;
+
+        instructions::update_multipliers(ctx, args)
+    }
+
     pub fn add_multiplier(ctx: Context<AddMultiplier>, args: AddMultiplierArgs) -> Result<()> {
         platform_gate_cpi(
             hpl_hive_control::state::SerializableActions::ManageStakingPool,
@@ -80,6 +137,80 @@ pub mod hpl_nectar_staking {
         instructions::add_multiplier(ctx, args)
     }
 
+    pub fn update_multiplier(
+        ctx: Context<UpdateMultiplier>,
+        args: UpdateMultiplierArgs,
+    ) -> Result<()> {
+        platform_gate_cpi(
+            hpl_hive_control::state::SerializableActions::ManageStakingPool,
+            None,
+            ctx.accounts.project.to_account_info(),
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            &ctx.accounts.delegate_authority,
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.hive_control.to_account_info(),
+            ctx.accounts.instructions_sysvar.to_account_info(),
+        )?;
+
+        instructions::update_multiplier(ctx, args)
+    }
+
+    pub fn remove_multiplier(
+        ctx: Context<RemoveMultiplier>,
+        args: RemoveMultiplierArgs,
+    ) -> Result<()> {
+        platform_gate_cpi(
+            hpl_hive_control::state::SerializableActions::ManageStakingPool,
+            None,
+            ctx.accounts.project.to_account_info(),
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            &ctx.accounts.delegate_authority,
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.hive_control.to_account_info(),
+            ctx.accounts.instructions_sysvar.to_account_info(),
+        )?;
+
+        instructions::remove_multiplier(ctx, args)
+    }
+
+    pub fn add_allowed_mint(ctx: Context<AddAllowedMint>) -> Result<()> {
+        platform_gate_cpi(
+            hpl_hive_control::state::SerializableActions::ManageStakingPool,
+            None,
+            ctx.accounts.project.to_account_info(),
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            &ctx.accounts.delegate_authority,
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.hive_control.to_account_info(),
+            ctx.accounts.instructions_sysvar.to_account_info(),
+        )?;
+
+        instructions::add_allowed_mint(ctx)
+    }
+
+    pub fn remove_allowed_mint(ctx: Context<RemoveAllowedMint>) -> Result<()> {
+        platform_gate_cpi(
+            hpl_hive_control::state::SerializableActions::ManageStakingPool,
+            None,
+            ctx.accounts.project.to_account_info(),
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            &ctx.accounts.delegate_authority,
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.hive_control.to_account_info(),
+            ctx.accounts.instructions_sysvar.to_account_info(),
+        )?;
+
+        instructions::remove_allowed_mint(ctx)
+    }
+
     pub fn init_nft(ctx: Context<InitNFT>) -> Result<()> {
         msg!("Initializing NFT");
         platform_gate_cpi(
@@ -136,6 +267,23 @@ pub mod hpl_nectar_staking {
         instructions::use_nft(ctx, used_by)
     }
 
+    pub fn prove_nft_trait(ctx: Context<ProveNftTrait>, args: ProveNftTraitArgs) -> Result<()> {
+        platform_gate_cpi(
+            hpl_hive_control::state::SerializableActions::PublicLow,
+            None,
+            ctx.accounts.project.to_account_info(),
+            ctx.accounts.wallet.to_account_info(),
+            ctx.accounts.wallet.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            &None,
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.hive_control.to_account_info(),
+            ctx.accounts.instructions_sysvar.to_account_info(),
+        )?;
+
+        instructions::prove_nft_trait(ctx, args)
+    }
+
     pub fn close_nft(ctx: Context<CloseNft>) -> Result<()> {
         platform_gate_cpi(
             hpl_hive_control::state::SerializableActions::ManageStakingPool,
@@ -153,6 +301,23 @@ pub mod hpl_nectar_staking {
         instructions::close_nft(ctx)
     }
 
+    pub fn migrate_nft(ctx: Context<MigrateNft>) -> Result<()> {
+        platform_gate_cpi(
+            hpl_hive_control::state::SerializableActions::PublicLow,
+            None,
+            ctx.accounts.project.to_account_info(),
+            ctx.accounts.wallet.to_account_info(),
+            ctx.accounts.wallet.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            &None,
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.hive_control.to_account_info(),
+            ctx.accounts.instructions_sysvar.to_account_info(),
+        )?;
+
+        instructions::migrate_nft(ctx)
+    }
+
     pub fn init_staker(ctx: Context<InitStaker>) -> Result<()> {
         platform_gate_cpi(
             hpl_hive_control::state::SerializableActions::PublicLow,
@@ -244,6 +409,77 @@ pub mod hpl_nectar_staking {
         instructions::unstake_cnft(ctx, args)
     }
 
+    pub fn emergency_unstake(ctx: Context<EmergencyUnstake>) -> Result<()> {
+        platform_gate_cpi(
+            hpl_hive_control::state::SerializableActions::ManageStakingPool,
+            None,
+            ctx.accounts.project.to_account_info(),
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            &ctx.accounts.delegate_authority,
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.hive_control.to_account_info(),
+            ctx.accounts.instructions_sysvar.to_account_info(),
+        )?;
+
+        instructions::emergency_unstake(ctx)
+    }
+
+    pub fn emergency_unstake_cnft<'info>(
+        ctx: Context<'_, '_, '_, 'info, EmergencyUnstakeCNFT<'info>>,
+        args: CNFTArgs,
+    ) -> Result<()> {
+        platform_gate_cpi(
+            hpl_hive_control::state::SerializableActions::ManageStakingPool,
+            None,
+            ctx.accounts.project.to_account_info(),
+            ctx.accounts.authority.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            &ctx.accounts.delegate_authority,
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.hive_control.to_account_info(),
+            ctx.accounts.instructions_sysvar.to_account_info(),
+        )?;
+
+        instructions::emergency_unstake_cnft(ctx, args)
+    }
+
+    pub fn stake_tokens(ctx: Context<StakeTokens>, args: StakeTokensArgs) -> Result<()> {
+        platform_gate_cpi(
+            hpl_hive_control::state::SerializableActions::PublicLow,
+            None,
+            ctx.accounts.project.to_account_info(),
+            ctx.accounts.wallet.to_account_info(),
+            ctx.accounts.wallet.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            &None,
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.hive_control.to_account_info(),
+            ctx.accounts.instructions_sysvar.to_account_info(),
+        )?;
+
+        instructions::stake_tokens(ctx, args)
+    }
+
+    pub fn unstake_tokens(ctx: Context<UnstakeTokens>, args: UnstakeTokensArgs) -> Result<()> {
+        platform_gate_cpi(
+            hpl_hive_control::state::SerializableActions::PublicLow,
+            None,
+            ctx.accounts.project.to_account_info(),
+            ctx.accounts.wallet.to_account_info(),
+            ctx.accounts.wallet.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            &None,
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.hive_control.to_account_info(),
+            ctx.accounts.instructions_sysvar.to_account_info(),
+        )?;
+
+        instructions::unstake_tokens(ctx, args)
+    }
+
     pub fn claim_rewards(ctx: Context<ClaimRewards>) -> Result<()> {
         platform_gate_cpi(
             hpl_hive_control::state::SerializableActions::FeeExempt,
@@ -261,6 +497,25 @@ pub mod hpl_nectar_staking {
         instructions::claim_rewards(ctx)
     }
 
+    pub fn claim_all_rewards<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimAllRewards<'info>>,
+    ) -> Result<()> {
+        platform_gate_cpi(
+            hpl_hive_control::state::SerializableActions::FeeExempt,
+            None,
+            ctx.accounts.project.to_account_info(),
+            ctx.accounts.wallet.to_account_info(),
+            ctx.accounts.wallet.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            &None,
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.hive_control.to_account_info(),
+            ctx.accounts.instructions_sysvar.to_account_info(),
+        )?;
+
+        instructions::claim_all_rewards(ctx)
+    }
+
     pub fn distribute_rewards(ctx: Context<DistriuteRewards>) -> Result<()> {
         platform_gate_cpi(
             hpl_hive_control::state::SerializableActions::ManageStakingPool,
@@ -277,4 +532,21 @@ pub mod hpl_nectar_staking {
 
         instructions::distribute_rewards(ctx)
     }
+
+    pub fn claim_token_rewards(ctx: Context<ClaimTokenRewards>) -> Result<()> {
+        platform_gate_cpi(
+            hpl_hive_control::state::SerializableActions::FeeExempt,
+            None,
+            ctx.accounts.project.to_account_info(),
+            ctx.accounts.wallet.to_account_info(),
+            ctx.accounts.wallet.to_account_info(),
+            ctx.accounts.vault.to_account_info(),
+            &None,
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.hive_control.to_account_info(),
+            ctx.accounts.instructions_sysvar.to_account_info(),
+        )?;
+
+        instructions::claim_token_rewards(ctx)
+    }
 }
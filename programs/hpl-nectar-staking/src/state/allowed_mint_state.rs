@@ -0,0 +1,25 @@
+use {anchor_lang::prelude::*, hpl_utils::traits::*};
+
+/// Allowlist entry of a mint for a staking_pool
+/// PDA: ['allowed_mint', staking_pool, mint]
+/// Category: allowed_mint_state
+#[account]
+#[derive(PartialEq, Eq, Debug)]
+pub struct AllowedMint {
+    pub bump: u8,
+
+    /// The staking_pool this mint is allowed in
+    pub staking_pool: Pubkey,
+
+    /// The mint of the NFT
+    pub mint: Pubkey,
+}
+impl Default for AllowedMint {
+    const LEN: usize = 8 + 65;
+
+    fn set_defaults(&mut self) {
+        self.bump = 0;
+        self.staking_pool = Pubkey::default();
+        self.mint = Pubkey::default();
+    }
+}
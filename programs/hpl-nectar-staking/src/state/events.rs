@@ -13,6 +13,10 @@ pub enum Event {
         address: Pubkey,
         state: Vec<u8>,
     },
+    NftTraitProven {
+        address: Pubkey,
+        state: Vec<u8>,
+    },
     NewStaker {
         address: Pubkey,
         state: Vec<u8>,
@@ -35,6 +39,26 @@ pub enum Event {
         staker_address: Pubkey,
         amount: u64,
     },
+    ClaimAllRewards {
+        staker_address: Pubkey,
+        nft_addresses: Vec<Pubkey>,
+        amount: u64,
+    },
+    StakeTokens {
+        position_address: Pubkey,
+        position: Vec<u8>,
+        amount: u64,
+    },
+    UnstakeTokens {
+        position_address: Pubkey,
+        position: Vec<u8>,
+        amount: u64,
+    },
+    ClaimTokenRewards {
+        position_address: Pubkey,
+        position: Vec<u8>,
+        amount: u64,
+    },
 }
 
 // impl Event {
@@ -1,7 +1,12 @@
+pub mod allowed_mint_state;
 pub mod events;
 pub mod multplier_state;
 pub mod nft_state;
 pub mod pool_state;
 pub mod staker_state;
+pub mod token_position_state;
 
-pub use {events::*, multplier_state::*, nft_state::*, pool_state::*, staker_state::*};
+pub use {
+    allowed_mint_state::*, events::*, multplier_state::*, nft_state::*, pool_state::*,
+    staker_state::*, token_position_state::*,
+};
@@ -24,9 +24,27 @@ pub struct Multipliers {
 
     /// The duration multipliers for the staking_pool
     pub collection_multipliers: Vec<Multiplier>,
+
+    /// Merkle root of the `[0x00, mint, trait_hash(trait_type, value)]` leaves the trait multipliers are proven against
+    pub traits_merkle_root: [u8; 32],
+
+    /// The trait multipliers for the staking_pool
+    pub trait_multipliers: Vec<Multiplier>,
+
+    /// The mint multipliers for the staking_pool
+    pub mint_multipliers: Vec<Multiplier>,
+
+    /// The merkle tree multipliers for the staking_pool
+    pub merkle_tree_multipliers: Vec<Multiplier>,
+
+    /// How the multipliers are combined
+    pub stacking: MultiplierStacking,
+
+    /// The maximum combined multiplier, with the decimals of the multipliers
+    pub max_multiplier: Option<u64>,
 }
 impl Default for Multipliers {
-    const LEN: usize = 8 + 136;
+    const LEN: usize = 8 + 190;
 
     fn set_defaults(&mut self) {
         self.bump = 0;
@@ -36,6 +54,72 @@ impl Default for Multipliers {
         self.count_multipliers = vec![];
         self.creator_multipliers = vec![];
         self.collection_multipliers = vec![];
+        self.traits_merkle_root = [0; 32];
+        self.trait_multipliers = vec![];
+        self.mint_multipliers = vec![];
+        self.merkle_tree_multipliers = vec![];
+        self.stacking = MultiplierStacking::Additive;
+        self.max_multiplier = None;
+    }
+}
+
+impl Multipliers {
+    /// Space of the fields appended after collection_multipliers since the first deployment
+    pub const APPENDED_LEN: usize = 54;
+
+    /// Combines the total multiplier with the multiplier of a single component,
+    /// penalties below `10^decimals` can bring an additive total down to zero
+    pub fn stack(&self, total: u64, multiplier: u64) -> u64 {
+        let decimals = 10u64.pow(self.decimals.into());
+        match self.stacking {
+            MultiplierStacking::Additive => (total as i128 + multiplier as i128 - decimals as i128)
+                .clamp(0, u64::MAX as i128) as u64,
+            MultiplierStacking::Multiplicative => {
+                (total as u128 * multiplier as u128 / decimals as u128) as u64
+            }
+        }
+    }
+
+    /// The list holding the multipliers of the given kind
+    pub fn list_mut(&mut self, kind: MultiplierKind) -> &mut Vec<Multiplier> {
+        match kind {
+            MultiplierKind::StakeDuration => &mut self.duration_multipliers,
+            MultiplierKind::NFTCount => &mut self.count_multipliers,
+            MultiplierKind::Creator => &mut self.creator_multipliers,
+            MultiplierKind::Collection => &mut self.collection_multipliers,
+            MultiplierKind::Trait => &mut self.trait_multipliers,
+            MultiplierKind::Mint => &mut self.mint_multipliers,
+            MultiplierKind::MerkleTree => &mut self.merkle_tree_multipliers,
+        }
+    }
+
+    /// Sorts the list of the given kind in the order calculate_rewards expects
+    pub fn sort(&mut self, kind: MultiplierKind) {
+        match kind {
+            MultiplierKind::StakeDuration => {
+                self.duration_multipliers
+                    .sort_by_key(|x| match x.multiplier_type {
+                        MultiplierType::StakeDuration { min_duration } => min_duration,
+                        _ => 0,
+                    })
+            }
+            MultiplierKind::NFTCount => {
+                self.count_multipliers
+                    .sort_by_key(|x| match x.multiplier_type {
+                        MultiplierType::NFTCount { min_count } => min_count,
+                        _ => 0,
+                    })
+            }
+            MultiplierKind::Creator => self.creator_multipliers.sort_by_key(|x| x.value),
+            MultiplierKind::Collection => self
+                .collection_multipliers
+                .sort_by(|a, b| b.value.cmp(&a.value)),
+            MultiplierKind::Trait => self.trait_multipliers.sort_by(|a, b| b.value.cmp(&a.value)),
+            MultiplierKind::Mint => self.mint_multipliers.sort_by(|a, b| b.value.cmp(&a.value)),
+            MultiplierKind::MerkleTree => self
+                .merkle_tree_multipliers
+                .sort_by(|a, b| b.value.cmp(&a.value)),
+        }
     }
 }
 
@@ -66,4 +150,107 @@ pub enum MultiplierType {
 
     /// The multiplier is applied based on collection
     Collection { collection: Pubkey },
+
+    /// The multiplier is applied based on a proven trait, see `MultiplierType::trait_hash`
+    Trait { trait_hash: [u8; 32] },
+
+    /// The multiplier is applied to a specific NFT mint or cNFT asset id
+    Mint { mint: Pubkey },
+
+    /// The multiplier is applied based on the merkle tree of a cNFT
+    MerkleTree { merkle_tree: Pubkey },
+}
+impl MultiplierType {
+    pub fn kind(&self) -> MultiplierKind {
+        match self {
+            Self::StakeDuration { .. } => MultiplierKind::StakeDuration,
+            Self::NFTCount { .. } => MultiplierKind::NFTCount,
+            Self::Creator { .. } => MultiplierKind::Creator,
+            Self::Collection { .. } => MultiplierKind::Collection,
+            Self::Trait { .. } => MultiplierKind::Trait,
+            Self::Mint { .. } => MultiplierKind::Mint,
+            Self::MerkleTree { .. } => MultiplierKind::MerkleTree,
+        }
+    }
+
+    /// The hash identifying a trait_type and value pair
+    pub fn trait_hash(trait_type: &str, value: &str) -> [u8; 32] {
+        anchor_lang::solana_program::hash::hashv(&[
+            trait_type.as_bytes(),
+            &[0x00],
+            value.as_bytes(),
+        ])
+        .to_bytes()
+    }
+}
+
+/// The kinds of multipliers, each kept in its own list
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MultiplierKind {
+    StakeDuration,
+    NFTCount,
+    Creator,
+    Collection,
+    Trait,
+    Mint,
+    MerkleTree,
+}
+
+/// How the multiplier components are combined
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MultiplierStacking {
+    /// The bonuses of the components are summed
+    Additive,
+
+    /// The components are multiplied with each other
+    Multiplicative,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn multipliers(stacking: MultiplierStacking) -> Multipliers {
+        Multipliers {
+            bump: 0,
+            staking_pool: Pubkey::default(),
+            decimals: 2,
+            duration_multipliers: vec![],
+            count_multipliers: vec![],
+            creator_multipliers: vec![],
+            collection_multipliers: vec![],
+            traits_merkle_root: [0; 32],
+            trait_multipliers: vec![],
+            mint_multipliers: vec![],
+            merkle_tree_multipliers: vec![],
+            stacking,
+            max_multiplier: None,
+        }
+    }
+
+    #[test]
+    fn additive_stacking_sums_bonuses() {
+        let multipliers = multipliers(MultiplierStacking::Additive);
+        assert_eq!(multipliers.stack(150, 120), 170);
+    }
+
+    #[test]
+    fn additive_stacking_applies_penalties() {
+        let multipliers = multipliers(MultiplierStacking::Additive);
+        assert_eq!(multipliers.stack(100, 50), 50);
+        assert_eq!(multipliers.stack(120, 80), 100);
+    }
+
+    #[test]
+    fn additive_penalties_stop_at_zero() {
+        let multipliers = multipliers(MultiplierStacking::Additive);
+        assert_eq!(multipliers.stack(30, 50), 0);
+        assert_eq!(multipliers.stack(0, 0), 0);
+    }
+
+    #[test]
+    fn multiplicative_stacking_applies_penalties() {
+        let multipliers = multipliers(MultiplierStacking::Multiplicative);
+        assert_eq!(multipliers.stack(200, 50), 100);
+    }
 }
@@ -37,10 +37,21 @@ pub struct NFTv1 {
 
     /// NFT being used by a HPL Service
     pub used_by: NFTUsedBy,
+
+    /// The reward_index of the staking_pool at last_claim, the reward debt in EmissionMode::Shared
+    /// Zero for NFTs staked before migrate_staking_pool, see StakingPool::legacy_rewards
+    pub reward_index: u128,
+
+    /// The weight this NFT is staked with in EmissionMode::Shared
+    pub weight: u64,
+
+    /// Hashes of the traits proven for this NFT, see `MultiplierType::trait_hash`
+    /// Cleared with the account on unstake, so proofs only last for one staking period
+    pub traits: Vec<[u8; 32]>,
 }
 
 impl Default for NFTv1 {
-    const LEN: usize = 8 + 200;
+    const LEN: usize = 8 + 227;
 
     fn set_defaults(&mut self) {
         self.bump = 0;
@@ -54,9 +65,17 @@ impl Default for NFTv1 {
         self.is_compressed = false;
         self.criteria = NFTCriteria::None;
         self.used_by = NFTUsedBy::None;
+        self.reward_index = 0;
+        self.weight = 0;
+        self.traits = vec![];
     }
 }
 
+impl NFTv1 {
+    /// Space of the fields appended after used_by since the first deployment, see migrate_nft
+    pub const APPENDED_LEN: usize = 27;
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Copy, Clone, PartialEq, Eq, Debug)]
 pub enum NFTCriteria {
     None,
@@ -76,3 +95,48 @@ pub enum GuildRole {
     Chief,
     Member,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legacy_layout_decodes_with_zero_filled_appended_fields() {
+        let nft = NFTv1 {
+            bump: 255,
+            staking_pool: Pubkey::new_unique(),
+            staker: Some(Pubkey::new_unique()),
+            mint: Pubkey::new_unique(),
+            last_claim: 1_000,
+            staked_at: 900,
+            last_staked_at: 900,
+            last_unstaked_at: 0,
+            is_compressed: false,
+            criteria: NFTCriteria::Collection {
+                address: Pubkey::new_unique(),
+            },
+            used_by: NFTUsedBy::Guild {
+                id: Pubkey::new_unique(),
+                role: GuildRole::Chief,
+            },
+            reward_index: 0,
+            weight: 0,
+            traits: vec![],
+        };
+
+        let mut data = vec![];
+        nft.try_serialize(&mut data).unwrap();
+
+        // The fields appended after used_by serialize to 28 zero bytes here,
+        // the layout before them is padded to the size the NFT was staked with
+        let (legacy, appended) = data.split_at(data.len() - 28);
+        assert!(appended.iter().all(|byte| *byte == 0));
+        let mut legacy = legacy.to_vec();
+        legacy.resize(8 + 200, 0);
+
+        assert!(NFTv1::try_deserialize(&mut &legacy[..]).is_err());
+
+        legacy.resize(legacy.len() + NFTv1::APPENDED_LEN, 0);
+        assert_eq!(NFTv1::try_deserialize(&mut &legacy[..]).unwrap(), nft);
+    }
+}
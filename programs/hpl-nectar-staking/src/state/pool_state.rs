@@ -1,4 +1,7 @@
-use {anchor_lang::prelude::*, hpl_utils::traits::*};
+use {
+    super::NFTCriteria, crate::errors::ErrorCode, anchor_lang::prelude::*,
+    hpl_hive_control::state::Project, hpl_utils::traits::*,
+};
 
 /// The NFT collection staking_pool state account
 /// PDA: ['staking_pool', project, key]
@@ -43,7 +46,7 @@ pub struct StakingPool {
     /// Allowed mints only
     pub allowed_mints: bool,
 
-    /// Total staked nfts
+    /// Total staked nfts, see total_staked_amount for token staking_pools
     pub total_staked: u64,
 
     /// The unix_timestamp when the statking starts
@@ -60,9 +63,59 @@ pub struct StakingPool {
 
     /// The merkle tress for cNFTs
     pub merkle_trees: Vec<u8>,
+
+    /// Wallets with assets currently staked
+    pub total_stakers: u64,
+
+    /// Total rewards minted to the stakers
+    pub total_rewards_minted: u64,
+
+    /// Staked NFTs currently used by missions
+    pub total_used_by_missions: u64,
+
+    /// Kind of the staked assets { Nft, Token }
+    pub kind: PoolKind,
+
+    /// The share of the rewards earned by cNFTs in basis points, at most 10_000 which matches NFTs
+    pub compressed_rewards_ratio: u16,
+
+    /// Flag to halt staking and rewards while the staking_pool is paused
+    pub paused: bool,
+
+    /// Flag to mint the pending rewards of an NFT when it is unstaked
+    pub settle_on_unstake: bool,
+
+    /// The lifetime cap on total_rewards_minted
+    pub max_total_rewards: Option<u64>,
+
+    /// Rewards a single NFT, or a unit of weight in EmissionMode::Shared, accrued until
+    /// reward_index_updated_at, scaled by REWARD_INDEX_PRECISION
+    pub reward_index: u128,
+
+    /// The unix_timestamp of the last reward_index checkpoint
+    pub reward_index_updated_at: i64,
+
+    /// How the rewards are emitted { PerNft, Shared }
+    pub emission_mode: EmissionMode,
+
+    /// Sum of the weights of the staked NFTs in EmissionMode::Shared
+    pub total_weight: u64,
+
+    /// Step-downs of the rewards rate, in ascending start_time order
+    pub emission_epochs: Vec<EmissionEpoch>,
+
+    /// Total amount of tokens staked in a token staking_pool
+    pub total_staked_amount: u64,
+
+    /// The unix_timestamp of migrate_staking_pool, zero for staking_pools created with the reward_index
+    pub migrated_at: i64,
+
+    /// The rewards rate at migrated_at, which NFTs staked before it accrue at until migrated_at,
+    /// scaled by REWARD_INDEX_PRECISION, see legacy_rewards
+    pub legacy_rewards_rate: u128,
 }
 impl Default for StakingPool {
-    const LEN: usize = 8 + 268;
+    const LEN: usize = 8 + 407;
 
     fn set_defaults(&mut self) {
         self.bump = 0;
@@ -86,6 +139,213 @@ impl Default for StakingPool {
         self.collections = vec![];
         self.creators = vec![];
         self.merkle_trees = vec![];
+        self.total_stakers = 0;
+        self.total_rewards_minted = 0;
+        self.total_used_by_missions = 0;
+        self.kind = PoolKind::Nft;
+        self.compressed_rewards_ratio = Self::DEFAULT_COMPRESSED_REWARDS_RATIO;
+        self.paused = false;
+        self.settle_on_unstake = true;
+        self.max_total_rewards = None;
+        self.reward_index = 0;
+        self.reward_index_updated_at = 0;
+        self.emission_mode = EmissionMode::PerNft;
+        self.total_weight = 0;
+        self.emission_epochs = vec![];
+        self.total_staked_amount = 0;
+        self.migrated_at = 0;
+        self.legacy_rewards_rate = 0;
+    }
+}
+
+impl StakingPool {
+    /// cNFTs earn a tenth of the rewards of NFTs unless configured otherwise
+    pub const DEFAULT_COMPRESSED_REWARDS_RATIO: u16 = 1_000;
+
+    /// Space of the fields appended after merkle_trees since the first deployment, see migrate_staking_pool
+    pub const APPENDED_LEN: usize = 139;
+
+    /// Scale of the reward_index so rates below one token per second are not lost
+    pub const REWARD_INDEX_PRECISION: u128 = 1_000_000_000_000;
+
+    /// The reward_index at the given unix_timestamp, accruing at the current rate since the last checkpoint
+    /// The rate is stepped down by the emission_epochs started in between
+    /// In EmissionMode::Shared the rate is split among the total_weight staked
+    /// Staking pools awaiting migrate_staking_pool have no checkpoint yet and don't accrue,
+    /// their rewards are rejected by assert_migrated until then
+    pub fn reward_index_at(&self, timestamp: i64) -> u128 {
+        if self.reward_index_updated_at == 0 {
+            return self.reward_index;
+        }
+
+        let rate = self.rewards_rate();
+
+        let mut from = self.reward_index_updated_at;
+        let mut rate_bps = self.emission_rate_bps_at(from);
+        let mut accrued = 0u128;
+        for epoch in self
+            .emission_epochs
+            .iter()
+            .filter(|epoch| epoch.start_time > from && epoch.start_time < timestamp)
+        {
+            accrued += rate * rate_bps as u128 / 10_000
+                * self.seconds_in_window(from, epoch.start_time) as u128;
+            from = epoch.start_time;
+            rate_bps = epoch.rate_bps;
+        }
+        accrued +=
+            rate * rate_bps as u128 / 10_000 * self.seconds_in_window(from, timestamp) as u128;
+
+        match self.emission_mode {
+            EmissionMode::PerNft => self.reward_index + accrued,
+            EmissionMode::Shared if self.total_weight == 0 => self.reward_index,
+            EmissionMode::Shared => self.reward_index + accrued / self.total_weight as u128,
+        }
+    }
+
+    /// Checkpoints the reward_index, must be called before the rewards rate or window changes
+    /// The first checkpoint is left to migrate_staking_pool
+    pub fn checkpoint_reward_index(&mut self, timestamp: i64) {
+        if self.reward_index_updated_at == 0 {
+            return;
+        }
+
+        self.reward_index = self.reward_index_at(timestamp);
+        self.reward_index_updated_at = timestamp;
+    }
+
+    /// The rewards_per_duration per second, scaled by REWARD_INDEX_PRECISION
+    pub fn rewards_rate(&self) -> u128 {
+        self.rewards_per_duration as u128 * Self::REWARD_INDEX_PRECISION
+            / self.rewards_duration as u128
+    }
+
+    /// Rewards an NFT staked before migrate_staking_pool accrued between last_claim and migrated_at,
+    /// the reward_index only covers the time after it, scaled by REWARD_INDEX_PRECISION
+    pub fn legacy_rewards(&self, last_claim: i64) -> u128 {
+        if last_claim >= self.migrated_at {
+            return 0;
+        }
+
+        self.legacy_rewards_rate * self.seconds_in_window(last_claim, self.migrated_at) as u128
+    }
+
+    /// The share of rewards_per_duration in basis points emitted at the given unix_timestamp
+    pub fn emission_rate_bps_at(&self, timestamp: i64) -> u16 {
+        self.emission_epochs
+            .iter()
+            .rev()
+            .find(|epoch| epoch.start_time <= timestamp)
+            .map_or(10_000, |epoch| epoch.rate_bps)
+    }
+
+    /// Caps the rewards to what is left under max_total_rewards
+    pub fn cap_rewards(&self, amount: u64) -> u64 {
+        if let Some(max_total_rewards) = self.max_total_rewards {
+            let remaining = max_total_rewards.saturating_sub(self.total_rewards_minted);
+            if remaining < amount {
+                msg!("Rewards capped, {} left of max_total_rewards", remaining);
+                return remaining;
+            }
+        }
+        amount
+    }
+
+    /// Adds the weight of a staked NFT, checkpointing the reward_index at the previous total_weight
+    pub fn add_weight(&mut self, weight: u64, timestamp: i64) {
+        self.checkpoint_reward_index(timestamp);
+        self.total_weight += weight;
+    }
+
+    /// Removes the weight of an unstaked NFT, checkpointing the reward_index at the previous total_weight
+    pub fn remove_weight(&mut self, weight: u64, timestamp: i64) {
+        self.checkpoint_reward_index(timestamp);
+        self.total_weight = self.total_weight.saturating_sub(weight);
+    }
+
+    /// Checks if staking is open at the given unix_timestamp
+    pub fn assert_active(&self, timestamp: i64) -> Result<()> {
+        self.assert_not_paused()?;
+
+        if let Some(start_time) = self.start_time {
+            if timestamp < start_time {
+                msg!("Staking starts in {} seconds", start_time - timestamp);
+                return Err(ErrorCode::PoolNotStarted.into());
+            }
+        }
+
+        if self.has_ended(timestamp) {
+            return Err(ErrorCode::PoolEnded.into());
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the staking_pool is not paused
+    pub fn assert_not_paused(&self) -> Result<()> {
+        if self.paused {
+            msg!("Staking pool is paused");
+            return Err(ErrorCode::PoolPaused.into());
+        }
+        Ok(())
+    }
+
+    /// Checks that the staking_pool has been through migrate_staking_pool,
+    /// its reward_index does not accrue before that so claims would pay nothing
+    pub fn assert_migrated(&self) -> Result<()> {
+        if self.reward_index_updated_at == 0 {
+            msg!("Staking pool has to be migrated first");
+            return Err(ErrorCode::PoolNotMigrated.into());
+        }
+        Ok(())
+    }
+
+    /// Checks if the staking_pool stakes fungible tokens instead of NFTs
+    pub fn is_token_pool(&self) -> bool {
+        matches!(self.kind, PoolKind::Token { .. })
+    }
+
+    /// Checks if NFTs of the given criteria are still accepted by the staking_pool
+    pub fn accepts_criteria(&self, project: &Project, criteria: &NFTCriteria) -> bool {
+        let contains = |indexes: &Vec<u8>, addresses: &Vec<Pubkey>, address: &Pubkey| {
+            indexes.iter().any(|i| {
+                addresses
+                    .get(usize::from(*i))
+                    .map_or(false, |x| x.eq(address))
+            })
+        };
+
+        match criteria {
+            NFTCriteria::None => self.allowed_mints,
+            NFTCriteria::Collection { address } => {
+                contains(&self.collections, &project.collections, address)
+            }
+            NFTCriteria::Creator { address } => {
+                contains(&self.creators, &project.creators, address)
+            }
+            NFTCriteria::MerkleTree { address } => {
+                contains(&self.merkle_trees, &project.merkle_trees, address)
+            }
+        }
+    }
+
+    /// Checks if the end_time of the staking_pool has passed
+    pub fn has_ended(&self, timestamp: i64) -> bool {
+        if let Some(end_time) = self.end_time {
+            timestamp >= end_time
+        } else {
+            false
+        }
+    }
+
+    /// Seconds between `from` and `to` that fall inside the start_time/end_time window
+    pub fn seconds_in_window(&self, from: i64, to: i64) -> u64 {
+        let from = self
+            .start_time
+            .map_or(from, |start_time| from.max(start_time));
+        let to = self.end_time.map_or(to, |end_time| to.min(end_time));
+
+        u64::try_from(to - from).unwrap_or(0)
     }
 }
 
@@ -94,3 +354,271 @@ pub enum LockType {
     Freeze,
     Custoday,
 }
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub enum PoolKind {
+    Nft,
+    Token { mint: Pubkey },
+}
+
+/// A step-down of the rewards rate starting at start_time
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EmissionEpoch {
+    /// The unix_timestamp the epoch starts at
+    pub start_time: i64,
+
+    /// The share of rewards_per_duration emitted during the epoch in basis points
+    pub rate_bps: u16,
+}
+impl Default for EmissionEpoch {
+    const LEN: usize = 10;
+
+    fn set_defaults(&mut self) {
+        self.start_time = 0;
+        self.rate_bps = 10_000;
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EmissionMode {
+    /// Every staked NFT earns rewards_per_duration
+    PerNft,
+
+    /// rewards_per_duration is split among the staked NFTs by their weight
+    Shared,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub enum CriteriaAction {
+    Add,
+    Remove,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const P: u128 = StakingPool::REWARD_INDEX_PRECISION;
+
+    /// A staking_pool emitting 10 rewards per second, checkpointed at 1_000
+    fn pool() -> StakingPool {
+        StakingPool {
+            bump: 0,
+            temp_place_holder_1: 0,
+            project: Pubkey::default(),
+            key: Pubkey::default(),
+            currency: Pubkey::default(),
+            temp_place_holder_2: Pubkey::default(),
+            lock_type: LockType::Freeze,
+            name: "".to_string(),
+            rewards_per_duration: 10,
+            rewards_duration: 1,
+            max_rewards_duration: None,
+            min_stake_duration: None,
+            cooldown_duration: None,
+            reset_stake_duration: true,
+            allowed_mints: false,
+            total_staked: 0,
+            start_time: None,
+            end_time: None,
+            collections: vec![],
+            creators: vec![],
+            merkle_trees: vec![],
+            total_stakers: 0,
+            total_rewards_minted: 0,
+            total_used_by_missions: 0,
+            kind: PoolKind::Nft,
+            compressed_rewards_ratio: StakingPool::DEFAULT_COMPRESSED_REWARDS_RATIO,
+            paused: false,
+            settle_on_unstake: true,
+            max_total_rewards: None,
+            reward_index: 0,
+            reward_index_updated_at: 1_000,
+            emission_mode: EmissionMode::PerNft,
+            total_weight: 0,
+            emission_epochs: vec![],
+            total_staked_amount: 0,
+            migrated_at: 0,
+            legacy_rewards_rate: 0,
+        }
+    }
+
+    #[test]
+    fn per_nft_index_accrues_the_rate_since_the_checkpoint() {
+        let pool = pool();
+        assert_eq!(pool.reward_index_at(1_000), 0);
+        assert_eq!(pool.reward_index_at(1_100), 1_000 * P);
+        assert_eq!(pool.reward_index_at(900), 0);
+    }
+
+    #[test]
+    fn index_only_accrues_inside_the_window() {
+        let mut pool = pool();
+        pool.start_time = Some(1_050);
+        pool.end_time = Some(1_080);
+        assert_eq!(pool.reward_index_at(1_040), 0);
+        assert_eq!(pool.reward_index_at(1_100), 300 * P);
+    }
+
+    #[test]
+    fn unmigrated_pools_do_not_accrue() {
+        let mut pool = pool();
+        pool.reward_index_updated_at = 0;
+        assert_eq!(pool.reward_index_at(1_700_000_000), 0);
+        assert!(pool.assert_migrated().is_err());
+
+        pool.checkpoint_reward_index(1_700_000_000);
+        assert_eq!(pool.reward_index_updated_at, 0);
+    }
+
+    #[test]
+    fn legacy_rewards_cover_the_time_until_the_migration() {
+        let mut pool = pool();
+        assert_eq!(pool.legacy_rewards(400), 0);
+
+        pool.migrated_at = 1_000;
+        pool.legacy_rewards_rate = pool.rewards_rate();
+        assert_eq!(pool.legacy_rewards(400), 6_000 * P);
+        assert_eq!(pool.legacy_rewards(1_000), 0);
+        assert_eq!(pool.legacy_rewards(1_200), 0);
+
+        // The rate at the migration is kept when the staking_pool is updated afterwards
+        pool.rewards_per_duration = 20;
+        pool.start_time = Some(700);
+        assert_eq!(pool.legacy_rewards(400), 3_000 * P);
+    }
+
+    #[test]
+    fn legacy_layout_decodes_with_zero_filled_appended_fields() {
+        let mut pool = pool();
+        pool.name = "legacy".to_string();
+        pool.total_staked = 3;
+        pool.compressed_rewards_ratio = 0;
+        pool.settle_on_unstake = false;
+        pool.reward_index_updated_at = 0;
+
+        let mut data = vec![];
+        pool.try_serialize(&mut data).unwrap();
+
+        // The fields appended after merkle_trees serialize to 105 zero bytes here,
+        // the layout before them is padded to the size the staking_pool was created with
+        let (legacy, appended) = data.split_at(data.len() - 105);
+        assert!(appended.iter().all(|byte| *byte == 0));
+        let mut legacy = legacy.to_vec();
+        legacy.resize(8 + 268, 0);
+
+        assert!(StakingPool::try_deserialize(&mut &legacy[..]).is_err());
+
+        legacy.resize(legacy.len() + StakingPool::APPENDED_LEN, 0);
+        let migrated = StakingPool::try_deserialize(&mut &legacy[..]).unwrap();
+        assert_eq!(migrated.name, "legacy");
+        assert_eq!(migrated.total_staked, 3);
+        assert_eq!(migrated.kind, PoolKind::Nft);
+        assert_eq!(migrated.emission_mode, EmissionMode::PerNft);
+        assert!(!migrated.settle_on_unstake);
+        assert!(migrated.assert_migrated().is_err());
+    }
+
+    #[test]
+    fn checkpoints_keep_the_index_continuous() {
+        let mut pool = pool();
+        pool.checkpoint_reward_index(1_050);
+        assert_eq!(pool.reward_index, 500 * P);
+        assert_eq!(pool.reward_index_updated_at, 1_050);
+        assert_eq!(pool.reward_index_at(1_100), 1_000 * P);
+    }
+
+    #[test]
+    fn shared_index_splits_the_rate_by_weight() {
+        let mut pool = pool();
+        pool.emission_mode = EmissionMode::Shared;
+        assert_eq!(pool.reward_index_at(1_100), 0);
+
+        pool.add_weight(2, 1_000);
+        assert_eq!(pool.reward_index_at(1_100), 500 * P);
+
+        pool.add_weight(3, 1_100);
+        assert_eq!(pool.reward_index, 500 * P);
+        let index = pool.reward_index_at(1_200);
+        assert_eq!(index, 700 * P);
+
+        // Weight 2 staked at 1_000 and weight 3 staked at 1_100 split the 2_000 emitted
+        assert_eq!(index * 2 / P, 1_400);
+        assert_eq!((index - 500 * P) * 3 / P, 600);
+
+        pool.remove_weight(2, 1_200);
+        assert_eq!(pool.total_weight, 3);
+        assert_eq!(pool.reward_index_at(1_230), 800 * P);
+
+        pool.remove_weight(3, 1_230);
+        assert_eq!(pool.total_weight, 0);
+        assert_eq!(pool.reward_index_at(2_000), 800 * P);
+    }
+
+    #[test]
+    fn shared_payouts_never_exceed_the_emission() {
+        let mut pool = pool();
+        pool.emission_mode = EmissionMode::Shared;
+
+        // An NFT weight of 10_000 next to a cNFT at the default compressed_rewards_ratio
+        let weights = [10_000u64, 1_000, 3 * 10_000];
+        for weight in weights {
+            pool.add_weight(weight, 1_000);
+        }
+
+        let index = pool.reward_index_at(1_007);
+        let paid: u128 = weights
+            .iter()
+            .map(|weight| index * *weight as u128 / P)
+            .sum();
+        assert!(paid <= 70);
+        assert!(paid >= 70 - weights.len() as u128);
+    }
+
+    #[test]
+    fn epochs_step_down_the_rate() {
+        let mut pool = pool();
+        pool.emission_epochs = vec![
+            EmissionEpoch {
+                start_time: 1_050,
+                rate_bps: 5_000,
+            },
+            EmissionEpoch {
+                start_time: 1_080,
+                rate_bps: 0,
+            },
+        ];
+        assert_eq!(pool.emission_rate_bps_at(1_000), 10_000);
+        assert_eq!(pool.emission_rate_bps_at(1_060), 5_000);
+        assert_eq!(pool.emission_rate_bps_at(1_080), 0);
+        assert_eq!(pool.reward_index_at(1_100), 650 * P);
+
+        pool.checkpoint_reward_index(1_060);
+        assert_eq!(pool.reward_index, 550 * P);
+        assert_eq!(pool.reward_index_at(1_100), 650 * P);
+    }
+
+    #[test]
+    fn epochs_started_before_the_checkpoint_keep_their_rate() {
+        let mut pool = pool();
+        pool.emission_epochs = vec![EmissionEpoch {
+            start_time: 900,
+            rate_bps: 2_500,
+        }];
+        assert_eq!(pool.reward_index_at(1_100), 250 * P);
+    }
+
+    #[test]
+    fn rewards_are_capped_by_the_lifetime_cap() {
+        let mut pool = pool();
+        assert_eq!(pool.cap_rewards(50), 50);
+
+        pool.max_total_rewards = Some(100);
+        pool.total_rewards_minted = 60;
+        assert_eq!(pool.cap_rewards(30), 30);
+        assert_eq!(pool.cap_rewards(50), 40);
+
+        pool.total_rewards_minted = 100;
+        assert_eq!(pool.cap_rewards(50), 0);
+    }
+}
@@ -0,0 +1,61 @@
+use {super::StakingPool, anchor_lang::prelude::*, hpl_utils::traits::*};
+
+/// The position of a wallet in a token staking_pool
+/// PDA: ['token_position', staking_pool, wallet]
+/// Category: token_position_state
+#[account]
+#[derive(Debug, PartialEq, Eq)]
+pub struct TokenPosition {
+    pub bump: u8,
+
+    /// The staking_pool this position is associated with
+    pub staking_pool: Pubkey,
+
+    /// The wallet that owns this position
+    pub wallet: Pubkey,
+
+    /// The amount of tokens staked
+    pub amount: u64,
+
+    /// Accumulated staked at
+    pub staked_at: i64,
+
+    /// Last time the owner claimed rewards
+    pub last_claim: i64,
+
+    /// The reward_index of the staking_pool when the accrued rewards were last updated
+    pub reward_index: u128,
+
+    /// Rewards accrued since last claim, scaled by REWARD_INDEX_PRECISION
+    pub accrued: u128,
+}
+impl Default for TokenPosition {
+    const LEN: usize = 8 + 121;
+
+    fn set_defaults(&mut self) {
+        self.bump = 0;
+        self.staking_pool = Pubkey::default();
+        self.wallet = Pubkey::default();
+        self.amount = 0;
+        self.staked_at = 0;
+        self.last_claim = 0;
+        self.reward_index = 0;
+        self.accrued = 0;
+    }
+}
+
+impl TokenPosition {
+    /// Accrues the rewards of the staked amount up to the given unix_timestamp
+    /// Every whole token earns the reward_index, like a single NFT in EmissionMode::PerNft
+    /// Must be called before the staked amount changes
+    pub fn accrue(&mut self, staking_pool: &StakingPool, decimals: u8, timestamp: i64) {
+        let reward_index = staking_pool.reward_index_at(timestamp);
+        let delta = reward_index.saturating_sub(self.reward_index);
+        let unit = 10u128.pow(decimals.into());
+        let amount = self.amount as u128;
+
+        // Split the index so the product can't overflow for large amounts
+        self.accrued += delta / unit * amount + delta % unit * amount / unit;
+        self.reward_index = reward_index;
+    }
+}
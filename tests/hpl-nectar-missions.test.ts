@@ -0,0 +1,1401 @@
+import * as web3 from "@solana/web3.js";
+import { Metaplex, Nft, keypairIdentity } from "@metaplex-foundation/js";
+import { TokenStandard } from "@metaplex-foundation/mpl-token-metadata";
+import {
+  HPL_HIVE_CONTROL_PROGRAM,
+  Honeycomb,
+  HoneycombProject,
+  Operation,
+  ProfileDataType,
+  VAULT,
+} from "@honeycomb-protocol/hive-control";
+import {
+  PermissionedCurrencyKind,
+  HplCurrency,
+  findProjectCurrencies,
+  HPL_CURRENCY_MANAGER_PROGRAM,
+} from "@honeycomb-protocol/currency-manager";
+import {
+  HPL_NECTAR_STAKING_PROGRAM,
+  LockType,
+  METADATA_PROGRAM_ID,
+  NectarStaking,
+  StakedNft,
+  findProjectStakingPools,
+  getNftPda,
+} from "../packages/hpl-nectar-staking";
+import {
+  HPL_NECTAR_MISSIONS_PROGRAM,
+  InvalidRewardDropsError,
+  Mission,
+  MissionPool,
+  NectarMission,
+  NectarMissions,
+  NftAccountsNotProvidedError,
+  NftEscrow,
+  NftRewardNotAvailableError,
+  NotEndedError,
+  Participation,
+  PartyRecallNotAllowedError,
+  PenaltyAccountsNotProvidedError,
+  RandomnessNotAvailableError,
+  RecallAccountsNotProvidedError,
+  RecallPolicy,
+  Reward,
+  RewardDrop,
+  RewardsAlreadyRevealedError,
+  RewardsNotCollectedError,
+  RewardsNotRevealedError,
+  createCollectAllRewardsInstruction,
+  createCollectRewardsInstruction,
+  createCreateNftEscrowInstruction,
+  createDepositEscrowNftInstruction,
+  createMigrateMissionInstruction,
+  createMigrateParticipationInstruction,
+  createParticipateInstruction,
+  createParticipatePartyInstruction,
+  createRecallInstruction,
+  createRecallPartyMemberInstruction,
+  createRevealRewardsInstruction,
+  createUpdateMissionInstruction,
+  createWithdrawEscrowNftInstruction,
+  findProjectMissionPools,
+  participationPda,
+} from "../packages/hpl-nectar-missions";
+import getHoneycombs from "../scripts/prepare";
+import { HPL_EVENTS_PROGRAM } from "@honeycomb-protocol/events";
+import {
+  SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
+} from "@solana/spl-account-compression";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  createAssociatedTokenAccountIdempotentInstruction,
+  getAccount,
+  getAssociatedTokenAddressSync,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+
+jest.setTimeout(2000000);
+
+const wait = (seconds: number) =>
+  new Promise((resolve) => setTimeout(resolve, seconds * 1000));
+
+const toNumber = (value: number | { toString(): string }) =>
+  Number(value.toString());
+
+const expectProgramError = (
+  promise: Promise<unknown>,
+  error: { code: number }
+) =>
+  expect(promise).rejects.toThrow(
+    `custom program error: 0x${error.code.toString(16)}`
+  );
+
+const send = (
+  honeycomb: Honeycomb,
+  ...instructions: web3.TransactionInstruction[]
+) =>
+  new Operation(honeycomb, [
+    web3.ComputeBudgetProgram.setComputeUnitLimit({ units: 1_000_000 }),
+    ...instructions,
+  ]).send();
+
+const writable = (accounts: web3.PublicKey[]): web3.AccountMeta[] =>
+  accounts.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true }));
+
+describe("Nectar Missions", () => {
+  const totalNfts = 8;
+
+  let adminHC: Honeycomb;
+  let userHC: Honeycomb;
+  let metaplex: Metaplex;
+  let collection: Nft;
+  let nfts: Nft[] = [];
+  let stakedNfts: StakedNft[] = [];
+  let revealMission: NectarMission;
+  let partyMission: NectarMission;
+  let nftMission: NectarMission;
+  let rewardNft: Nft;
+  let nftEscrow: web3.PublicKey;
+  let lootMission: NectarMission;
+
+  const stakedNft = (nft: Nft) =>
+    stakedNfts.find((staked) => staked.mint.equals(nft.mint.address))!;
+
+  const participationOf = (nft: StakedNft) =>
+    participationPda(getNftPda(nft.stakingPool, nft.mint)[0])[0];
+
+  const fetchParticipation = (nft: StakedNft) =>
+    Participation.fromAccountAddress(
+      userHC.processedConnection,
+      participationOf(nft)
+    );
+
+  const waitForReveal = async (participation: Participation) => {
+    while (
+      (await userHC.processedConnection.getSlot()) <
+      toNumber(participation.revealSlot)
+    ) {
+      await wait(0.4);
+    }
+  };
+
+  const participateInstruction = (
+    mission: NectarMission,
+    nft: StakedNft,
+    escrows: web3.PublicKey[] = []
+  ) => {
+    const currency = mission.requirements.cost.currency();
+    const { holderAccount, tokenAccount } = userHC
+      .pda()
+      .currencyManager()
+      .holderAccountWithTokenAccount(
+        userHC.identity().address,
+        currency.mint.address,
+        currency.kind
+      );
+
+    return createParticipateInstruction(
+      {
+        project: mission.pool().project().address,
+        stakingPool: nft.stakingPool,
+        missionPool: mission.pool().address,
+        mission: mission.address,
+        nft: getNftPda(nft.stakingPool, nft.mint)[0],
+        staker: nft.staker,
+        currency: currency.address,
+        mint: currency.mint.address,
+        holderAccount,
+        tokenAccount,
+        participation: participationOf(nft),
+        wallet: userHC.identity().address,
+        vault: VAULT,
+        hiveControl: HPL_HIVE_CONTROL_PROGRAM,
+        rentSysvar: web3.SYSVAR_RENT_PUBKEY,
+        instructionsSysvar: web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        clock: web3.SYSVAR_CLOCK_PUBKEY,
+        currencyManagerProgram: HPL_CURRENCY_MANAGER_PROGRAM,
+        nectarStakingProgram: HPL_NECTAR_STAKING_PROGRAM,
+        hplEvents: HPL_EVENTS_PROGRAM,
+        anchorRemainingAccounts: writable(escrows),
+      },
+      {
+        args: {
+          faction: null,
+          merkleProof: null,
+        },
+      }
+    );
+  };
+
+  const revealInstruction = (
+    mission: NectarMission,
+    nft: StakedNft,
+    escrows: web3.PublicKey[] = []
+  ) =>
+    createRevealRewardsInstruction({
+      project: mission.pool().project().address,
+      missionPool: mission.pool().address,
+      mission: mission.address,
+      participation: participationOf(nft),
+      payer: userHC.identity().address,
+      vault: VAULT,
+      hiveControl: HPL_HIVE_CONTROL_PROGRAM,
+      instructionsSysvar: web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+      slotHashes: web3.SYSVAR_SLOT_HASHES_PUBKEY,
+      anchorRemainingAccounts: writable(escrows),
+    });
+
+  const migrateParticipationInstruction = (
+    mission: web3.PublicKey,
+    missionPool: web3.PublicKey,
+    project: web3.PublicKey,
+    participation: web3.PublicKey
+  ) =>
+    createMigrateParticipationInstruction({
+      project,
+      missionPool,
+      mission,
+      participation,
+      payer: userHC.identity().address,
+      vault: VAULT,
+      hiveControl: HPL_HIVE_CONTROL_PROGRAM,
+      rentSysvar: web3.SYSVAR_RENT_PUBKEY,
+      instructionsSysvar: web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+    });
+
+  const createMission = async (
+    name: string,
+    duration: number,
+    rewards: Reward[],
+    rewardDrops: RewardDrop[] = [],
+    recallPolicy: RecallPolicy = { __kind: "Disabled" }
+  ) => {
+    await adminHC
+      .missions()
+      .create()
+      .mission({
+        name,
+        cost: {
+          address: adminHC.currency().address,
+          amount: 1 * 1_000_000_000,
+        },
+        duration,
+        minXp: 0,
+        rewards,
+        rewardDrops,
+        factions: [],
+        recallPolicy,
+      });
+    return userHC.missions().mission(name, true);
+  };
+
+  const participatePartyInstruction = (
+    mission: NectarMission,
+    chief: StakedNft,
+    members: StakedNft[]
+  ) => {
+    const currency = mission.requirements.cost.currency();
+    const { holderAccount, tokenAccount } = userHC
+      .pda()
+      .currencyManager()
+      .holderAccountWithTokenAccount(
+        userHC.identity().address,
+        currency.mint.address,
+        currency.kind
+      );
+
+    return createParticipatePartyInstruction(
+      {
+        project: mission.pool().project().address,
+        stakingPool: chief.stakingPool,
+        missionPool: mission.pool().address,
+        mission: mission.address,
+        nft: getNftPda(chief.stakingPool, chief.mint)[0],
+        staker: chief.staker,
+        currency: currency.address,
+        mint: currency.mint.address,
+        holderAccount,
+        tokenAccount,
+        participation: participationOf(chief),
+        wallet: userHC.identity().address,
+        vault: VAULT,
+        hiveControl: HPL_HIVE_CONTROL_PROGRAM,
+        rentSysvar: web3.SYSVAR_RENT_PUBKEY,
+        instructionsSysvar: web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        clock: web3.SYSVAR_CLOCK_PUBKEY,
+        currencyManagerProgram: HPL_CURRENCY_MANAGER_PROGRAM,
+        nectarStakingProgram: HPL_NECTAR_STAKING_PROGRAM,
+        hplEvents: HPL_EVENTS_PROGRAM,
+        anchorRemainingAccounts: members.flatMap((member) => [
+          {
+            pubkey: getNftPda(member.stakingPool, member.mint)[0],
+            isSigner: false,
+            isWritable: true,
+          },
+          { pubkey: member.staker, isSigner: false, isWritable: true },
+          {
+            pubkey: userHC.identity().address,
+            isSigner: true,
+            isWritable: false,
+          },
+        ]),
+      },
+      {
+        args: {
+          shareRule: { __kind: "Equal" },
+          faction: null,
+          merkleProofs: null,
+        },
+      }
+    );
+  };
+
+  const recallPartyMemberInstruction = (
+    mission: NectarMission,
+    chief: StakedNft,
+    member: StakedNft
+  ) =>
+    createRecallPartyMemberInstruction({
+      project: mission.pool().project().address,
+      stakingPool: member.stakingPool,
+      nft: getNftPda(member.stakingPool, member.mint)[0],
+      staker: member.staker,
+      missionPool: mission.pool().address,
+      mission: mission.address,
+      participation: participationOf(chief),
+      chief: userHC.identity().address,
+      wallet: userHC.identity().address,
+      hiveControl: HPL_HIVE_CONTROL_PROGRAM,
+      nectarStakingProgram: HPL_NECTAR_STAKING_PROGRAM,
+      hplEvents: HPL_EVENTS_PROGRAM,
+      clock: web3.SYSVAR_CLOCK_PUBKEY,
+      instructionsSysvar: web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+      vault: VAULT,
+    });
+
+  const migrateMissionInstruction = (
+    mission: web3.PublicKey,
+    missionPool: web3.PublicKey,
+    project: web3.PublicKey
+  ) =>
+    createMigrateMissionInstruction({
+      project,
+      missionPool,
+      mission,
+      authority: adminHC.identity().address,
+      payer: adminHC.identity().address,
+      vault: VAULT,
+      hiveControl: HPL_HIVE_CONTROL_PROGRAM,
+      rentSysvar: web3.SYSVAR_RENT_PUBKEY,
+      instructionsSysvar: web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+    });
+
+  const recallInstruction = (
+    mission: NectarMission,
+    nft: StakedNft,
+    withPenaltyAccounts = false
+  ) => {
+    const currency = mission.requirements.cost.currency();
+    const { holderAccount, tokenAccount } = userHC
+      .pda()
+      .currencyManager()
+      .holderAccountWithTokenAccount(
+        userHC.identity().address,
+        currency.mint.address,
+        currency.kind
+      );
+
+    return createRecallInstruction({
+      project: mission.pool().project().address,
+      stakingPool: nft.stakingPool,
+      nft: getNftPda(nft.stakingPool, nft.mint)[0],
+      staker: nft.staker,
+      missionPool: mission.pool().address,
+      mission: mission.address,
+      participation: participationOf(nft),
+      ...(withPenaltyAccounts
+        ? {
+            currency: currency.address,
+            mint: currency.mint.address,
+            holderAccount,
+            tokenAccount,
+            currencyManagerProgram: HPL_CURRENCY_MANAGER_PROGRAM,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          }
+        : {}),
+      wallet: userHC.identity().address,
+      vault: VAULT,
+      hiveControl: HPL_HIVE_CONTROL_PROGRAM,
+      nectarStakingProgram: HPL_NECTAR_STAKING_PROGRAM,
+      hplEvents: HPL_EVENTS_PROGRAM,
+      clock: web3.SYSVAR_CLOCK_PUBKEY,
+      instructionsSysvar: web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+      slotHashes: web3.SYSVAR_SLOT_HASHES_PUBKEY,
+    });
+  };
+
+  const collectAllInstruction = async (
+    mission: NectarMission,
+    nft: StakedNft,
+    recall: boolean,
+    chief: StakedNft = nft,
+    withRecallAccounts = recall
+  ) => {
+    const project = mission.pool().project();
+    const currency = adminHC.currency();
+    const { holderAccount, tokenAccount } = userHC
+      .pda()
+      .currencyManager()
+      .holderAccountWithTokenAccount(
+        userHC.identity().address,
+        currency.mint.address,
+        currency.kind
+      );
+    const user = await userHC.profiles().userFromUsername("Test2");
+
+    return createCollectAllRewardsInstruction(
+      {
+        project: project.address,
+        missionPool: mission.pool().address,
+        missionPoolDelegate: userHC
+          .pda()
+          .hiveControl()
+          .delegateAuthority(
+            project.address,
+            project.authority,
+            mission.pool().address
+          )[0],
+        mission: mission.address,
+        participation: participationOf(chief),
+        nft: getNftPda(nft.stakingPool, nft.mint)[0],
+        profile: userHC
+          .pda()
+          .hiveControl()
+          .profile(project.address, user.address)[0],
+        ...(withRecallAccounts
+          ? {
+              stakingPool: nft.stakingPool,
+              staker: nft.staker,
+              nectarStakingProgram: HPL_NECTAR_STAKING_PROGRAM,
+            }
+          : {}),
+        wallet: userHC.identity().address,
+        vault: VAULT,
+        hiveControl: HPL_HIVE_CONTROL_PROGRAM,
+        currencyManagerProgram: HPL_CURRENCY_MANAGER_PROGRAM,
+        hplEvents: HPL_EVENTS_PROGRAM,
+        rentSysvar: web3.SYSVAR_RENT_PUBKEY,
+        instructionsSysvar: web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        clock: web3.SYSVAR_CLOCK_PUBKEY,
+        slotHashes: web3.SYSVAR_SLOT_HASHES_PUBKEY,
+        anchorRemainingAccounts: [
+          { pubkey: currency.address, isSigner: false, isWritable: false },
+          ...writable([currency.mint.address, holderAccount, tokenAccount]),
+        ],
+      },
+      { args: { recall } }
+    );
+  };
+
+  const escrowAccountsOf = (nftEscrow: web3.PublicKey, nft: Nft) => ({
+    nftMint: nft.mint.address,
+    nftMetadata: nft.metadataAddress,
+    nftEdition: nft.edition.address,
+    escrowAccount: web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("nft_escrow"),
+        nftEscrow.toBuffer(),
+        nft.mint.address.toBuffer(),
+      ],
+      HPL_NECTAR_MISSIONS_PROGRAM
+    )[0],
+  });
+
+  const escrowInstructionAccounts = (nftEscrow: web3.PublicKey, nft: Nft) => ({
+    project: adminHC.project().address,
+    missionPool: adminHC.missions().address,
+    nftEscrow,
+    ...escrowAccountsOf(nftEscrow, nft),
+    nftAccount: getAssociatedTokenAddressSync(
+      nft.mint.address,
+      adminHC.identity().address
+    ),
+    authority: adminHC.identity().address,
+    payer: adminHC.identity().address,
+    vault: VAULT,
+    hiveControl: HPL_HIVE_CONTROL_PROGRAM,
+    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+    tokenMetadataProgram: METADATA_PROGRAM_ID,
+    rentSysvar: web3.SYSVAR_RENT_PUBKEY,
+    instructionsSysvar: web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+  });
+
+  const collectNftRewardInstructions = (
+    mission: NectarMission,
+    nft: StakedNft,
+    nftEscrow: web3.PublicKey,
+    reward: Nft
+  ) => {
+    const { escrowAccount } = escrowAccountsOf(nftEscrow, reward);
+    const rewardAccount = getAssociatedTokenAddressSync(
+      reward.mint.address,
+      userHC.identity().address
+    );
+
+    return [
+      createAssociatedTokenAccountIdempotentInstruction(
+        userHC.identity().address,
+        rewardAccount,
+        userHC.identity().address,
+        reward.mint.address
+      ),
+      createCollectRewardsInstruction({
+        project: mission.pool().project().address,
+        missionPool: mission.pool().address,
+        mission: mission.address,
+        participation: participationOf(nft),
+        nft: getNftPda(nft.stakingPool, nft.mint)[0],
+        nftEscrow,
+        rewardMint: reward.mint.address,
+        escrowAccount,
+        rewardAccount,
+        rewardMetadata: reward.metadataAddress,
+        rewardEdition: reward.edition.address,
+        wallet: userHC.identity().address,
+        vault: VAULT,
+        hiveControl: HPL_HIVE_CONTROL_PROGRAM,
+        currencyManagerProgram: HPL_CURRENCY_MANAGER_PROGRAM,
+        hplEvents: HPL_EVENTS_PROGRAM,
+        compressionProgram: SPL_ACCOUNT_COMPRESSION_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        tokenMetadataProgram: METADATA_PROGRAM_ID,
+        rentSysvar: web3.SYSVAR_RENT_PUBKEY,
+        instructionsSysvar: web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        clock: web3.SYSVAR_CLOCK_PUBKEY,
+        slotHashes: web3.SYSVAR_SLOT_HASHES_PUBKEY,
+      }),
+    ];
+  };
+
+  it("Prepare", async () => {
+    const temp = getHoneycombs();
+
+    adminHC = temp.adminHC;
+    userHC = temp.userHC;
+
+    metaplex = new Metaplex(adminHC.connection);
+    metaplex.use(keypairIdentity(temp.admin));
+  });
+
+  it("Setup", async () => {
+    collection = await metaplex
+      .nfts()
+      .create({
+        name: "Collection",
+        symbol: "COL",
+        sellerFeeBasisPoints: 0,
+        uri: "https://api.eboy.dev/",
+        isCollection: true,
+        collectionIsSized: true,
+      })
+      .then((x) => x.nft);
+
+    for (let i = 1; i <= totalNfts; i++) {
+      const nft = await metaplex
+        .nfts()
+        .create({
+          name: `NFT #${i}`,
+          symbol: `TEST`,
+          sellerFeeBasisPoints: 100,
+          uri: "https://arweave.net/WhyRt90kgI7f0EG9GPfB8TIBTIBgX3X12QaF9ObFerE",
+          collection: collection.mint.address,
+          collectionAuthority: metaplex.identity(),
+          tokenStandard: TokenStandard.NonFungible,
+          tokenOwner: userHC.identity().address,
+        })
+        .then((x) => x.nft);
+      nfts.push(nft);
+    }
+
+    adminHC.use(
+      await HoneycombProject.new(adminHC, {
+        name: "MissionsTestProject",
+        expectedMintAddresses: nfts.length,
+        collections: [collection.mint.address],
+        merkleTrees: [],
+        profileDataConfigs: [
+          {
+            label: "nectar_missions_xp",
+            dataType: ProfileDataType.SingleValue,
+          },
+        ],
+      })
+    );
+
+    adminHC.use(
+      await HplCurrency.new(adminHC, {
+        name: "BAIL",
+        symbol: "BAIL",
+        kind: PermissionedCurrencyKind.NonCustodial,
+        decimals: 9,
+        uri: "https://arweave.net/1VxSzPEOwYlTo3lU5XSQWj-9Ldt3dB68cynDDjzeF-c",
+      })
+    );
+
+    await adminHC
+      .currency()
+      .create()
+      .holderAccount(userHC.identity().address)
+      .then((hA) => hA.mint(10_000 * 1_000_000_000));
+
+    adminHC.use(
+      await NectarStaking.new(adminHC, {
+        args: {
+          name: "MissionsStaking",
+          rewardsPerDuration: 1 * 1_000_000_000,
+          rewardsDuration: 1,
+          maxRewardsDuration: null,
+          minStakeDuration: null,
+          cooldownDuration: null,
+          resetStakeDuration: false,
+          allowedMints: null,
+          startTime: null,
+          endTime: null,
+          lockType: LockType.Freeze,
+          kind: null,
+          compressedRewardsRatio: null,
+          settleOnUnstake: null,
+          emissionMode: null,
+          maxTotalRewards: null,
+        },
+        project: adminHC.project(),
+        currency: adminHC.currency(),
+        collections: [collection.mint.address],
+      })
+    );
+
+    adminHC.use(
+      await NectarMissions.new(adminHC, {
+        args: {
+          name: "MissionsTest",
+          factionsMerkleRoot: new Array(32).fill(0),
+          stakingPools: [adminHC.staking().address],
+        },
+      })
+    );
+
+    console.log(
+      "Project",
+      adminHC.project().address.toString(),
+      "Missions",
+      adminHC.missions().address.toString()
+    );
+  });
+
+  it("Create missions", async () => {
+    revealMission = await adminHC
+      .missions()
+      .create()
+      .mission({
+        name: "Reveal Patrol",
+        cost: {
+          address: adminHC.currency().address,
+          amount: 1 * 1_000_000_000,
+        },
+        duration: 1,
+        minXp: 0,
+        rewards: [
+          {
+            min: 100,
+            max: 200,
+            rewardType: {
+              __kind: "Xp",
+            },
+          },
+          {
+            min: 1 * 1_000_000_000,
+            max: 20 * 1_000_000_000,
+            rewardType: {
+              __kind: "Currency",
+              address: adminHC.currency().address,
+            },
+          },
+        ],
+        rewardDrops: [],
+        factions: [],
+        recallPolicy: {
+          __kind: "Disabled",
+        },
+      });
+  });
+
+  it("Fetch for user and stake NFTs", async () => {
+    userHC.use(
+      await HoneycombProject.fromAddress(userHC, adminHC.project().address)
+    );
+    await findProjectCurrencies(userHC.project());
+    await findProjectStakingPools(userHC.project());
+    await findProjectMissionPools(userHC.project());
+
+    const user = await userHC
+      .profiles()
+      .userFromUsername("Test2")
+      .catch(() =>
+        userHC.profiles().newUser({
+          username: "Test2",
+          name: "Test User",
+          bio: "This user account is used for testing",
+          pfp: "https://lh3.googleusercontent.com/yTzqJcgQ4VNQuq5BXjEefj88NvmY6uqmq9UEM6nGUF9Vs68LPsTYocXR9vJ4yhvl-LlXeXgdXkm5Y5lz9p3LQqbEifbKHV5xtLc",
+        })
+      );
+    await user.profile(userHC.project().address).catch(() =>
+      user.newProfile(
+        {
+          pfp: "https://lh3.googleusercontent.com/UjE0kuudxuDzQ0QezywU99TzM49_QbNKHvmE8A8rC9o76W84YU1TmT0M78WJZz5bcu1VMud5RfYSoYZuv5Pa52PpO_bchLkiQQ",
+        },
+        userHC.project().address
+      )
+    );
+
+    const staking = userHC.staking() as unknown as NectarStaking;
+    await staking.stake(await staking.availableNfts());
+    stakedNfts = await staking.stakedNfts();
+    expect(stakedNfts.length).toBe(totalNfts);
+
+    revealMission = await userHC.missions().mission("Reveal Patrol");
+  });
+
+  it("Participate commits the reward rolls", async () => {
+    const nft = stakedNft(nfts[0]);
+
+    // The committed slot has no hash yet while participating
+    await expectProgramError(
+      send(
+        userHC,
+        participateInstruction(revealMission, nft),
+        revealInstruction(revealMission, nft)
+      ),
+      new RandomnessNotAvailableError()
+    );
+
+    await send(userHC, participateInstruction(revealMission, nft));
+
+    const participation = await fetchParticipation(nft);
+    expect(participation.isRevealed).toBe(false);
+    expect(participation.rolls.length).toBe(2);
+    expect(participation.rewards.length).toBe(2);
+    expect(toNumber(participation.revealSlot)).toBeGreaterThan(0);
+    expect(
+      toNumber(participation.endTime) - toNumber(participation.startTime)
+    ).toBe(1);
+  });
+
+  it("Reveal the rewards", async () => {
+    const nft = stakedNft(nfts[0]);
+    await waitForReveal(await fetchParticipation(nft));
+
+    await send(userHC, revealInstruction(revealMission, nft));
+
+    const participation = await fetchParticipation(nft);
+    expect(participation.isRevealed).toBe(true);
+    participation.rewards.forEach((reward, index) => {
+      const roll = participation.rolls[index];
+      expect(toNumber(reward.amount)).toBeGreaterThanOrEqual(
+        toNumber(roll.min)
+      );
+      expect(toNumber(reward.amount)).toBeLessThanOrEqual(
+        toNumber(roll.max)
+      );
+    });
+
+    await expectProgramError(
+      send(userHC, revealInstruction(revealMission, nft)),
+      new RewardsAlreadyRevealedError()
+    );
+  });
+
+  it("Migrate an up to date participation", async () => {
+    const nft = stakedNft(nfts[0]);
+    const address = participationOf(nft);
+    const before = await userHC.processedConnection.getAccountInfo(address);
+
+    await send(
+      userHC,
+      migrateParticipationInstruction(
+        revealMission.address,
+        revealMission.pool().address,
+        userHC.project().address,
+        address
+      )
+    );
+
+    const after = await userHC.processedConnection.getAccountInfo(address);
+    expect(after!.data.equals(before!.data)).toBe(true);
+  });
+
+  // A mission of the previous deployment, set LEGACY_MISSION
+  // The admin wallet must hold the authority of its project
+  (process.env.LEGACY_MISSION ? it : it.skip)(
+    "Migrate a pre-upgrade mission",
+    async () => {
+      const address = new web3.PublicKey(process.env.LEGACY_MISSION!);
+      const legacy = await adminHC.processedConnection.getAccountInfo(address);
+
+      // The original layout starts with bump and mission_pool
+      const missionPoolAddress = new web3.PublicKey(
+        legacy!.data.subarray(9, 41)
+      );
+      const missionPool = await MissionPool.fromAccountAddress(
+        adminHC.processedConnection,
+        missionPoolAddress
+      );
+
+      await send(
+        adminHC,
+        migrateMissionInstruction(
+          address,
+          missionPoolAddress,
+          missionPool.project
+        )
+      );
+
+      const mission = await Mission.fromAccountAddress(
+        adminHC.processedConnection,
+        address
+      );
+      expect(mission.factions).toEqual([]);
+      expect(mission.recallPolicy.__kind).toBe("Disabled");
+      expect(mission.rewardDrops).toEqual([]);
+    }
+  );
+
+  // A participation of the previous deployment, set LEGACY_PARTICIPATION
+  // Its mission must already be migrated for the mission account to decode
+  (process.env.LEGACY_PARTICIPATION ? it : it.skip)(
+    "Migrate a pre-upgrade participation",
+    async () => {
+      const address = new web3.PublicKey(process.env.LEGACY_PARTICIPATION!);
+      const legacy = await userHC.processedConnection.getAccountInfo(address);
+
+      // The original layout starts with bump, wallet and mission
+      const missionAddress = new web3.PublicKey(legacy!.data.subarray(41, 73));
+      const mission = await Mission.fromAccountAddress(
+        userHC.processedConnection,
+        missionAddress
+      );
+      const missionPool = await MissionPool.fromAccountAddress(
+        userHC.processedConnection,
+        mission.missionPool
+      );
+
+      await send(
+        userHC,
+        migrateParticipationInstruction(
+          missionAddress,
+          mission.missionPool,
+          missionPool.project,
+          address
+        )
+      );
+
+      const participation = await Participation.fromAccountAddress(
+        userHC.processedConnection,
+        address
+      );
+      expect(participation.isRevealed).toBe(true);
+      expect(toNumber(participation.startTime)).toBe(
+        toNumber(participation.endTime) - toNumber(mission.duration)
+      );
+    }
+  );
+
+  it("Participate as a party", async () => {
+    partyMission = await createMission("Party Patrol", 1, [
+      {
+        min: 10 * 1_000_000_000,
+        max: 20 * 1_000_000_000,
+        rewardType: {
+          __kind: "Currency",
+          address: adminHC.currency().address,
+        },
+      },
+    ]);
+
+    const chief = stakedNft(nfts[3]);
+    await send(
+      userHC,
+      participatePartyInstruction(partyMission, chief, [stakedNft(nfts[4])])
+    );
+
+    const participation = await fetchParticipation(chief);
+    expect(participation.members.length).toBe(2);
+    expect(participation.members[0].nft).toEqual(
+      getNftPda(chief.stakingPool, chief.mint)[0]
+    );
+    expect(
+      participation.members.reduce((total, member) => total + member.share, 0)
+    ).toBe(10_000);
+  });
+
+  it("Reveal splits the party rewards among the members", async () => {
+    const chief = stakedNft(nfts[3]);
+    await waitForReveal(await fetchParticipation(chief));
+
+    await send(userHC, revealInstruction(partyMission, chief));
+
+    const participation = await fetchParticipation(chief);
+    participation.rewards.forEach((reward, index) => {
+      expect(
+        participation.members.reduce(
+          (total, member) => total + toNumber(member.rewards[index].amount),
+          0
+        )
+      ).toBe(toNumber(reward.amount));
+    });
+
+    await expectProgramError(
+      send(
+        userHC,
+        recallPartyMemberInstruction(partyMission, chief, stakedNft(nfts[4]))
+      ),
+      new RewardsNotCollectedError()
+    );
+  });
+
+  it("Deposit an NFT reward in the escrow", async () => {
+    rewardNft = await metaplex
+      .nfts()
+      .create({
+        name: "Reward",
+        symbol: "REWARD",
+        sellerFeeBasisPoints: 0,
+        uri: "https://arweave.net/WhyRt90kgI7f0EG9GPfB8TIBTIBgX3X12QaF9ObFerE",
+        tokenStandard: TokenStandard.NonFungible,
+      })
+      .then((x) => x.nft);
+
+    nftEscrow = web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("nft_escrow"),
+        adminHC.missions().address.toBuffer(),
+        Buffer.from("Rewards"),
+      ],
+      HPL_NECTAR_MISSIONS_PROGRAM
+    )[0];
+
+    await send(
+      adminHC,
+      createCreateNftEscrowInstruction(
+        {
+          project: adminHC.project().address,
+          missionPool: adminHC.missions().address,
+          nftEscrow,
+          authority: adminHC.identity().address,
+          payer: adminHC.identity().address,
+          vault: VAULT,
+          hiveControl: HPL_HIVE_CONTROL_PROGRAM,
+          instructionsSysvar: web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        },
+        { args: { name: "Rewards" } }
+      ),
+      createDepositEscrowNftInstruction(
+        escrowInstructionAccounts(nftEscrow, rewardNft)
+      )
+    );
+
+    const escrow = await NftEscrow.fromAccountAddress(
+      adminHC.processedConnection,
+      nftEscrow
+    );
+    expect(escrow.mints).toEqual([rewardNft.mint.address]);
+    expect(toNumber(escrow.reserved)).toBe(0);
+  });
+
+  it("Participate reserves the escrow stock", async () => {
+    nftMission = await createMission("Treasure Hunt", 1, [
+      {
+        min: 0,
+        max: 1_000_000,
+        rewardType: {
+          __kind: "Nft",
+          escrow: nftEscrow,
+          mint: null,
+        },
+      },
+    ]);
+
+    const nft = stakedNft(nfts[2]);
+    await expectProgramError(
+      send(userHC, participateInstruction(nftMission, nft)),
+      new NftAccountsNotProvidedError()
+    );
+
+    await send(userHC, participateInstruction(nftMission, nft, [nftEscrow]));
+
+    const escrow = await NftEscrow.fromAccountAddress(
+      userHC.processedConnection,
+      nftEscrow
+    );
+    expect(escrow.mints.length).toBe(1);
+    expect(toNumber(escrow.reserved)).toBe(1);
+
+    // The only NFT in stock is reserved for the first participation
+    await expectProgramError(
+      send(
+        userHC,
+        participateInstruction(nftMission, stakedNft(nfts[1]), [nftEscrow])
+      ),
+      new NftRewardNotAvailableError()
+    );
+    await expectProgramError(
+      send(
+        adminHC,
+        createWithdrawEscrowNftInstruction(
+          escrowInstructionAccounts(nftEscrow, rewardNft)
+        )
+      ),
+      new NftRewardNotAvailableError()
+    );
+  });
+
+  it("Reveal picks the NFT reward from the escrow", async () => {
+    const nft = stakedNft(nfts[2]);
+    await waitForReveal(await fetchParticipation(nft));
+    await wait(2);
+
+    // The escrow of a random pick is only passed to reveal_rewards
+    await expectProgramError(
+      send(
+        userHC,
+        ...collectNftRewardInstructions(nftMission, nft, nftEscrow, rewardNft)
+      ),
+      new RewardsNotRevealedError()
+    );
+
+    await send(userHC, revealInstruction(nftMission, nft, [nftEscrow]));
+
+    const participation = await fetchParticipation(nft);
+    const rewardType = participation.rewards[0].rewardType;
+    expect(rewardType.__kind).toBe("Nft");
+    if (rewardType.__kind === "Nft") {
+      expect(rewardType.mint).toEqual(rewardNft.mint.address);
+    }
+
+    const escrow = await NftEscrow.fromAccountAddress(
+      userHC.processedConnection,
+      nftEscrow
+    );
+    expect(escrow.mints.length).toBe(0);
+    expect(toNumber(escrow.reserved)).toBe(0);
+  });
+
+  it("Collect the NFT reward", async () => {
+    const nft = stakedNft(nfts[2]);
+    await send(
+      userHC,
+      ...collectNftRewardInstructions(nftMission, nft, nftEscrow, rewardNft)
+    );
+
+    const rewardAccount = await getAccount(
+      userHC.processedConnection,
+      getAssociatedTokenAddressSync(
+        rewardNft.mint.address,
+        userHC.identity().address
+      )
+    );
+    expect(Number(rewardAccount.amount)).toBe(1);
+
+    const participation = await fetchParticipation(nft);
+    expect(participation.rewards[0].collected).toBe(true);
+  });
+
+  it("Create a mission with a drop table", async () => {
+    const currencyReward = (min: number, max: number): Reward => ({
+      min: min * 1_000_000_000,
+      max: max * 1_000_000_000,
+      rewardType: {
+        __kind: "Currency",
+        address: adminHC.currency().address,
+      },
+    });
+
+    await expectProgramError(
+      createMission(
+        "Broken Loot Run",
+        1,
+        [currencyReward(1, 2), currencyReward(3, 4)],
+        [{ dropChance: 5_000, group: null }]
+      ),
+      new InvalidRewardDropsError()
+    );
+
+    lootMission = await createMission(
+      "Loot Run",
+      1,
+      [currencyReward(1, 2), currencyReward(3, 4), currencyReward(5, 6)],
+      [
+        { dropChance: 10_000, group: null },
+        { dropChance: 5_000, group: 1 },
+        { dropChance: 5_000, group: 1 },
+      ]
+    );
+
+    const mission = await Mission.fromAccountAddress(
+      adminHC.processedConnection,
+      lootMission.address
+    );
+    expect(mission.rewards.length).toBe(3);
+    expect(mission.rewardDrops).toEqual([
+      { dropChance: 10_000, group: null },
+      { dropChance: 5_000, group: 1 },
+      { dropChance: 5_000, group: 1 },
+    ]);
+  });
+
+  it("Exactly one reward of a group drops", async () => {
+    const nft = stakedNft(nfts[5]);
+    await send(userHC, participateInstruction(lootMission, nft));
+    await waitForReveal(await fetchParticipation(nft));
+    await send(userHC, revealInstruction(lootMission, nft));
+
+    const participation = await fetchParticipation(nft);
+    expect(participation.rolls.map((roll) => roll.group)).toEqual([
+      null,
+      1,
+      1,
+    ]);
+    expect(participation.rewards[0].collected).toBe(false);
+    expect(
+      participation.rewards.slice(1).filter((reward) => !reward.collected)
+        .length
+    ).toBe(1);
+  });
+
+  it("Rewards added without drops always drop", async () => {
+    await send(
+      adminHC,
+      createUpdateMissionInstruction(
+        {
+          project: adminHC.project().address,
+          missionPool: adminHC.missions().address,
+          mission: lootMission.address,
+          authority: adminHC.identity().address,
+          payer: adminHC.identity().address,
+          vault: VAULT,
+          hiveControl: HPL_HIVE_CONTROL_PROGRAM,
+          rentSysvar: web3.SYSVAR_RENT_PUBKEY,
+          instructionsSysvar: web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        },
+        {
+          args: {
+            name: null,
+            minXp: null,
+            cost: null,
+            duration: null,
+            removeAllRewards: null,
+            addRewards: [
+              {
+                min: 100,
+                max: 200,
+                rewardType: {
+                  __kind: "Xp",
+                },
+              },
+            ],
+            addRewardDrops: null,
+            removeRewardIndices: null,
+            factions: null,
+            recallPolicy: null,
+          },
+        }
+      )
+    );
+
+    const mission = await Mission.fromAccountAddress(
+      adminHC.processedConnection,
+      lootMission.address
+    );
+    expect(mission.rewards.length).toBe(4);
+    expect(mission.rewardDrops[3]).toEqual({ dropChance: 10_000, group: null });
+  });
+
+  it("Migrate an up to date mission", async () => {
+    const before = await adminHC.processedConnection.getAccountInfo(
+      lootMission.address
+    );
+
+    await send(
+      adminHC,
+      migrateMissionInstruction(
+        lootMission.address,
+        adminHC.missions().address,
+        adminHC.project().address
+      )
+    );
+
+    const after = await adminHC.processedConnection.getAccountInfo(
+      lootMission.address
+    );
+    expect(after!.data.equals(before!.data)).toBe(true);
+  });
+
+  it("Recall before the end is rejected when disabled", async () => {
+    const reward: Reward = {
+      min: 10 * 1_000_000_000,
+      max: 20 * 1_000_000_000,
+      rewardType: {
+        __kind: "Currency",
+        address: adminHC.currency().address,
+      },
+    };
+    const mission = await createMission("Guard Duty", 3600, [reward]);
+
+    const nft = stakedNft(nfts[6]);
+    await send(userHC, participateInstruction(mission, nft));
+    await expectProgramError(
+      send(userHC, recallInstruction(mission, nft)),
+      new NotEndedError()
+    );
+  });
+
+  it("Recall before the end forfeits all rewards", async () => {
+    const mission = await createMission(
+      "Forfeit Patrol",
+      3600,
+      [
+        {
+          min: 10 * 1_000_000_000,
+          max: 20 * 1_000_000_000,
+          rewardType: {
+            __kind: "Currency",
+            address: adminHC.currency().address,
+          },
+        },
+      ],
+      [],
+      { __kind: "ForfeitAll" }
+    );
+
+    const nft = stakedNft(nfts[7]);
+    await send(userHC, participateInstruction(mission, nft));
+    await send(userHC, recallInstruction(mission, nft));
+
+    // Nothing is left to collect, so the participation is closed right away
+    expect(
+      await userHC.processedConnection.getAccountInfo(participationOf(nft))
+    ).toBeNull();
+  });
+
+  it("Recall before the end pro-rates the rolled rewards", async () => {
+    const mission = await createMission(
+      "Long Patrol",
+      3600,
+      [
+        {
+          min: 10 * 1_000_000_000,
+          max: 20 * 1_000_000_000,
+          rewardType: {
+            __kind: "Currency",
+            address: adminHC.currency().address,
+          },
+        },
+      ],
+      [],
+      { __kind: "ProRated" }
+    );
+
+    const nft = stakedNft(nfts[1]);
+    await send(userHC, participateInstruction(mission, nft));
+    await waitForReveal(await fetchParticipation(nft));
+    await send(userHC, revealInstruction(mission, nft));
+    const rolled = toNumber((await fetchParticipation(nft)).rewards[0].amount);
+
+    await send(userHC, recallInstruction(mission, nft));
+
+    const participation = await fetchParticipation(nft);
+    expect(participation.isRecalled).toBe(true);
+    expect(toNumber(participation.rewards[0].amount)).toBeLessThan(rolled);
+    expect(toNumber(participation.endTime)).toBeLessThan(
+      toNumber(participation.startTime) + 3600
+    );
+  });
+
+  it("Recall before the end burns the penalty", async () => {
+    const penalty = 2 * 1_000_000_000;
+    const mission = await createMission(
+      "Penalty Patrol",
+      3600,
+      [
+        {
+          min: 100,
+          max: 200,
+          rewardType: {
+            __kind: "Xp",
+          },
+        },
+      ],
+      [],
+      { __kind: "Penalty", amount: penalty }
+    );
+
+    const nft = stakedNft(nfts[7]);
+    await send(userHC, participateInstruction(mission, nft));
+
+    await expectProgramError(
+      send(userHC, recallInstruction(mission, nft)),
+      new PenaltyAccountsNotProvidedError()
+    );
+
+    const { tokenAccount } = userHC
+      .pda()
+      .currencyManager()
+      .holderAccountWithTokenAccount(
+        userHC.identity().address,
+        adminHC.currency().mint.address,
+        adminHC.currency().kind
+      );
+    const balance = async () =>
+      Number(
+        (await getAccount(userHC.processedConnection, tokenAccount)).amount
+      );
+    const before = await balance();
+
+    await send(userHC, recallInstruction(mission, nft, true));
+
+    expect(await balance()).toBe(before - penalty);
+    const participation = await fetchParticipation(nft);
+    expect(participation.isRecalled).toBe(true);
+    expect(participation.rewards[0].collected).toBe(false);
+  });
+
+  it("Collect all rewards and recall", async () => {
+    const nft = stakedNft(nfts[0]);
+    const rewards = (await fetchParticipation(nft)).rewards;
+
+    // Recalling without the staking accounts is rejected before collecting
+    await expectProgramError(
+      send(
+        userHC,
+        await collectAllInstruction(revealMission, nft, true, nft, false)
+      ),
+      new RecallAccountsNotProvidedError()
+    );
+
+    const { tokenAccount } = userHC
+      .pda()
+      .currencyManager()
+      .holderAccountWithTokenAccount(
+        userHC.identity().address,
+        adminHC.currency().mint.address,
+        adminHC.currency().kind
+      );
+    const balance = async () =>
+      Number(
+        (await getAccount(userHC.processedConnection, tokenAccount)).amount
+      );
+    const before = await balance();
+
+    await send(userHC, await collectAllInstruction(revealMission, nft, true));
+
+    expect(await balance()).toBe(before + toNumber(rewards[1].amount));
+    expect(
+      await userHC.processedConnection.getAccountInfo(participationOf(nft))
+    ).toBeNull();
+  });
+
+  it("Collect all and recall once the NFT reward is collected", async () => {
+    const nft = stakedNft(nfts[2]);
+    await send(userHC, await collectAllInstruction(nftMission, nft, true));
+
+    expect(
+      await userHC.processedConnection.getAccountInfo(participationOf(nft))
+    ).toBeNull();
+  });
+
+  it("Collect all for every party member before recalling them", async () => {
+    const chief = stakedNft(nfts[3]);
+    const member = stakedNft(nfts[4]);
+
+    await expectProgramError(
+      send(
+        userHC,
+        await collectAllInstruction(partyMission, chief, true, chief)
+      ),
+      new PartyRecallNotAllowedError()
+    );
+
+    for (const nft of [chief, member]) {
+      await send(
+        userHC,
+        await collectAllInstruction(partyMission, nft, false, chief),
+        recallPartyMemberInstruction(partyMission, chief, nft)
+      );
+    }
+
+    // The participation is closed once every member is recalled
+    expect(
+      await userHC.processedConnection.getAccountInfo(participationOf(chief))
+    ).toBeNull();
+  });
+});
@@ -0,0 +1,757 @@
+import * as web3 from "@solana/web3.js";
+import { Metaplex, Nft, keypairIdentity } from "@metaplex-foundation/js";
+import { TokenStandard } from "@metaplex-foundation/mpl-token-metadata";
+import {
+  HPL_HIVE_CONTROL_PROGRAM,
+  Honeycomb,
+  HoneycombProject,
+  Operation,
+  VAULT,
+} from "@honeycomb-protocol/hive-control";
+import {
+  PermissionedCurrencyKind,
+  HplCurrency,
+  HPL_CURRENCY_MANAGER_PROGRAM,
+} from "@honeycomb-protocol/currency-manager";
+import {
+  AlreadyMigratedError,
+  DuplicateNFTError,
+  HPL_NECTAR_STAKING_PROGRAM,
+  InvalidAmountError,
+  InvalidNFTError,
+  LockType,
+  METADATA_PROGRAM_ID,
+  NFTv1,
+  NectarStaking,
+  PoolNotEmptyError,
+  PoolPausedError,
+  StakedNft,
+  StakingPool,
+  TokenPosition,
+  createClaimAllRewardsInstruction,
+  createClaimRewardsOperation,
+  createCloseStakingPoolInstruction,
+  createEmergencyUnstakeInstruction,
+  createMigrateNftInstruction,
+  createMigrateStakingPoolInstruction,
+  createStakeOperation,
+  createStakeTokensInstruction,
+  createUnstakeTokensInstruction,
+  findProjectStakingPools,
+  getMetadataAccount_,
+  getMultipliersPda,
+  getNftPda,
+  getStakerPda,
+} from "../packages/hpl-nectar-staking";
+import getHoneycombs from "../scripts/prepare";
+import { HPL_EVENTS_PROGRAM } from "@honeycomb-protocol/events";
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  createMint,
+  getAccount,
+  getAssociatedTokenAddressSync,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+
+jest.setTimeout(2000000);
+
+const toNumber = (value: number | { toString(): string }) =>
+  Number(value.toString());
+
+const expectProgramError = (
+  promise: Promise<unknown>,
+  error: { code: number }
+) =>
+  expect(promise).rejects.toThrow(
+    `custom program error: 0x${error.code.toString(16)}`
+  );
+
+const send = (
+  honeycomb: Honeycomb,
+  ...instructions: web3.TransactionInstruction[]
+) =>
+  new Operation(honeycomb, [
+    web3.ComputeBudgetProgram.setComputeUnitLimit({ units: 1_000_000 }),
+    ...instructions,
+  ]).send();
+
+describe("Nectar Staking", () => {
+  const totalNfts = 4;
+
+  let admin: web3.Keypair;
+  let user: web3.Keypair;
+  let adminHC: Honeycomb;
+  let userHC: Honeycomb;
+  let stakeMint: web3.PublicKey;
+  let stakeAccount: web3.PublicKey;
+  let tokenPool: NectarStaking;
+  let metaplex: Metaplex;
+  let collection: Nft;
+  let nfts: Nft[] = [];
+  let nftPool: NectarStaking;
+  let stakedNfts: StakedNft[] = [];
+
+  const positionOf = (stakingPool: web3.PublicKey) =>
+    web3.PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("token_position"),
+        stakingPool.toBuffer(),
+        userHC.identity().address.toBuffer(),
+      ],
+      HPL_NECTAR_STAKING_PROGRAM
+    )[0];
+
+  const depositOf = (stakingPool: web3.PublicKey) =>
+    web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("token_deposit"), stakingPool.toBuffer()],
+      HPL_NECTAR_STAKING_PROGRAM
+    )[0];
+
+  const fetchPool = (stakingPool: web3.PublicKey) =>
+    StakingPool.fromAccountAddress(adminHC.processedConnection, stakingPool);
+
+  const tokenAccounts = (stakingPool: web3.PublicKey) => ({
+    project: adminHC.project().address,
+    stakingPool,
+    position: positionOf(stakingPool),
+    tokenMint: stakeMint,
+    tokenAccount: stakeAccount,
+    depositAccount: depositOf(stakingPool),
+    wallet: userHC.identity().address,
+    hiveControl: HPL_HIVE_CONTROL_PROGRAM,
+    tokenProgram: TOKEN_PROGRAM_ID,
+    hplEvents: HPL_EVENTS_PROGRAM,
+    clock: web3.SYSVAR_CLOCK_PUBKEY,
+    instructionsSysvar: web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+    vault: VAULT,
+  });
+
+  const stakeTokensInstruction = (
+    stakingPool: web3.PublicKey,
+    amount: number
+  ) =>
+    createStakeTokensInstruction(tokenAccounts(stakingPool), {
+      args: { amount },
+    });
+
+  const unstakeTokensInstruction = (
+    stakingPool: web3.PublicKey,
+    amount: number
+  ) =>
+    createUnstakeTokensInstruction(tokenAccounts(stakingPool), {
+      args: { amount },
+    });
+
+  const setPaused = (paused: boolean) =>
+    nftPool.updatePool({
+      args: {
+        name: null,
+        rewardsPerDuration: null,
+        rewardsDuration: null,
+        maxRewardsDuration: null,
+        minStakeDuration: null,
+        cooldownDuration: null,
+        resetStakeDuration: null,
+        allowedMints: null,
+        startTime: null,
+        endTime: null,
+        compressedRewardsRatio: null,
+        paused,
+        settleOnUnstake: null,
+        emissionMode: null,
+        maxTotalRewards: null,
+        emissionEpochs: null,
+        criteriaAction: null,
+      },
+    });
+
+  const emergencyUnstakeInstruction = (nft: StakedNft) => {
+    const nftAccount = getAssociatedTokenAddressSync(
+      nft.mint,
+      userHC.identity().address
+    );
+
+    return createEmergencyUnstakeInstruction({
+      project: adminHC.project().address,
+      vault: VAULT,
+      stakingPool: nftPool.address,
+      nft: getNftPda(nftPool.address, nft.mint)[0],
+      nftMint: nft.mint,
+      nftAccount,
+      nftMetadata: getMetadataAccount_(nft.mint)[0],
+      nftEdition: getMetadataAccount_(nft.mint, { __kind: "edition" })[0],
+      nftTokenRecord: HPL_NECTAR_STAKING_PROGRAM,
+      depositAccount: HPL_NECTAR_STAKING_PROGRAM,
+      depositTokenRecord: HPL_NECTAR_STAKING_PROGRAM,
+      staker: getStakerPda(nftPool.address, userHC.identity().address)[0],
+      wallet: userHC.identity().address,
+      authority: adminHC.identity().address,
+      payer: adminHC.identity().address,
+      delegateAuthority: HPL_NECTAR_STAKING_PROGRAM,
+      hiveControl: HPL_HIVE_CONTROL_PROGRAM,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      tokenMetadataProgram: METADATA_PROGRAM_ID,
+      hplEvents: HPL_EVENTS_PROGRAM,
+      clock: web3.SYSVAR_CLOCK_PUBKEY,
+      instructionsSysvar: web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+      authorizationRulesProgram: HPL_NECTAR_STAKING_PROGRAM,
+      authorizationRules: HPL_NECTAR_STAKING_PROGRAM,
+    });
+  };
+
+  const closeStakingPoolInstruction = (stakingPool: web3.PublicKey) =>
+    createCloseStakingPoolInstruction({
+      project: adminHC.project().address,
+      stakingPool,
+      multipliers: getMultipliersPda(stakingPool)[0],
+      delegateAuthority: HPL_NECTAR_STAKING_PROGRAM,
+      authority: adminHC.identity().address,
+      payer: adminHC.identity().address,
+      vault: VAULT,
+      hplEvents: HPL_EVENTS_PROGRAM,
+      clockSysvar: web3.SYSVAR_CLOCK_PUBKEY,
+      rentSysvar: web3.SYSVAR_RENT_PUBKEY,
+      instructionsSysvar: web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+      hiveControl: HPL_HIVE_CONTROL_PROGRAM,
+    });
+
+  const claimAllRewardsInstruction = (nftAccounts: web3.AccountMeta[]) => {
+    const { holderAccount, tokenAccount } = userHC
+      .pda()
+      .currencyManager()
+      .holderAccountWithTokenAccount(
+        userHC.identity().address,
+        adminHC.currency().mint.address,
+        adminHC.currency().kind
+      );
+
+    return createClaimAllRewardsInstruction({
+      project: adminHC.project().address,
+      stakingPool: nftPool.address,
+      stakingPoolDelegate: userHC
+        .pda()
+        .hiveControl()
+        .delegateAuthority(
+          adminHC.project().address,
+          adminHC.project().authority,
+          nftPool.address
+        )[0],
+      multipliers: HPL_NECTAR_STAKING_PROGRAM,
+      currency: adminHC.currency().address,
+      mint: adminHC.currency().mint.address,
+      holderAccount,
+      tokenAccount,
+      staker: getStakerPda(nftPool.address, userHC.identity().address)[0],
+      wallet: userHC.identity().address,
+      vault: VAULT,
+      hiveControl: HPL_HIVE_CONTROL_PROGRAM,
+      currencyManagerProgram: HPL_CURRENCY_MANAGER_PROGRAM,
+      hplEvents: HPL_EVENTS_PROGRAM,
+      clock: web3.SYSVAR_CLOCK_PUBKEY,
+      instructionsSysvar: web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+      anchorRemainingAccounts: nftAccounts,
+    });
+  };
+
+  const migrateStakingPoolInstruction = (
+    stakingPool: web3.PublicKey,
+    project: web3.PublicKey,
+    totalStaked: number,
+    totalStakers: number
+  ) =>
+    createMigrateStakingPoolInstruction(
+      {
+        project,
+        stakingPool,
+        multipliers: getMultipliersPda(stakingPool)[0],
+        delegateAuthority: HPL_NECTAR_STAKING_PROGRAM,
+        authority: adminHC.identity().address,
+        payer: adminHC.identity().address,
+        vault: VAULT,
+        clockSysvar: web3.SYSVAR_CLOCK_PUBKEY,
+        rentSysvar: web3.SYSVAR_RENT_PUBKEY,
+        instructionsSysvar: web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        hiveControl: HPL_HIVE_CONTROL_PROGRAM,
+      },
+      {
+        args: {
+          totalStaked,
+          totalStakers,
+        },
+      }
+    );
+
+  const migrateNftInstruction = (
+    nft: web3.PublicKey,
+    stakingPool: web3.PublicKey,
+    project: web3.PublicKey
+  ) =>
+    createMigrateNftInstruction({
+      project,
+      stakingPool,
+      nft,
+      wallet: userHC.identity().address,
+      hiveControl: HPL_HIVE_CONTROL_PROGRAM,
+      rentSysvar: web3.SYSVAR_RENT_PUBKEY,
+      instructionsSysvar: web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+      vault: VAULT,
+    });
+
+  it("Prepare", async () => {
+    const temp = getHoneycombs();
+
+    admin = temp.admin;
+    user = temp.user;
+    adminHC = temp.adminHC;
+    userHC = temp.userHC;
+
+    metaplex = new Metaplex(adminHC.connection);
+    metaplex.use(keypairIdentity(temp.admin));
+  });
+
+  it("Setup", async () => {
+    collection = await metaplex
+      .nfts()
+      .create({
+        name: "Collection",
+        symbol: "COL",
+        sellerFeeBasisPoints: 0,
+        uri: "https://api.eboy.dev/",
+        isCollection: true,
+        collectionIsSized: true,
+      })
+      .then((x) => x.nft);
+
+    for (let i = 1; i <= totalNfts; i++) {
+      const nft = await metaplex
+        .nfts()
+        .create({
+          name: `NFT #${i}`,
+          symbol: `TEST`,
+          sellerFeeBasisPoints: 100,
+          uri: "https://arweave.net/WhyRt90kgI7f0EG9GPfB8TIBTIBgX3X12QaF9ObFerE",
+          collection: collection.mint.address,
+          collectionAuthority: metaplex.identity(),
+          tokenStandard: TokenStandard.NonFungible,
+          tokenOwner: userHC.identity().address,
+        })
+        .then((x) => x.nft);
+      nfts.push(nft);
+    }
+
+    adminHC.use(
+      await HoneycombProject.new(adminHC, {
+        name: "StakingTestProject",
+        expectedMintAddresses: nfts.length,
+        collections: [collection.mint.address],
+        merkleTrees: [],
+        profileDataConfigs: [],
+      })
+    );
+
+    adminHC.use(
+      await HplCurrency.new(adminHC, {
+        name: "BAIL",
+        symbol: "BAIL",
+        kind: PermissionedCurrencyKind.NonCustodial,
+        decimals: 9,
+        uri: "https://arweave.net/1VxSzPEOwYlTo3lU5XSQWj-9Ldt3dB68cynDDjzeF-c",
+      })
+    );
+
+    await adminHC
+      .currency()
+      .create()
+      .holderAccount(userHC.identity().address);
+
+    stakeMint = await createMint(
+      adminHC.connection,
+      admin,
+      admin.publicKey,
+      null,
+      9
+    );
+    stakeAccount = await getOrCreateAssociatedTokenAccount(
+      adminHC.connection,
+      admin,
+      stakeMint,
+      user.publicKey
+    ).then((account) => account.address);
+    await mintTo(
+      adminHC.connection,
+      admin,
+      stakeMint,
+      stakeAccount,
+      admin,
+      1_000 * 1_000_000_000
+    );
+
+    tokenPool = await NectarStaking.new(adminHC, {
+      args: {
+        name: "TokenStaking",
+        rewardsPerDuration: 1 * 1_000_000_000,
+        rewardsDuration: 1,
+        maxRewardsDuration: null,
+        minStakeDuration: null,
+        cooldownDuration: null,
+        resetStakeDuration: false,
+        allowedMints: null,
+        startTime: null,
+        endTime: null,
+        lockType: null,
+        kind: { __kind: "Token", mint: stakeMint },
+        compressedRewardsRatio: null,
+        settleOnUnstake: null,
+        emissionMode: null,
+        maxTotalRewards: null,
+      },
+      project: adminHC.project(),
+      currency: adminHC.currency(),
+    });
+
+    nftPool = await NectarStaking.new(adminHC, {
+      args: {
+        name: "NftStaking",
+        rewardsPerDuration: 1 * 1_000_000_000,
+        rewardsDuration: 1,
+        maxRewardsDuration: null,
+        minStakeDuration: null,
+        cooldownDuration: null,
+        resetStakeDuration: false,
+        allowedMints: null,
+        startTime: null,
+        endTime: null,
+        lockType: LockType.Freeze,
+        kind: null,
+        compressedRewardsRatio: null,
+        settleOnUnstake: null,
+        emissionMode: null,
+        maxTotalRewards: null,
+      },
+      project: adminHC.project(),
+      currency: adminHC.currency(),
+      collections: [collection.mint.address],
+    });
+    adminHC.use(nftPool);
+
+    console.log(
+      "Project",
+      adminHC.project().address.toString(),
+      "Token Staking",
+      tokenPool.address.toString(),
+      "NFT Staking",
+      nftPool.address.toString()
+    );
+  });
+
+  it("Stake tokens", async () => {
+    await expectProgramError(
+      send(userHC, stakeTokensInstruction(tokenPool.address, 0)),
+      new InvalidAmountError()
+    );
+
+    await send(
+      userHC,
+      stakeTokensInstruction(tokenPool.address, 100 * 1_000_000_000)
+    );
+
+    const position = await TokenPosition.fromAccountAddress(
+      userHC.processedConnection,
+      positionOf(tokenPool.address)
+    );
+    expect(toNumber(position.amount)).toBe(100 * 1_000_000_000);
+    expect(position.wallet.equals(userHC.identity().address)).toBe(true);
+
+    const pool = await fetchPool(tokenPool.address);
+    expect(toNumber(pool.totalStakedAmount)).toBe(100 * 1_000_000_000);
+    expect(toNumber(pool.totalStakers)).toBe(1);
+    expect(toNumber(pool.totalStaked)).toBe(0);
+
+    const deposit = await getAccount(
+      adminHC.processedConnection,
+      depositOf(tokenPool.address)
+    );
+    expect(Number(deposit.amount)).toBe(100 * 1_000_000_000);
+  });
+
+  it("Unstake tokens", async () => {
+    await expectProgramError(
+      send(
+        userHC,
+        unstakeTokensInstruction(tokenPool.address, 101 * 1_000_000_000)
+      ),
+      new InvalidAmountError()
+    );
+
+    await send(
+      userHC,
+      unstakeTokensInstruction(tokenPool.address, 40 * 1_000_000_000)
+    );
+
+    let position = await TokenPosition.fromAccountAddress(
+      userHC.processedConnection,
+      positionOf(tokenPool.address)
+    );
+    expect(toNumber(position.amount)).toBe(60 * 1_000_000_000);
+    let pool = await fetchPool(tokenPool.address);
+    expect(toNumber(pool.totalStakedAmount)).toBe(60 * 1_000_000_000);
+    expect(toNumber(pool.totalStakers)).toBe(1);
+
+    await send(
+      userHC,
+      unstakeTokensInstruction(tokenPool.address, 60 * 1_000_000_000)
+    );
+
+    position = await TokenPosition.fromAccountAddress(
+      userHC.processedConnection,
+      positionOf(tokenPool.address)
+    );
+    expect(toNumber(position.amount)).toBe(0);
+    pool = await fetchPool(tokenPool.address);
+    expect(toNumber(pool.totalStakedAmount)).toBe(0);
+    expect(toNumber(pool.totalStakers)).toBe(0);
+
+    const wallet = await getAccount(adminHC.processedConnection, stakeAccount);
+    expect(Number(wallet.amount)).toBe(1_000 * 1_000_000_000);
+  });
+
+  it("Fetch for user and stake NFTs", async () => {
+    userHC.use(
+      await HoneycombProject.fromAddress(userHC, adminHC.project().address)
+    );
+    await findProjectStakingPools(userHC.project());
+
+    // The last NFT stays available to try staking it while paused
+    const staking = userHC.staking(nftPool.address) as unknown as NectarStaking;
+    const available = await staking.availableNfts();
+    await staking.stake(available.slice(0, totalNfts - 1));
+    stakedNfts = await staking.stakedNfts();
+    expect(stakedNfts.length).toBe(totalNfts - 1);
+  });
+
+  it("Pausing the pool rejects staking and claims", async () => {
+    await setPaused(true);
+    expect((await fetchPool(nftPool.address)).paused).toBe(true);
+
+    const staking = userHC.staking(nftPool.address) as unknown as NectarStaking;
+    const [available] = await staking.availableNfts(undefined, true);
+    await expectProgramError(
+      createStakeOperation(userHC, {
+        stakingPool: staking,
+        nft: available,
+        isFirst: true,
+      }).then(({ operation }) => operation.send()),
+      new PoolPausedError()
+    );
+
+    await expectProgramError(
+      createClaimRewardsOperation(userHC, {
+        stakingPool: staking,
+        nft: stakedNfts[0],
+      }).then(({ operation }) => operation.send()),
+      new PoolPausedError()
+    );
+  });
+
+  it("Emergency unstake by the project authority", async () => {
+    const nft = stakedNfts.pop()!;
+    const before = await fetchPool(nftPool.address);
+
+    await send(adminHC, emergencyUnstakeInstruction(nft));
+
+    const nftAccount = await getAccount(
+      adminHC.processedConnection,
+      getAssociatedTokenAddressSync(nft.mint, userHC.identity().address)
+    );
+    expect(nftAccount.isFrozen).toBe(false);
+    expect(
+      await adminHC.processedConnection.getAccountInfo(
+        getNftPda(nftPool.address, nft.mint)[0]
+      )
+    ).toBeNull();
+
+    const pool = await fetchPool(nftPool.address);
+    expect(toNumber(pool.totalStaked)).toBe(toNumber(before.totalStaked) - 1);
+  });
+
+  it("Unpause the pool", async () => {
+    await setPaused(false);
+    expect((await fetchPool(nftPool.address)).paused).toBe(false);
+  });
+
+  it("Close a staking pool once it is empty", async () => {
+    await send(
+      userHC,
+      stakeTokensInstruction(tokenPool.address, 10 * 1_000_000_000)
+    );
+    await expectProgramError(
+      send(adminHC, closeStakingPoolInstruction(tokenPool.address)),
+      new PoolNotEmptyError()
+    );
+
+    await send(
+      userHC,
+      unstakeTokensInstruction(tokenPool.address, 10 * 1_000_000_000)
+    );
+    await send(adminHC, closeStakingPoolInstruction(tokenPool.address));
+
+    expect(
+      await adminHC.processedConnection.getAccountInfo(tokenPool.address)
+    ).toBeNull();
+    expect(
+      await adminHC.processedConnection.getAccountInfo(
+        getMultipliersPda(tokenPool.address)[0]
+      )
+    ).toBeNull();
+  });
+
+  it("Claim the rewards of all staked NFTs at once", async () => {
+    const nftAddresses = stakedNfts.map(
+      (nft) => getNftPda(nftPool.address, nft.mint)[0]
+    );
+    const nftAccounts = (isWritable: boolean) =>
+      nftAddresses.map((pubkey) => ({ pubkey, isSigner: false, isWritable }));
+
+    await expectProgramError(
+      send(
+        userHC,
+        claimAllRewardsInstruction([
+          ...nftAccounts(true),
+          ...nftAccounts(true).slice(0, 1),
+        ])
+      ),
+      new DuplicateNFTError()
+    );
+    await expectProgramError(
+      send(userHC, claimAllRewardsInstruction(nftAccounts(false))),
+      new InvalidNFTError()
+    );
+
+    const { tokenAccount } = userHC
+      .pda()
+      .currencyManager()
+      .holderAccountWithTokenAccount(
+        userHC.identity().address,
+        adminHC.currency().mint.address,
+        adminHC.currency().kind
+      );
+    const balance = async () =>
+      Number(
+        (await getAccount(userHC.processedConnection, tokenAccount)).amount
+      );
+    const before = await balance();
+
+    await send(userHC, claimAllRewardsInstruction(nftAccounts(true)));
+
+    expect(await balance()).toBeGreaterThan(before);
+    const claimed = await Promise.all(
+      nftAddresses.map((address) =>
+        NFTv1.fromAccountAddress(userHC.processedConnection, address)
+      )
+    );
+    claimed.forEach((nft) =>
+      expect(toNumber(nft.lastClaim)).toBe(toNumber(claimed[0].lastClaim))
+    );
+  });
+
+  it("Migrate an up to date staking pool", async () => {
+    const pool = await fetchPool(nftPool.address);
+    await expectProgramError(
+      send(
+        adminHC,
+        migrateStakingPoolInstruction(
+          nftPool.address,
+          adminHC.project().address,
+          toNumber(pool.totalStaked),
+          toNumber(pool.totalStakers)
+        )
+      ),
+      new AlreadyMigratedError()
+    );
+  });
+
+  it("Migrate an up to date NFT", async () => {
+    const address = getNftPda(nftPool.address, stakedNfts[0].mint)[0];
+    const before = await userHC.processedConnection.getAccountInfo(address);
+
+    await send(
+      userHC,
+      migrateNftInstruction(address, nftPool.address, adminHC.project().address)
+    );
+
+    const after = await userHC.processedConnection.getAccountInfo(address);
+    expect(after!.data.equals(before!.data)).toBe(true);
+  });
+
+  // A staking pool of the previous deployment, set LEGACY_STAKING_POOL
+  // with LEGACY_TOTAL_STAKED and LEGACY_TOTAL_STAKERS recounted off-chain
+  // The admin wallet must hold the authority of its project
+  (process.env.LEGACY_STAKING_POOL ? it : it.skip)(
+    "Migrate a pre-upgrade staking pool",
+    async () => {
+      const address = new web3.PublicKey(process.env.LEGACY_STAKING_POOL!);
+      const legacy = await adminHC.processedConnection.getAccountInfo(address);
+      const totalStaked = Number(process.env.LEGACY_TOTAL_STAKED || 0);
+      const totalStakers = Number(process.env.LEGACY_TOTAL_STAKERS || 0);
+
+      // The original layout starts with bump, a placeholder and project
+      const project = new web3.PublicKey(legacy!.data.subarray(10, 42));
+
+      await send(
+        adminHC,
+        migrateStakingPoolInstruction(
+          address,
+          project,
+          totalStaked,
+          totalStakers
+        )
+      );
+
+      const pool = await fetchPool(address);
+      expect(toNumber(pool.migratedAt)).toBeGreaterThan(0);
+      expect(toNumber(pool.rewardIndexUpdatedAt)).toBeGreaterThan(0);
+      expect(toNumber(pool.totalStaked)).toBe(totalStaked);
+      expect(toNumber(pool.totalStakers)).toBe(totalStakers);
+
+      await expectProgramError(
+        send(
+          adminHC,
+          migrateStakingPoolInstruction(
+            address,
+            project,
+            totalStaked,
+            totalStakers
+          )
+        ),
+        new AlreadyMigratedError()
+      );
+    }
+  );
+
+  // An NFT of the previous deployment, set LEGACY_NFT
+  // Its staking pool must already be migrated for the NFT to be migrated
+  (process.env.LEGACY_NFT ? it : it.skip)(
+    "Migrate a pre-upgrade NFT",
+    async () => {
+      const address = new web3.PublicKey(process.env.LEGACY_NFT!);
+      const legacy = await userHC.processedConnection.getAccountInfo(address);
+
+      // The original layout starts with bump and staking_pool
+      const stakingPool = new web3.PublicKey(legacy!.data.subarray(9, 41));
+      const pool = await fetchPool(stakingPool);
+
+      await send(
+        userHC,
+        migrateNftInstruction(address, stakingPool, pool.project)
+      );
+
+      const nft = await NFTv1.fromAccountAddress(
+        userHC.processedConnection,
+        address
+      );
+      expect(nft.stakingPool.equals(stakingPool)).toBe(true);
+    }
+  );
+});
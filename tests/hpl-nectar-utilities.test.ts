@@ -254,9 +254,15 @@ describe("Nectar Utilities", () => {
             minStakeDuration: null,
             cooldownDuration: null,
             resetStakeDuration: false,
-            startTime: Date.now(),
+            allowedMints: null,
+            startTime: null,
             endTime: null,
             lockType: LockType.Freeze,
+            kind: null,
+            compressedRewardsRatio: null,
+            settleOnUnstake: null,
+            emissionMode: null,
+            maxTotalRewards: null,
           },
           project: adminHC.project(),
           currency: adminHC.currency(),
@@ -339,6 +345,11 @@ describe("Nectar Utilities", () => {
               },
             },
           ],
+          rewardDrops: [],
+          factions: [],
+          recallPolicy: {
+            __kind: "Disabled",
+          },
         });
     }
   });